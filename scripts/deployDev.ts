@@ -5,7 +5,7 @@ import BN from 'bn.js';
 import chalk from 'chalk';
 import { readFileSync, writeJSON } from 'fs-extra';
 import path from 'path';
-import { USDC_DECIMALS } from 'tests/consts';
+import { TGE_TOKEN_ALLOCATION_DISTRIBUTION, USDC_DECIMALS } from 'tests/consts';
 import { roleToSelectorId } from 'tests/misc';
 import AbaxTgeDeployer from 'typechain/deployers/abax_tge';
 import AbaxTokenDeployer from 'typechain/deployers/abax_token';
@@ -45,6 +45,11 @@ const VOTING_RULES: VotingRules = {
   initialPeriod: time.duration.days(3),
   flatPeriod: time.duration.days(7),
   finalPeriod: time.duration.days(4),
+  minimumQuorumE3: 0,
+  maximumActiveProposals: 10,
+  maximumActiveProposalsPerProposer: 10,
+  minimumProposalInterval: 0,
+  forceUnstakeGracePeriod: 0,
 };
 
 const NUMBER_OF_DEPLOYMENTS = 6;
@@ -103,7 +108,7 @@ const NUMBER_OF_DEPLOYMENTS = 6;
     await USDC.withSigner(signer).tx.mint('5FeXodVJgh6hvZs2ZniJyCZhpNjDxsm8bUXhsh3sCw7VzDT3', toE(USDC_DECIMALS, 5_000_000));
     await USDC.withSigner(signer).tx.mint('5ExyTZgRGxiT7nyD6h8jGsbr6hL88bbm2gvqTquu65qrbSJM', toE(USDC_DECIMALS, 500_000));
 
-    const abaxToken = (await new AbaxTokenDeployer(api, signer).new('ABAX', 'ABAX', ABAX_DECIMALS)).contract;
+    const abaxToken = (await new AbaxTokenDeployer(api, signer).new('ABAX', 'ABAX', ABAX_DECIMALS, api.genesisHash.toHex())).contract;
 
     const tgeVester = (await new VesterDeployer(api, signer).new()).contract;
     const governorVester = (await new VesterDeployer(api, signer).new()).contract;
@@ -140,6 +145,7 @@ const NUMBER_OF_DEPLOYMENTS = 6;
         treasury.address,
         PHASE_ONE_TOKEN_CAP,
         COST_TO_MINT_MILLION_TOKENS_E6,
+        TGE_TOKEN_ALLOCATION_DISTRIBUTION,
       )
     ).contract;
 
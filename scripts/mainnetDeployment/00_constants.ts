@@ -1,6 +1,7 @@
 import { time, toE } from '@c-forge/polkahat-network-helpers';
 import { nobody } from '@polkadot/keyring/pair/nobody';
 import BN from 'bn.js';
+import { TokenAllocationDistribution } from 'typechain/types-arguments/abax_tge';
 import { VotingRules } from 'typechain/types-arguments/governor';
 export const USDC_ADDRESS = '5FYFojNCJVFR2bBNKfAePZCa72ZcVX5yeTv8K9bzeUo8D83Z';
 
@@ -16,6 +17,31 @@ const toABAXTokenDecimals = (amount: string | number | BN) => (BN.isBN(amount) ?
 export const PHASE_ONE_TOKEN_CAP = toABAXTokenDecimals(1).mul(new BN(ONE_HUNDRED_MILLION)); // 100 million tokens with 12 decimals
 export const COST_TO_MINT_MILLIARD_TOKENS = new BN(ONE_MILLION).divn(25); // in absolute units // 1USDC = 25 ABAX
 
+const TGE_VEST_DURATION = time.duration.days(4 * 365); // 4 years in milliseconds
+// 20% public contribution (40% instant, 60% vested), 20% founders (20% instant, 80% vested), 2% foundation, 58% strategic reserves
+export const TGE_TOKEN_ALLOCATION_DISTRIBUTION: TokenAllocationDistribution = {
+  publicContribution: {
+    partE3: 200,
+    instantReleasePercentageE3: 400,
+    vestingParams: { duration: TGE_VEST_DURATION },
+  },
+  founders: {
+    partE3: 200,
+    instantReleasePercentageE3: 200,
+    vestingParams: { duration: TGE_VEST_DURATION },
+  },
+  foundation: { partE3: 20, instantReleasePercentageE3: 1000, vestingParams: null },
+  strategicReserves: { partE3: 580, instantReleasePercentageE3: 1000, vestingParams: null },
+};
+
+// the inflator is limited only by the cap of the token
+const MAX_U128 = new BN(2).pow(new BN(128)).subn(1);
+export const INFLATOR_MINTER_ALLOWANCE = {
+  windowDuration: time.duration.days(365),
+  maxPerWindow: MAX_U128,
+  lifetimeBudget: MAX_U128,
+};
+
 export const UNSTAKE_PERIOD = time.duration.days(180); // 180 days in milliseconds
 
 export const VOTING_RULES: VotingRules = {
@@ -24,4 +50,9 @@ export const VOTING_RULES: VotingRules = {
   initialPeriod: time.duration.days(3),
   flatPeriod: time.duration.days(7),
   finalPeriod: time.duration.days(4),
+  minimumQuorumE3: 0,
+  maximumActiveProposals: 10,
+  maximumActiveProposalsPerProposer: 10,
+  minimumProposalInterval: 0,
+  forceUnstakeGracePeriod: 0,
 };
@@ -14,9 +14,11 @@ import {
   COST_TO_MINT_MILLIARD_TOKENS,
   FOUNDATION_ADDRESS,
   FOUNDERS_ADDRESS,
+  INFLATOR_MINTER_ALLOWANCE,
   PHASE_ONE_TOKEN_CAP,
   PHASE_TWO_DURATION,
   TGE_START_TIME,
+  TGE_TOKEN_ALLOCATION_DISTRIBUTION,
   UNSTAKE_PERIOD,
   USDC_ADDRESS,
   VOTING_RULES,
@@ -54,7 +56,7 @@ export const saveContractInfoToFileAsJson = async (contractInfos: StoredContract
   console.log('Deployer:', deployer.address);
 
   // ABAX TOKEN is deployed with deployer as a RoleAdmin.
-  const { result: abaxTokenResult, contract: abaxToken } = await new AbaxTokenDeployer(api, deployer).new('ABAX', 'ABAX', ABAX_DECIMALS);
+  const { result: abaxTokenResult, contract: abaxToken } = await new AbaxTokenDeployer(api, deployer).new('ABAX', 'ABAX', ABAX_DECIMALS, api.genesisHash.toHex());
   console.log(`Deployed ABAX Token at ${abaxToken.address}`);
 
   // TGE Vester is deployed. it doesn't have any admins.
@@ -104,6 +106,7 @@ export const saveContractInfoToFileAsJson = async (contractInfos: StoredContract
     treasury.address,
     PHASE_ONE_TOKEN_CAP,
     COST_TO_MINT_MILLIARD_TOKENS,
+    TGE_TOKEN_ALLOCATION_DISTRIBUTION,
   );
   console.log(`Deployed TGE at ${abaxTge.address}`);
   console.log('TGE START TIME:', new Date(TGE_START_TIME), 'ISO:', new Date(TGE_START_TIME).toISOString());
@@ -119,6 +122,17 @@ export const saveContractInfoToFileAsJson = async (contractInfos: StoredContract
   const res0 = await abaxToken.withSigner(deployer).tx.grantRole(roleToSelectorId('MINTER'), inflator.address);
   console.log('Granted MINTER role to Inflator');
 
+  // minters can mint only within their allowance
+  const res0a = await abaxToken
+    .withSigner(deployer)
+    .tx.setMinterAllowance(
+      inflator.address,
+      INFLATOR_MINTER_ALLOWANCE.windowDuration,
+      INFLATOR_MINTER_ALLOWANCE.maxPerWindow,
+      INFLATOR_MINTER_ALLOWANCE.lifetimeBudget,
+    );
+  console.log('Set minter allowance of Inflator');
+
   // Give TGE GENERATOR role
   const res1 = await abaxToken.withSigner(deployer).tx.grantRole(roleToSelectorId('GENERATOR'), abaxTge.address);
   console.log('Granted GENERATOR role to TGE');
@@ -221,7 +235,7 @@ export const saveContractInfoToFileAsJson = async (contractInfos: StoredContract
 
   await writeJSON(
     EXECUTED_TX_RESULTS_PATH,
-    [res0, res1, res2, res3, res4, res5, res6, res7, res8, res9, res10].map((r) => ({
+    [res0, res0a, res1, res2, res3, res4, res5, res6, res7, res8, res9, res10].map((r) => ({
       txHash: r.txHash!,
       blockHash: r.blockHash!,
     })),
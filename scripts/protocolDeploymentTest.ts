@@ -18,6 +18,7 @@ import BN from 'bn.js';
 import chalk from 'chalk';
 import { readFileSync } from 'fs-extra';
 import path from 'path';
+import { TGE_TOKEN_ALLOCATION_DISTRIBUTION } from 'tests/consts';
 import { roleToSelectorId } from 'tests/misc';
 import ATokenContract from 'typechain/contracts/a_token';
 import LendingPoolContract from 'typechain/contracts/lending_pool';
@@ -322,6 +323,11 @@ const VOTING_RULES: VotingRules = {
   initialPeriod: time.duration.days(3),
   flatPeriod: time.duration.days(7),
   finalPeriod: time.duration.days(4),
+  minimumQuorumE3: 0,
+  maximumActiveProposals: 10,
+  maximumActiveProposalsPerProposer: 10,
+  minimumProposalInterval: 0,
+  forceUnstakeGracePeriod: 0,
 };
 
 const ONE_TOKEN = new BN(10).pow(new BN(ABAX_DECIMALS));
@@ -350,7 +356,6 @@ const bigStake = midStake.muln(10);
 
   const ABAX_TOKEN_DEFAULT_ADMIN = signer.address;
   const ABAX_TGE_DEFAULT_ADMIN = signer.address;
-  const ABAX_TGE_STAKEDROP_ADMIN = signer.address;
   // const CUSTOM_ADMIN = '5EPYzg9NkmtSGcJD82465NG92XxXbX3MgzMsVv1Z3ZALR8aE';
   const CUSTOM_ADMIN = signer.address;
   const testSeeds = JSON.parse(readFileSync(path.join(__dirname, 'testSeeds.json')).toString()) as { [key: string]: string };
@@ -362,7 +367,7 @@ const bigStake = midStake.muln(10);
   // const wAZERO = (await new WazeroDeployer(api, signer).new()).contract;
   const wAZERO = (await new Psp22EmitableDeployer(api, signer).new('wAZERO', 'wAZERO', ABAX_DECIMALS)).contract;
 
-  // const abaxToken = (await new AbaxTokenDeployer(api, signer).new('ABAX', 'ABAX', ABAX_DECIMALS)).contract;
+  // const abaxToken = (await new AbaxTokenDeployer(api, signer).new('ABAX', 'ABAX', ABAX_DECIMALS, api.genesisHash.toHex())).contract;
   const abaxToken = (await new Psp22EmitableDeployer(api, signer).new('Token', 'TOK', ABAX_DECIMALS)).contract;
 
   const vester = (await new VesterDeployer(api, signer).new()).contract;
@@ -394,7 +399,7 @@ const bigStake = midStake.muln(10);
       treasury.address,
       PHASE_ONE_TOKEN_CAP,
       COST_TO_MINT_MILLION_TOKENS,
-      ABAX_TGE_STAKEDROP_ADMIN,
+      TGE_TOKEN_ALLOCATION_DISTRIBUTION,
     )
   ).contract;
 
@@ -1,17 +1,27 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
 mod modules;
+#[cfg(any(feature = "transaction-builders", test))]
+pub use modules::govern::helpers::transactions;
 /// This is AbaxGovernor Contract implementation.
 /// It allows for staking PSP22 token (Abax token) in exchange for PSP22Vault shares (votes).
 /// The shares are non-transferrable.
 /// Withdrawing assets is possible only after unstake period - unstaking is handled by GeneralVest contract.
+/// Assets can be deposited with a signed permit of the asset (`deposit_with_permit`) instead of a prior approve transaction.
 ///
 /// The contract allows for proposing and voting on proposals by implementing Govern trait.
 /// To create a proposal, the proposer must have enough votes (shares) to meet the minimum stake part.
 /// While proposal is created the proposer must deposit a part of his votes. This votes are returned when proposal is finalized unless the proposal is finalized with 'DefeatedWithSlash' status.
+/// Alternatively the deposit can be taken in assets (Abax token). Then the proposer doesn't have to meet the minimum stake part and the slashed assets are shared among stakers.
 /// One share is one vote.
+/// Optionally assets that are still vesting (e.g. ABAX bought in the TGE) are counted as votes at a discount set by PARAMETERS_ADMIN.
 /// Proposal has 3 periods of voting: Intial, Flat and Final which influence the minimum votes to finalize.
-/// If proposal was finalized in Final phase, it's possible to force unstake an account that didn't vote on that proposal.
+/// If proposal was finalized in Final phase, it's possible to force unstake an account that didn't vote on that proposal once the grace period after finalization has passed.
+/// Voters without an opinion can abstain. Abstaining votes count toward the quorum and the minimum votes to finalize, but not for or against.
+/// Proposal can use commit-reveal voting in which votes are committed as hashes and counted only when revealed after the final period.
+/// Stakers that don't meet the minimum stake part alone can create a draft proposal that becomes active once sponsors pledge enough votes.
+/// Account with PAUSER role can temporarily pause a subset of messages. While voting is paused, voting periods of active proposals are extended.
+/// Storage layout is versioned. After the code hash is updated, account with MIGRATOR role runs the migration steps in batches of proposals with `migrate`.
 ///
 /// Contract is using pendzl Access Control to manage access to the messages
 
@@ -20,18 +30,28 @@ mod modules;
 mod abax_governor {
     pub use crate::modules::govern::{
         helpers::{
-            finalization::minimum_to_finalize,
-            hashes::{hash_description, hash_proposal},
+            finalization::{minimum_quorum, minimum_to_finalize, voting_time},
+            hashes::{hash_description, hash_proposal, hash_vote},
         },
         storage::{
-            govern_storage_item::GovernData, locked_shares_storage_item::LockedSharesData,
-            unstake_storage_item::UnstakeData, vault_counter_storage_item::VaultCounterData,
+            govern_storage_item::GovernData,
+            locked_assets_storage_item::LockedAssetsData,
+            locked_shares_storage_item::LockedSharesData,
+            pause_storage_item::PauseData,
+            sponsorship_storage_item::SponsorshipData,
+            unstake_storage_item::UnstakeData,
+            vault_counter_storage_item::VaultCounterData,
+            version_storage_item::{VersionData, STORAGE_VERSION},
+            vested_votes_storage_item::VestedVotesData,
         },
         traits::{
-            AbaxGovern, AbaxGovernInternal, AbaxGovernManage, AbaxGovernView, GovernError,
-            OpaqueTypes, Proposal, ProposalCreated, ProposalExecuted, ProposalFinalized,
-            ProposalHash, ProposalId, ProposalState, ProposalStatus, UnstakePeriodChanged,
-            UserVote, Vote, VoteCasted, VotingRules, VotingRulesChanged,
+            AbaxGovern, AbaxGovernInternal, AbaxGovernManage, AbaxGovernView, CommitRevealRules,
+            CommitRevealRulesChanged, Draft, DraftCancelled, DraftCreated, GovernError, Migrated,
+            OpaqueTypes, PSP22Permit, PSP22PermitRef, Pause, Paused, PausedMessages, Proposal,
+            ProposalCreated, ProposalExecuted, ProposalFinalized, ProposalHash, ProposalId,
+            ProposalState, ProposalStatus, ProposerDeposit, ProposerDepositChanged, Sponsored,
+            Sponsorship, Unpaused, Unsponsored, UnstakePeriodChanged, UserVote, VestedVotes,
+            VestedVotesChanged, Vote, VoteCasted, VoteCommitted, VotingRules, VotingRulesChanged,
         },
     };
     use ink::codegen::TraitCallBuilder;
@@ -46,7 +66,8 @@ mod abax_governor {
         contracts::{
             access_control::RoleType,
             general_vest::{
-                ExternalTimeConstraint, GeneralVest, ProvideVestScheduleInfo, VestingSchedule,
+                ExternalTimeConstraint, GeneralVest, GeneralVestRef, ProvideVestScheduleInfo,
+                VestingData, VestingSchedule,
             },
             psp22::{vault::PSP22VaultInternalDefaultImpl, *},
         },
@@ -56,6 +77,16 @@ mod abax_governor {
 
     pub const EXECUTOR: RoleType = ink::selector_id!("EXECUTOR");
     pub const PARAMETERS_ADMIN: RoleType = ink::selector_id!("PARAMETERS_ADMIN"); // 368_001_360_u32
+    pub const PAUSER: RoleType = ink::selector_id!("PAUSER");
+    pub const MIGRATOR: RoleType = ink::selector_id!("MIGRATOR");
+
+    /// upper bound of `VotingRules::maximum_active_proposals`.
+    /// Votes on every active proposal are synced on each stake change, so their number has to stay small.
+    pub const MAXIMUM_ACTIVE_PROPOSALS_CAP: u32 = 32;
+
+    /// maximal number of vests of an account counted as its vested votes.
+    /// Each of them is queried from the vester on every stake change of the account.
+    pub const MAXIMUM_VOTING_VESTS: u32 = 4;
 
     #[derive(StorageFieldGetter)]
     #[ink(storage)]
@@ -77,7 +108,17 @@ mod abax_governor {
         #[storage_field]
         lock: LockedSharesData,
         #[storage_field]
+        locked_assets: LockedAssetsData,
+        #[storage_field]
+        sponsorship: SponsorshipData,
+        #[storage_field]
         unstake: UnstakeData,
+        #[storage_field]
+        pause: PauseData,
+        #[storage_field]
+        vested_votes: VestedVotesData,
+        #[storage_field]
+        upgradeable: VersionData,
     }
 
     #[overrider(PSP22VaultInternal)]
@@ -88,13 +129,24 @@ mod abax_governor {
         assets: &Balance,
         shares: &Balance,
     ) -> Result<(), PSP22Error> {
+        if self._paused_messages().deposit {
+            return Err(PSP22Error::Custom("Paused".to_string()));
+        }
         self.counter.increase_counter(*shares);
         self.govern.set_last_stake_timestamp(receiver);
         self._deposit_default_impl(caller, receiver, assets, shares)?;
+        self._sync_votes(receiver)?;
 
         Ok(())
     }
 
+    // proposer deposits taken in assets don't back the shares
+    #[overrider(PSP22VaultInternal)]
+    fn _total_assets(&self) -> Balance {
+        self._total_assets_default_impl()
+            .saturating_sub(self.locked_assets.total_locked())
+    }
+
     #[overrider(PSP22VaultInternal)]
     fn _withdraw(
         &mut self,
@@ -104,11 +156,17 @@ mod abax_governor {
         assets: &Balance,
         shares: &Balance,
     ) -> Result<(), PSP22Error> {
+        // force unstake is paused separately
+        if *caller != self.env().account_id() && self._paused_messages().withdraw {
+            return Err(PSP22Error::Custom("Paused".to_string()));
+        }
         if *caller != *owner && *caller != self.env().account_id() {
             self._decrease_allowance_from_to(owner, caller, shares)?;
         }
+        self._ensure_pledged_votes_are_kept(owner, shares)?;
 
         self._burn_from(owner, shares)?;
+        self._sync_votes(owner)?;
 
         if self._balance_of(owner) == 0 {
             self.govern.remove_last_stake_timestamp(owner);
@@ -185,7 +243,11 @@ mod abax_governor {
             symbol: String,
             rules: VotingRules,
         ) -> Result<Self, GovernError> {
-            _ensure_voting_rules_and_unstake_period_are_valid(&rules, unstake_period)?;
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                &rules,
+                &CommitRevealRules::default(),
+                unstake_period,
+            )?;
 
             let mut instance = Self {
                 access_control: AccessControlData::new(Some(Self::env().account_id())),
@@ -195,7 +257,12 @@ mod abax_governor {
                 govern: GovernData::new(&rules),
                 counter: VaultCounterData::default(),
                 lock: LockedSharesData::default(),
+                locked_assets: LockedAssetsData::default(),
+                sponsorship: SponsorshipData::default(),
                 unstake: UnstakeData::new(vester, unstake_period),
+                pause: PauseData::default(),
+                vested_votes: VestedVotesData::default(),
+                upgradeable: VersionData::new(),
             };
 
             if let Some(admin) = parameters_admin {
@@ -205,16 +272,332 @@ mod abax_governor {
             instance._grant_role(EXECUTOR, Some(foundation))?;
             Ok(instance)
         }
+
+        /// Instance with the decimals of the asset given upfront, as querying them is not possible off-chain.
+        #[cfg(test)]
+        pub(crate) fn new_off_chain(
+            asset: AccountId,
+            vester: AccountId,
+            unstake_period: Timestamp,
+            rules: VotingRules,
+        ) -> Self {
+            Self {
+                access_control: AccessControlData::new(None),
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, Some(12)),
+                metadata: PSP22MetadataData::new(None, None),
+                govern: GovernData::new(&rules),
+                counter: VaultCounterData::default(),
+                lock: LockedSharesData::default(),
+                locked_assets: LockedAssetsData::default(),
+                sponsorship: SponsorshipData::default(),
+                unstake: UnstakeData::new(vester, unstake_period),
+                pause: PauseData::default(),
+                vested_votes: VestedVotesData::default(),
+                upgradeable: VersionData::new(),
+            }
+        }
+
+        /// Migrates the storage from `version` to `version + 1`, at most `count` proposals at once.
+        /// Returns true if the step is finished.
+        fn _migration_step(&mut self, version: u16, count: u32) -> Result<bool, GovernError> {
+            match version {
+                // `VotingRules` and `ProposalState` got new fields
+                0 => {
+                    let from = match self.upgradeable.migration_cursor() {
+                        Some(from) => from,
+                        None => {
+                            self.govern.migrate_rules_from_v0();
+                            0
+                        }
+                    };
+                    let cursor = self.govern.migrate_proposal_states_from_v0(from, count)?;
+                    self.upgradeable.set_migration_cursor(cursor);
+                    Ok(cursor.is_none())
+                }
+                // there are no other versions below `STORAGE_VERSION`
+                _ => Ok(true),
+            }
+        }
+
+        /// Runs the ongoing migration step on at most `count` proposals and bumps the version once the step is finished.
+        fn _migrate(&mut self, count: u32) -> Result<(), GovernError> {
+            let from = self.upgradeable.version();
+            if from >= STORAGE_VERSION {
+                return Err(GovernError::AlreadyMigrated);
+            }
+            if self._migration_step(from, count)? {
+                let to = from + 1;
+                self.upgradeable.set_version(to);
+                ink::env::emit_event::<DefaultEnvironment, Migrated>(Migrated { from, to });
+            }
+            Ok(())
+        }
+
+        fn _update_vote(
+            &mut self,
+            voter: &AccountId,
+            proposal_id: ProposalId,
+            vote: Vote,
+        ) -> Result<(), GovernError> {
+            let voter_votes = {
+                let balance = self._count_votes_of(voter)?;
+                let locked = self.lock.locked(&proposal_id);
+                let proposer = self
+                    .govern
+                    .state_of(&proposal_id)
+                    .ok_or(GovernError::ProposalDoesntExist)?
+                    .proposer;
+                if self.env().caller() == proposer {
+                    balance.checked_add(locked).ok_or(MathError::Overflow)?
+                } else {
+                    balance
+                }
+            };
+
+            self.govern
+                .update_vote_of_for(voter, &proposal_id, &vote, &voter_votes)?;
+
+            ink::env::emit_event::<DefaultEnvironment, VoteCasted>(VoteCasted {
+                account: *voter,
+                proposal_id,
+                vote,
+            });
+
+            Ok(())
+        }
+
+        fn _minimum_votes_to_propose(&self) -> Result<Balance, GovernError> {
+            Ok(mul_div(
+                self._total_votes()?,
+                u128::from(self.govern.rules().minimum_stake_part_e3),
+                1000,
+                Rounding::Down,
+            )?)
+        }
+
+        // amount of assets taken as the proposer deposit if it is not taken in shares
+        fn _proposer_deposit_in_assets(
+            &self,
+            deposit_kind: &ProposerDeposit,
+        ) -> Result<Balance, GovernError> {
+            match deposit_kind {
+                ProposerDeposit::FixedAssets(assets) => Ok(*assets),
+                _ => {
+                    let proposer_deposit = mul_div(
+                        self._minimum_votes_to_propose()?,
+                        self.govern.rules().proposer_deposit_part_e3 as u128,
+                        1000,
+                        Rounding::Down,
+                    )?;
+                    Ok(self._preview_redeem(&proposer_deposit)?)
+                }
+            }
+        }
+
+        fn _pull_assets(&mut self, from: &AccountId, assets: Balance) -> Result<(), GovernError> {
+            self.vault
+                .asset()
+                .call_mut()
+                .transfer_from(*from, self.env().account_id(), assets, Vec::<u8>::new())
+                .call_v1()
+                .invoke()?;
+            Ok(())
+        }
+
+        /// Updates votes of `account` on all active proposals to its current votes.
+        pub(crate) fn _sync_votes(&mut self, account: &AccountId) -> Result<(), MathError> {
+            let balance = self._count_votes_of(account)?;
+            for proposal_id in self.govern.active_proposal_ids() {
+                if self.govern.vote_of_for(account, &proposal_id).is_none() {
+                    continue;
+                }
+                let amount = match self.govern.state_of(&proposal_id) {
+                    Some(state) if state.proposer == *account => balance
+                        .checked_add(self.lock.locked(&proposal_id))
+                        .ok_or(MathError::Overflow)?,
+                    _ => balance,
+                };
+                self.govern
+                    .sync_vote_amount(account, &proposal_id, &amount)?;
+            }
+            Ok(())
+        }
+
+        /// Votes pledged in sponsorships must stay staked until the sponsorships end.
+        /// Returns `PledgedVotesLocked` if after withdrawing `shares` the remaining shares of `owner`
+        /// together with its sponsor deposits would be lower than votes pledged by `owner`.
+        pub(crate) fn _ensure_pledged_votes_are_kept(
+            &self,
+            owner: &AccountId,
+            shares: &Balance,
+        ) -> Result<(), PSP22Error> {
+            let remaining_votes = self
+                ._balance_of(owner)
+                .saturating_sub(*shares)
+                .saturating_add(self.sponsorship.deposited_by(owner));
+            if remaining_votes < self.sponsorship.pledged_by(owner) {
+                return Err(PSP22Error::Custom("PledgedVotesLocked".to_string()));
+            }
+            Ok(())
+        }
+
+        /// Returns votes of `account` - its shares and its discounted vesting assets.
+        pub(crate) fn _votes_of(&self, account: &AccountId) -> Result<Balance, MathError> {
+            self._balance_of(account)
+                .checked_add(self._vested_votes_of(account)?)
+                .ok_or(MathError::Overflow)
+        }
+
+        /// Returns `_votes_of` `account` and records its vested votes as counted.
+        /// An increase of the counted vested votes increases the counter like a deposit,
+        /// so that vested votes casted after the start of a proposal are included in its total votes.
+        fn _count_votes_of(&mut self, account: &AccountId) -> Result<Balance, MathError> {
+            let vested_votes = self._vested_votes_of(account)?;
+            let increase = self.vested_votes.set_counted(account, &vested_votes)?;
+            self.counter.increase_counter(increase);
+            self._balance_of(account)
+                .checked_add(vested_votes)
+                .ok_or(MathError::Overflow)
+        }
+
+        /// Returns all votes - the total supply of shares and the counted vested votes.
+        fn _total_votes(&self) -> Result<Balance, MathError> {
+            self._total_supply()
+                .checked_add(self.vested_votes.total_counted())
+                .ok_or(MathError::Overflow)
+        }
+
+        /// Returns votes of `account` coming from its assets that are not yet released by the vester of `VestedVotes`.
+        /// The assets are converted to shares and discounted by `discount_e3`. Vests that can't be queried are skipped.
+        /// Only the first `MAXIMUM_VOTING_VESTS` vests with a constant schedule (the one used by the TGE) are counted,
+        /// so vests of assets withdrawn from the governor (with an external schedule) don't carry votes.
+        /// Releasing the assets doesn't update the casted and counted votes - they are updated on the next vote, stake change or `sync_votes` of `account`.
+        /// Until then released assets deposited by another account would be counted twice, so `sync_votes` should be called for `account` after a release.
+        fn _vested_votes_of(&self, account: &AccountId) -> Result<Balance, MathError> {
+            let vested_votes = match self.vested_votes.vested_votes() {
+                Some(vested_votes) if vested_votes.discount_e3 > 0 => vested_votes,
+                _ => return Ok(0),
+            };
+            let vester: GeneralVestRef = vested_votes.vester.into();
+            let asset = Some(self.vault.asset().to_account_id());
+            let vests_count = match vester
+                .call()
+                .next_id_vest_of(*account, asset, Vec::<u8>::new())
+                .call_v1()
+                .try_invoke()
+            {
+                Ok(Ok(vests_count)) => vests_count,
+                _ => return Ok(0),
+            };
+            let mut unreleased: Balance = 0;
+            for id in 0..vests_count.min(MAXIMUM_VOTING_VESTS) {
+                if let Ok(Ok(Some(
+                    vest @ VestingData {
+                        schedule: VestingSchedule::Constant(..),
+                        ..
+                    },
+                ))) = vester
+                    .call()
+                    .vesting_schedule_of(*account, asset, id, Vec::<u8>::new())
+                    .call_v1()
+                    .try_invoke()
+                {
+                    unreleased = unreleased
+                        .checked_add(vest.amount.saturating_sub(vest.released))
+                        .ok_or(MathError::Overflow)?;
+                }
+            }
+            mul_div(
+                self._preview_deposit(&unreleased)?,
+                u128::from(vested_votes.discount_e3),
+                1000,
+                Rounding::Down,
+            )
+        }
+
+        fn _paused_messages(&self) -> PausedMessages {
+            self.pause
+                .is_paused(self.env().block_timestamp())
+                .unwrap_or_default()
+        }
     }
 
     impl AbaxGovern for AbaxGovernor {
         #[ink(message)]
         fn propose(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError> {
-            self._propose(&self.env().caller(), &proposal)
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._propose(&self.env().caller(), &proposal, false)
+        }
+
+        #[ink(message)]
+        fn propose_commit_reveal(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError> {
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._propose(&self.env().caller(), &proposal, true)
+        }
+
+        #[ink(message)]
+        fn create_draft(&mut self, proposal: Proposal) -> Result<ProposalHash, GovernError> {
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._create_draft(&self.env().caller(), &proposal)
+        }
+
+        #[ink(message)]
+        fn sponsor(
+            &mut self,
+            proposal_hash: ProposalHash,
+            amount: Balance,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._sponsor(&self.env().caller(), &proposal_hash, amount)
+        }
+
+        #[ink(message)]
+        fn unsponsor(&mut self, proposal_hash: ProposalHash) -> Result<(), GovernError> {
+            self._unsponsor(&self.env().caller(), &proposal_hash)
+        }
+
+        #[ink(message)]
+        fn cancel_draft(&mut self, proposal_hash: ProposalHash) -> Result<(), GovernError> {
+            self._cancel_draft(&self.env().caller(), &proposal_hash)
+        }
+
+        #[ink(message)]
+        fn deposit_with_permit(
+            &mut self,
+            assets: Balance,
+            receiver: AccountId,
+            deadline: Timestamp,
+            signature: [u8; 65],
+        ) -> Result<Balance, GovernError> {
+            let mut asset: PSP22PermitRef = self.vault.asset().to_account_id().into();
+            asset
+                .call_mut()
+                .permit(
+                    self.env().caller(),
+                    self.env().account_id(),
+                    assets,
+                    deadline,
+                    signature,
+                )
+                .call_v1()
+                .invoke()?;
+            Ok(PSP22Vault::deposit(self, assets, receiver)?)
         }
 
         #[ink(message)]
         fn finalize(&mut self, proposal_id: ProposalId) -> Result<(), GovernError> {
+            if self._paused_messages().finalize {
+                return Err(GovernError::Paused);
+            }
             self._finalize(&proposal_id)
         }
 
@@ -231,18 +614,54 @@ mod abax_governor {
             vote: Vote,
             _reason: Vec<u8>,
         ) -> Result<(), GovernError> {
+            if self._paused_messages().vote {
+                return Err(GovernError::Paused);
+            }
             self._cast_vote(&self.env().caller(), proposal_id, vote, _reason)
         }
 
+        #[ink(message)]
+        fn commit_vote(
+            &mut self,
+            proposal_id: ProposalId,
+            vote_hash: Hash,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().vote {
+                return Err(GovernError::Paused);
+            }
+            self._commit_vote(&self.env().caller(), proposal_id, vote_hash)
+        }
+
+        #[ink(message)]
+        fn reveal_vote(
+            &mut self,
+            proposal_id: ProposalId,
+            vote: Vote,
+            salt: Hash,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().vote {
+                return Err(GovernError::Paused);
+            }
+            self._reveal_vote(&self.env().caller(), proposal_id, vote, salt)
+        }
+
         #[ink(message)]
         fn force_unstake(
             &mut self,
             account: AccountId,
             proposal_id: ProposalId,
         ) -> Result<(), GovernError> {
+            if self._paused_messages().force_unstake {
+                return Err(GovernError::Paused);
+            }
             self._force_unstake(&account, &proposal_id)?;
             Ok(())
         }
+
+        #[ink(message)]
+        fn sync_votes(&mut self, account: AccountId) -> Result<(), GovernError> {
+            Ok(self._sync_votes(&account)?)
+        }
     }
 
     impl AbaxGovernManage for AbaxGovernor {
@@ -250,6 +669,7 @@ mod abax_governor {
         fn change_voting_rules(&mut self, rules: VotingRules) -> Result<(), GovernError> {
             _ensure_voting_rules_and_unstake_period_are_valid(
                 &rules,
+                &self.commit_reveal_rules(),
                 self.unstake.unstake_period(),
             )?;
             self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
@@ -260,9 +680,45 @@ mod abax_governor {
             Ok(())
         }
 
+        #[ink(message)]
+        fn change_commit_reveal_rules(
+            &mut self,
+            rules: CommitRevealRules,
+        ) -> Result<(), GovernError> {
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                &self.rules(),
+                &rules,
+                self.unstake.unstake_period(),
+            )?;
+            self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
+            self.govern.change_commit_reveal_rules(&rules);
+            ink::env::emit_event::<DefaultEnvironment, CommitRevealRulesChanged>(
+                CommitRevealRulesChanged { rules },
+            );
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_proposer_deposit(&mut self, deposit: ProposerDeposit) -> Result<(), GovernError> {
+            self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
+            // free proposals would allow to spam
+            if deposit == ProposerDeposit::FixedAssets(0) {
+                return Err(GovernError::InvalidProposerDeposit);
+            }
+            self.govern.change_proposer_deposit(&deposit);
+            ink::env::emit_event::<DefaultEnvironment, ProposerDepositChanged>(
+                ProposerDepositChanged { deposit },
+            );
+            Ok(())
+        }
+
         #[ink(message)]
         fn change_unstake_period(&mut self, period: Timestamp) -> Result<(), GovernError> {
-            _ensure_voting_rules_and_unstake_period_are_valid(&self.rules(), period)?;
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                &self.rules(),
+                &self.commit_reveal_rules(),
+                period,
+            )?;
             self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
             self.unstake.set_unstake_period(period);
             ink::env::emit_event::<DefaultEnvironment, UnstakePeriodChanged>(
@@ -272,6 +728,55 @@ mod abax_governor {
             );
             Ok(())
         }
+
+        #[ink(message)]
+        fn pause(
+            &mut self,
+            messages: PausedMessages,
+            duration: Timestamp,
+        ) -> Result<(), GovernError> {
+            self._ensure_has_role(PAUSER, Some(self.env().caller()))?;
+            let pause = self
+                .pause
+                .set_pause(&messages, duration, self.env().block_timestamp())?;
+            ink::env::emit_event::<DefaultEnvironment, Paused>(Paused {
+                messages,
+                until: pause.end,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn unpause(&mut self) -> Result<(), GovernError> {
+            self._ensure_has_role(PAUSER, Some(self.env().caller()))?;
+            self.pause.unpause(self.env().block_timestamp())?;
+            ink::env::emit_event::<DefaultEnvironment, Unpaused>(Unpaused {});
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn migrate(&mut self, count: u32) -> Result<(), GovernError> {
+            self._ensure_has_role(MIGRATOR, Some(self.env().caller()))?;
+            self._migrate(count)
+        }
+
+        #[ink(message)]
+        fn set_vested_votes(
+            &mut self,
+            vested_votes: Option<VestedVotes>,
+        ) -> Result<(), GovernError> {
+            self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
+            if let Some(VestedVotes { discount_e3, .. }) = vested_votes {
+                if discount_e3 > 1000 {
+                    return Err(GovernError::InvalidDiscount);
+                }
+            }
+            self.vested_votes.set_vested_votes(&vested_votes);
+            ink::env::emit_event::<DefaultEnvironment, VestedVotesChanged>(VestedVotesChanged {
+                vested_votes,
+            });
+            Ok(())
+        }
     }
 
     impl AbaxGovernView for AbaxGovernor {
@@ -300,6 +805,41 @@ mod abax_governor {
             self.govern.rules()
         }
 
+        #[ink(message)]
+        fn commit_reveal_rules(&self) -> CommitRevealRules {
+            self.govern.commit_reveal_rules()
+        }
+
+        #[ink(message)]
+        fn proposer_deposit(&self) -> ProposerDeposit {
+            self.govern.proposer_deposit()
+        }
+
+        #[ink(message)]
+        fn locked_assets(&self, proposal_id: ProposalId) -> Balance {
+            self.locked_assets.locked(&proposal_id)
+        }
+
+        #[ink(message)]
+        fn locked_draft_assets(&self, proposal_hash: ProposalHash) -> Balance {
+            self.locked_assets.locked_for_draft(&proposal_hash)
+        }
+
+        #[ink(message)]
+        fn draft(&self, proposal_hash: ProposalHash) -> Option<Draft> {
+            self.sponsorship.draft(&proposal_hash)
+        }
+
+        #[ink(message)]
+        fn sponsorships(&self, proposal_hash: ProposalHash) -> Vec<Sponsorship> {
+            self.sponsorship.sponsorships(&proposal_hash)
+        }
+
+        #[ink(message)]
+        fn pledged_by(&self, sponsor: AccountId) -> Balance {
+            self.sponsorship.pledged_by(&sponsor)
+        }
+
         #[ink(message)]
         fn status(&self, proposal_id: ProposalId) -> Option<ProposalStatus> {
             self.govern.state_of(&proposal_id).map(|state| state.status)
@@ -307,24 +847,28 @@ mod abax_governor {
 
         #[ink(message)]
         fn minimum_to_finalize(&self, proposal_id: ProposalId) -> Option<Balance> {
-            let state = match self.govern.state_of(&proposal_id) {
-                Some(state) => state,
-                None => return None,
-            };
+            let state = self.govern.state_of(&proposal_id)?;
 
             if state.status != ProposalStatus::Active {
                 return None;
             }
 
-            Some(
-                minimum_to_finalize(
-                    &state,
-                    &self.rules(),
-                    ink::env::block_timestamp::<DefaultEnvironment>(),
-                    self.counter.counter(),
-                )
-                .unwrap(),
-            )
+            let now = ink::env::block_timestamp::<DefaultEnvironment>();
+            let voting_paused_duration = self.pause.voting_paused_duration(now).ok()?;
+            let voting_now = voting_time(&state, now, voting_paused_duration).ok()?;
+
+            minimum_to_finalize(&state, &self.rules(), voting_now, self.counter.counter()).ok()
+        }
+
+        #[ink(message)]
+        fn minimum_quorum(&self, proposal_id: ProposalId) -> Option<Balance> {
+            let state = self.govern.state_of(&proposal_id)?;
+
+            if state.status != ProposalStatus::Active {
+                return None;
+            }
+
+            Some(minimum_quorum(&state, &self.rules(), self.counter.counter()).unwrap())
         }
 
         #[ink(message)]
@@ -337,6 +881,22 @@ mod abax_governor {
             self.govern.vote_of_for(&account, &proposal_id)
         }
 
+        #[ink(message)]
+        fn commit_of_for(&self, account: AccountId, proposal_id: ProposalId) -> Option<Hash> {
+            self.govern.commit_of_for(&account, &proposal_id)
+        }
+
+        #[ink(message)]
+        fn hash_vote(
+            &self,
+            account: AccountId,
+            proposal_id: ProposalId,
+            vote: Vote,
+            salt: Hash,
+        ) -> Hash {
+            hash_vote(&account, &proposal_id, &vote, &salt)
+        }
+
         #[ink(message)]
         fn last_force_unstakes(&self, account: AccountId) -> Option<ProposalId> {
             self.govern.last_force_unstake(&account)
@@ -352,6 +912,21 @@ mod abax_governor {
             self.govern.active_proposals()
         }
 
+        #[ink(message)]
+        fn active_proposal_ids(&self) -> Vec<ProposalId> {
+            self.govern.active_proposal_ids()
+        }
+
+        #[ink(message)]
+        fn active_proposals_of(&self, account: AccountId) -> u32 {
+            self.govern.active_proposals_of(&account)
+        }
+
+        #[ink(message)]
+        fn last_proposal_timestamp_of(&self, account: AccountId) -> Option<Timestamp> {
+            self.govern.last_proposal_timestamp(&account)
+        }
+
         #[ink(message)]
         fn finalized_proposals(&self) -> u32 {
             self.govern.finalized_proposals()
@@ -374,6 +949,26 @@ mod abax_governor {
         fn description_hash_by_proposal_id(&self, proposal_id: ProposalId) -> Option<Hash> {
             self.govern.proposal_id_to_description_hash(&proposal_id)
         }
+
+        #[ink(message)]
+        fn pause_info(&self) -> Option<Pause> {
+            self.pause.pause()
+        }
+
+        #[ink(message)]
+        fn storage_version(&self) -> u16 {
+            self.upgradeable.version()
+        }
+
+        #[ink(message)]
+        fn vested_votes(&self) -> Option<VestedVotes> {
+            self.vested_votes.vested_votes()
+        }
+
+        #[ink(message)]
+        fn vested_votes_of(&self, account: AccountId) -> Balance {
+            self._vested_votes_of(&account).unwrap_or_default()
+        }
     }
 
     impl AbaxGovernInternal for AbaxGovernor {
@@ -381,16 +976,13 @@ mod abax_governor {
             &mut self,
             proposer: &AccountId,
             proposal: &Proposal,
+            commit_reveal: bool,
         ) -> Result<ProposalId, GovernError> {
+            let deposit_kind = self.govern.proposer_deposit();
             //check if the proposer has enough votes to create a proposal
-            let total_votes = self._total_supply();
-            let minimum_votes_to_propose = mul_div(
-                total_votes,
-                u128::from(self.govern.rules().minimum_stake_part_e3),
-                1000,
-                Rounding::Down,
-            )?;
-
+            // non-stakers can propose only through drafts sponsored by stakers
+            let total_votes = self._total_votes()?;
+            let minimum_votes_to_propose = self._minimum_votes_to_propose()?;
             let proposer_votes = self._balance_of(proposer);
             if proposer_votes < minimum_votes_to_propose {
                 return Err(GovernError::InsuficientVotes);
@@ -413,11 +1005,24 @@ mod abax_governor {
                 &proposal.description_hash,
                 total_votes,
                 self.counter.counter(),
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+                commit_reveal,
             )?;
 
-            self.lock.lock(&proposal_id, proposer_deposit)?;
+            match deposit_kind {
+                ProposerDeposit::Shares => {
+                    self.lock.lock(&proposal_id, proposer_deposit)?;
 
-            self._transfer(proposer, &self.env().account_id(), &proposer_deposit)?;
+                    self._transfer(proposer, &self.env().account_id(), &proposer_deposit)?;
+                    self._sync_votes(proposer)?;
+                }
+                ProposerDeposit::Assets | ProposerDeposit::FixedAssets(_) => {
+                    let assets = self._proposer_deposit_in_assets(&deposit_kind)?;
+                    self.locked_assets.lock(&proposal_id, assets)?;
+                    self._pull_assets(proposer, assets)?;
+                }
+            }
 
             ink::env::emit_event::<DefaultEnvironment, ProposalCreated>(ProposalCreated {
                 proposal_id,
@@ -427,6 +1032,176 @@ mod abax_governor {
             Ok(proposal_id)
         }
 
+        fn _create_draft(
+            &mut self,
+            proposer: &AccountId,
+            proposal: &Proposal,
+        ) -> Result<ProposalHash, GovernError> {
+            let deposit_kind = self.govern.proposer_deposit();
+            // with the deposit in assets non-stakers can create drafts
+            if deposit_kind == ProposerDeposit::Shares && self._balance_of(proposer) == 0 {
+                return Err(GovernError::InsuficientVotes);
+            }
+            let proposal_hash = hash_proposal(proposal);
+            if self.govern.proposal_hash_to_id(&proposal_hash).is_some() {
+                return Err(GovernError::ProposalAlreadyExists);
+            }
+
+            self.sponsorship.create_draft(
+                proposer,
+                &proposal_hash,
+                proposal,
+                self.env().block_timestamp(),
+            )?;
+            if deposit_kind != ProposerDeposit::Shares {
+                let assets = self._proposer_deposit_in_assets(&deposit_kind)?;
+                self.locked_assets.lock_for_draft(&proposal_hash, assets)?;
+                self._pull_assets(proposer, assets)?;
+            }
+
+            ink::env::emit_event::<DefaultEnvironment, DraftCreated>(DraftCreated {
+                proposal_hash,
+                proposer: *proposer,
+                proposal: proposal.clone(),
+            });
+            Ok(proposal_hash)
+        }
+
+        fn _sponsor(
+            &mut self,
+            sponsor: &AccountId,
+            proposal_hash: &ProposalHash,
+            amount: Balance,
+        ) -> Result<(), GovernError> {
+            // the same votes can't back more than one sponsorship
+            let pledged = self
+                .sponsorship
+                .pledged_by(sponsor)
+                .checked_add(amount)
+                .ok_or(MathError::Overflow)?;
+            let sponsor_votes = self
+                ._balance_of(sponsor)
+                .checked_add(self.sponsorship.deposited_by(sponsor))
+                .ok_or(MathError::Overflow)?;
+            if amount == 0 || sponsor_votes < pledged {
+                return Err(GovernError::InsuficientVotes);
+            }
+
+            // sponsor deposits the same part of pledged votes as the proposer does
+            let deposit = mul_div(
+                amount,
+                self.govern.rules().proposer_deposit_part_e3 as u128,
+                1000,
+                Rounding::Down,
+            )?;
+            let draft = self.sponsorship.sponsor(
+                proposal_hash,
+                sponsor,
+                amount,
+                deposit,
+                self.env().block_timestamp(),
+            )?;
+
+            self._transfer(sponsor, &self.env().account_id(), &deposit)?;
+            self._sync_votes(sponsor)?;
+
+            ink::env::emit_event::<DefaultEnvironment, Sponsored>(Sponsored {
+                proposal_hash: *proposal_hash,
+                sponsor: *sponsor,
+                pledged: amount,
+                deposit,
+            });
+
+            let total_votes = self._total_votes()?;
+            if draft.pledged < self._minimum_votes_to_propose()? {
+                return Ok(());
+            }
+
+            // activate the draft
+            let (draft, proposal) = self.sponsorship.take_draft(proposal_hash)?;
+            let proposal_id = self.govern.register_new_proposal(
+                &draft.proposer,
+                proposal_hash,
+                proposal.earliest_execution,
+                &proposal.description_url,
+                &proposal.description_hash,
+                total_votes,
+                self.counter.counter(),
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+                false,
+            )?;
+            // sponsors' deposits are returned or slashed on finalization
+            self.lock.lock_by_sponsors(&proposal_id, draft.deposit)?;
+            // the proposer deposit in assets now backs the proposal
+            let locked_assets = self.locked_assets.unlock_for_draft(proposal_hash)?;
+            if locked_assets > 0 {
+                self.locked_assets.lock(&proposal_id, locked_assets)?;
+            }
+
+            ink::env::emit_event::<DefaultEnvironment, ProposalCreated>(ProposalCreated {
+                proposal_id,
+                proposal_hash: *proposal_hash,
+                proposal,
+            });
+            Ok(())
+        }
+
+        fn _unsponsor(
+            &mut self,
+            sponsor: &AccountId,
+            proposal_hash: &ProposalHash,
+        ) -> Result<(), GovernError> {
+            let sponsorship = self.sponsorship.unsponsor(proposal_hash, sponsor)?;
+
+            self._transfer(&self.env().account_id(), sponsor, &sponsorship.deposit)?;
+            self._sync_votes(sponsor)?;
+
+            ink::env::emit_event::<DefaultEnvironment, Unsponsored>(Unsponsored {
+                proposal_hash: *proposal_hash,
+                sponsor: *sponsor,
+                pledged: sponsorship.pledged,
+                deposit: sponsorship.deposit,
+            });
+            Ok(())
+        }
+
+        fn _cancel_draft(
+            &mut self,
+            caller: &AccountId,
+            proposal_hash: &ProposalHash,
+        ) -> Result<(), GovernError> {
+            let (draft, sponsorships) = self.sponsorship.cancel_draft(
+                proposal_hash,
+                caller,
+                self.env().block_timestamp(),
+            )?;
+
+            for sponsorship in sponsorships {
+                self._transfer(
+                    &self.env().account_id(),
+                    &sponsorship.sponsor,
+                    &sponsorship.deposit,
+                )?;
+                self._sync_votes(&sponsorship.sponsor)?;
+            }
+            let locked_assets = self.locked_assets.unlock_for_draft(proposal_hash)?;
+            if locked_assets > 0 {
+                self.vault
+                    .asset()
+                    .call_mut()
+                    .transfer(draft.proposer, locked_assets, Vec::<u8>::new())
+                    .call_v1()
+                    .invoke()?;
+            }
+
+            ink::env::emit_event::<DefaultEnvironment, DraftCancelled>(DraftCancelled {
+                proposal_hash: *proposal_hash,
+                caller: *caller,
+            });
+            Ok(())
+        }
+
         fn _cast_vote(
             &mut self,
             voter: &AccountId,
@@ -434,45 +1209,106 @@ mod abax_governor {
             vote: Vote,
             #[allow(unused_variables)] reason: Vec<u8>,
         ) -> Result<(), GovernError> {
-            let voter_votes = {
-                let balance = self._balance_of(voter);
-                let locked = self.lock.locked(&proposal_id);
-                let proposer = self
-                    .govern
-                    .state_of(&proposal_id)
-                    .ok_or(GovernError::ProposalDoesntExist)?
-                    .proposer;
-                if self.env().caller() == proposer {
-                    balance.checked_add(locked).ok_or(MathError::Overflow)?
-                } else {
-                    balance
-                }
-            };
+            if self
+                .govern
+                .state_of(&proposal_id)
+                .ok_or(GovernError::ProposalDoesntExist)?
+                .commit_reveal
+            {
+                return Err(GovernError::WrongVotingMode);
+            }
 
-            self.govern
-                .update_vote_of_for(voter, &proposal_id, &vote, &voter_votes)?;
+            self._update_vote(voter, proposal_id, vote)
+        }
 
-            ink::env::emit_event::<DefaultEnvironment, VoteCasted>(VoteCasted {
+        fn _commit_vote(
+            &mut self,
+            voter: &AccountId,
+            proposal_id: ProposalId,
+            vote_hash: Hash,
+        ) -> Result<(), GovernError> {
+            self.govern.commit_vote(
+                voter,
+                &proposal_id,
+                &vote_hash,
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+            )?;
+
+            ink::env::emit_event::<DefaultEnvironment, VoteCommitted>(VoteCommitted {
                 account: *voter,
                 proposal_id,
-                vote,
             });
 
             Ok(())
         }
 
+        fn _reveal_vote(
+            &mut self,
+            voter: &AccountId,
+            proposal_id: ProposalId,
+            vote: Vote,
+            salt: Hash,
+        ) -> Result<(), GovernError> {
+            let commit = self.govern.take_commit(
+                voter,
+                &proposal_id,
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+            )?;
+
+            if commit != hash_vote(voter, &proposal_id, &vote, &salt) {
+                return Err(GovernError::CommitMismatch);
+            }
+
+            self._update_vote(voter, proposal_id, vote)
+        }
+
         fn _finalize(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError> {
-            let status = self.govern.finalize(proposal_id, self.counter.counter())?;
+            let status = self.govern.finalize(
+                proposal_id,
+                self.counter.counter(),
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+            )?;
+
+            let locked_assets = self.locked_assets.locked(proposal_id);
+            // slashed deposit in assets stays in the vault and is shared among stakers
+            self.locked_assets.unlock(proposal_id, locked_assets)?;
 
             // return the proposer deposit if proposal was not 'DefeatedWithSlash'
             if status != ProposalStatus::DefeatedWithSlash {
                 let locked = self.lock.locked(proposal_id);
-                self.lock.unlock(proposal_id, locked)?;
-                self._transfer(
-                    &self.env().account_id(),
-                    &self.govern.state_of(proposal_id).unwrap().proposer,
-                    &locked,
-                )?;
+                let proposer = self.govern.state_of(proposal_id).unwrap().proposer;
+                if locked > 0 {
+                    self.lock.unlock(proposal_id, locked)?;
+                    self._transfer(&self.env().account_id(), &proposer, &locked)?;
+                    self._sync_votes(&proposer)?;
+                }
+                if locked_assets > 0 {
+                    self.vault
+                        .asset()
+                        .call_mut()
+                        .transfer(proposer, locked_assets, Vec::<u8>::new())
+                        .call_v1()
+                        .invoke()?;
+                }
+            }
+
+            // sponsors' deposits are returned or slashed the same way as the proposer's deposit
+            let proposal_hash = self.govern.proposal_id_to_hash(proposal_id).unwrap();
+            let sponsorships = self.sponsorship.take_sponsorships(&proposal_hash)?;
+            if status != ProposalStatus::DefeatedWithSlash {
+                for sponsorship in sponsorships {
+                    self.lock
+                        .unlock_by_sponsors(proposal_id, sponsorship.deposit)?;
+                    self._transfer(
+                        &self.env().account_id(),
+                        &sponsorship.sponsor,
+                        &sponsorship.deposit,
+                    )?;
+                    self._sync_votes(&sponsorship.sponsor)?;
+                }
             }
 
             ink::env::emit_event::<DefaultEnvironment, ProposalFinalized>(ProposalFinalized {
@@ -563,6 +1399,7 @@ mod abax_governor {
 
     fn _ensure_voting_rules_and_unstake_period_are_valid(
         rules: &VotingRules,
+        commit_reveal_rules: &CommitRevealRules,
         unstake_period: Timestamp,
     ) -> Result<(), GovernError> {
         if rules
@@ -571,10 +1408,632 @@ mod abax_governor {
             .ok_or(MathError::Overflow)?
             .checked_add(rules.final_period)
             .ok_or(MathError::Overflow)?
+            .checked_add(commit_reveal_rules.reveal_period)
+            .ok_or(MathError::Overflow)?
             > unstake_period
         {
             return Err(GovernError::UnstakeShorterThanVotingPeriod);
         }
+        if rules.minimum_quorum_e3 > 1000 {
+            return Err(GovernError::InvalidQuorum);
+        }
+        if rules.maximum_active_proposals == 0
+            || rules.maximum_active_proposals > MAXIMUM_ACTIVE_PROPOSALS_CAP
+        {
+            return Err(GovernError::InvalidMaximumActiveProposals);
+        }
+        if rules.maximum_active_proposals_per_proposer == 0
+            || rules.maximum_active_proposals_per_proposer > rules.maximum_active_proposals
+        {
+            return Err(GovernError::InvalidMaximumActiveProposalsPerProposer);
+        }
         Ok(())
     }
+
+    #[cfg(test)]
+    mod tests {
+        use ink::storage::{traits::ManualKey, Lazy, Mapping};
+
+        use super::*;
+        use crate::modules::govern::{
+            storage::govern_storage_item::{
+                STORAGE_KEY_GOVERNDATA_NEXT_PROPOSAL_ID, STORAGE_KEY_GOVERNDATA_RULES,
+                STORAGE_KEY_GOVERNDATA_STATE,
+            },
+            traits::{ProposalStateV0, VotingRulesV0},
+        };
+
+        const DAY: Timestamp = 24 * 60 * 60 * 1000;
+        const ALICE: [u8; 32] = [1; 32];
+        const BOB: [u8; 32] = [2; 32];
+
+        fn rules() -> VotingRules {
+            VotingRules {
+                minimum_stake_part_e3: 10,
+                proposer_deposit_part_e3: 100,
+                initial_period: 3 * DAY,
+                flat_period: 7 * DAY,
+                final_period: 4 * DAY,
+                minimum_quorum_e3: 0,
+                maximum_active_proposals: 10,
+                maximum_active_proposals_per_proposer: 10,
+                minimum_proposal_interval: 0,
+                force_unstake_grace_period: 0,
+            }
+        }
+
+        fn validate(rules: &VotingRules) -> Result<(), GovernError> {
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                rules,
+                &CommitRevealRules { reveal_period: 0 },
+                180 * DAY,
+            )
+        }
+
+        #[test]
+        fn minimum_quorum_is_validated() {
+            for minimum_quorum_e3 in [0, 300, 1000] {
+                assert!(validate(&VotingRules {
+                    minimum_quorum_e3,
+                    ..rules()
+                })
+                .is_ok());
+            }
+            assert!(matches!(
+                validate(&VotingRules {
+                    minimum_quorum_e3: 1001,
+                    ..rules()
+                }),
+                Err(GovernError::InvalidQuorum)
+            ));
+        }
+
+        #[test]
+        fn maximum_active_proposals_is_validated() {
+            for maximum_active_proposals in [1, MAXIMUM_ACTIVE_PROPOSALS_CAP] {
+                assert!(validate(&VotingRules {
+                    maximum_active_proposals,
+                    maximum_active_proposals_per_proposer: 1,
+                    ..rules()
+                })
+                .is_ok());
+            }
+            for maximum_active_proposals in [0, MAXIMUM_ACTIVE_PROPOSALS_CAP + 1, u32::MAX] {
+                assert!(matches!(
+                    validate(&VotingRules {
+                        maximum_active_proposals,
+                        ..rules()
+                    }),
+                    Err(GovernError::InvalidMaximumActiveProposals)
+                ));
+            }
+        }
+
+        #[test]
+        fn maximum_active_proposals_per_proposer_is_validated() {
+            for maximum_active_proposals_per_proposer in [1, 10] {
+                assert!(validate(&VotingRules {
+                    maximum_active_proposals_per_proposer,
+                    ..rules()
+                })
+                .is_ok());
+            }
+            for maximum_active_proposals_per_proposer in [0, 11, u32::MAX] {
+                assert!(matches!(
+                    validate(&VotingRules {
+                        maximum_active_proposals_per_proposer,
+                        ..rules()
+                    }),
+                    Err(GovernError::InvalidMaximumActiveProposalsPerProposer)
+                ));
+            }
+        }
+
+        fn register(
+            governor: &mut AbaxGovernor,
+            votes_at_start: Balance,
+            voting_paused_at_start: Timestamp,
+            commit_reveal: bool,
+        ) -> ProposalId {
+            governor
+                .govern
+                .register_new_proposal(
+                    &ALICE.into(),
+                    &Hash::from([0; 32]),
+                    None,
+                    &String::from("url"),
+                    &Hash::from([0; 32]),
+                    votes_at_start,
+                    0,
+                    voting_paused_at_start,
+                    commit_reveal,
+                )
+                .unwrap()
+        }
+
+        #[ink::test]
+        fn minimum_to_finalize_is_extended_by_paused_voting() {
+            let start = 1_000 * DAY;
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(start);
+            let mut governor =
+                AbaxGovernor::new_off_chain(ALICE.into(), BOB.into(), 180 * DAY, rules());
+            let proposal_id = register(&mut governor, 1_000, 0, false);
+            let paused_voting = PausedMessages {
+                vote: true,
+                ..Default::default()
+            };
+            governor
+                .pause
+                .set_pause(&paused_voting, 2 * DAY, start)
+                .unwrap();
+
+            // 4 days after the start, but only 2 days of voting time - still the initial period
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(start + 4 * DAY);
+            assert_eq!(governor.minimum_to_finalize(proposal_id), Some(666));
+
+            // voting resumes where it was paused
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(start + 5 * DAY);
+            assert_eq!(governor.minimum_to_finalize(proposal_id), Some(500));
+        }
+
+        #[ink::test]
+        fn minimum_to_finalize_returns_none_instead_of_trapping() {
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(1_000 * DAY);
+            let mut governor =
+                AbaxGovernor::new_off_chain(ALICE.into(), BOB.into(), 180 * DAY, rules());
+            assert_eq!(governor.minimum_to_finalize(0), None);
+
+            // paused duration at the start above the current one makes the voting time underflow
+            let proposal_id = register(&mut governor, 1_000, DAY, false);
+            assert_eq!(governor.minimum_to_finalize(proposal_id), None);
+        }
+
+        const START: Timestamp = 1_000 * DAY;
+        // end of the final period of proposals registered at `START`
+        const FINAL_PERIOD_END: Timestamp = START + 14 * DAY;
+        const REVEAL_PERIOD: Timestamp = DAY;
+
+        fn set_time(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+        }
+
+        /// Commit-reveal proposal of `ALICE` in which `BOB` has 100 votes out of 1000.
+        fn commit_reveal_setup() -> (AbaxGovernor, ProposalId) {
+            set_time(START);
+            let mut governor =
+                AbaxGovernor::new_off_chain(ALICE.into(), BOB.into(), 180 * DAY, rules());
+            governor
+                .govern
+                .change_commit_reveal_rules(&CommitRevealRules {
+                    reveal_period: REVEAL_PERIOD,
+                });
+            governor._mint_to(&BOB.into(), &100).unwrap();
+            let proposal_id = register(&mut governor, 1_000, 0, true);
+            ink::env::test::set_caller::<DefaultEnvironment>(BOB.into());
+            (governor, proposal_id)
+        }
+
+        fn commit(governor: &mut AbaxGovernor, proposal_id: ProposalId, vote: Vote, salt: u8) {
+            let vote_hash = hash_vote(&BOB.into(), &proposal_id, &vote, &Hash::from([salt; 32]));
+            governor.commit_vote(proposal_id, vote_hash).unwrap();
+        }
+
+        #[ink::test]
+        fn committed_vote_is_counted_only_when_revealed() {
+            let (mut governor, proposal_id) = commit_reveal_setup();
+            commit(&mut governor, proposal_id, Vote::Agreed, 1);
+            assert_eq!(governor.vote_of_for(BOB.into(), proposal_id), None);
+            assert_eq!(governor.state(proposal_id).unwrap().votes_for, 0);
+
+            set_time(FINAL_PERIOD_END + 1);
+            governor
+                .reveal_vote(proposal_id, Vote::Agreed, Hash::from([1; 32]))
+                .unwrap();
+            assert_eq!(governor.state(proposal_id).unwrap().votes_for, 100);
+            assert_eq!(governor.commit_of_for(BOB.into(), proposal_id), None);
+            assert!(matches!(
+                governor.reveal_vote(proposal_id, Vote::Agreed, Hash::from([1; 32])),
+                Err(GovernError::NoCommit)
+            ));
+        }
+
+        #[ink::test]
+        fn reveal_fails_on_mismatched_vote() {
+            let (mut governor, proposal_id) = commit_reveal_setup();
+            commit(&mut governor, proposal_id, Vote::Agreed, 1);
+
+            set_time(FINAL_PERIOD_END + 1);
+            assert!(matches!(
+                governor.reveal_vote(proposal_id, Vote::Disagreed, Hash::from([1; 32])),
+                Err(GovernError::CommitMismatch)
+            ));
+            assert_eq!(governor.state(proposal_id).unwrap().votes_against, 0);
+        }
+
+        #[ink::test]
+        fn reveal_fails_on_mismatched_salt() {
+            let (mut governor, proposal_id) = commit_reveal_setup();
+            commit(&mut governor, proposal_id, Vote::Agreed, 1);
+
+            set_time(FINAL_PERIOD_END + 1);
+            assert!(matches!(
+                governor.reveal_vote(proposal_id, Vote::Agreed, Hash::from([2; 32])),
+                Err(GovernError::CommitMismatch)
+            ));
+            assert_eq!(governor.state(proposal_id).unwrap().votes_for, 0);
+        }
+
+        #[ink::test]
+        fn reveal_fails_outside_of_the_reveal_period() {
+            let (mut governor, proposal_id) = commit_reveal_setup();
+            commit(&mut governor, proposal_id, Vote::Agreed, 1);
+
+            set_time(FINAL_PERIOD_END);
+            assert!(matches!(
+                governor.reveal_vote(proposal_id, Vote::Agreed, Hash::from([1; 32])),
+                Err(GovernError::NotRevealPeriod)
+            ));
+            set_time(FINAL_PERIOD_END + REVEAL_PERIOD + 1);
+            assert!(matches!(
+                governor.reveal_vote(proposal_id, Vote::Agreed, Hash::from([1; 32])),
+                Err(GovernError::NotRevealPeriod)
+            ));
+        }
+
+        #[ink::test]
+        fn commit_fails_after_the_final_period() {
+            let (mut governor, proposal_id) = commit_reveal_setup();
+            set_time(FINAL_PERIOD_END + 1);
+            let vote_hash = hash_vote(
+                &BOB.into(),
+                &proposal_id,
+                &Vote::Agreed,
+                &Hash::from([1; 32]),
+            );
+            assert!(matches!(
+                governor.commit_vote(proposal_id, vote_hash),
+                Err(GovernError::NotCommitPeriod)
+            ));
+        }
+
+        #[ink::test]
+        fn second_commit_overrides_the_first_one() {
+            let (mut governor, proposal_id) = commit_reveal_setup();
+            commit(&mut governor, proposal_id, Vote::Agreed, 1);
+            commit(&mut governor, proposal_id, Vote::Disagreed, 2);
+            assert_eq!(
+                governor.commit_of_for(BOB.into(), proposal_id),
+                Some(hash_vote(
+                    &BOB.into(),
+                    &proposal_id,
+                    &Vote::Disagreed,
+                    &Hash::from([2; 32])
+                ))
+            );
+
+            set_time(FINAL_PERIOD_END + 1);
+            governor
+                .reveal_vote(proposal_id, Vote::Disagreed, Hash::from([2; 32]))
+                .unwrap();
+            let state = governor.state(proposal_id).unwrap();
+            assert_eq!(state.votes_for, 0);
+            assert_eq!(state.votes_against, 100);
+        }
+
+        #[ink::test]
+        fn commit_reveal_proposal_is_finalized_only_after_the_reveal_period() {
+            let (mut governor, proposal_id) = commit_reveal_setup();
+            commit(&mut governor, proposal_id, Vote::Agreed, 1);
+            set_time(FINAL_PERIOD_END + 1);
+            governor
+                .reveal_vote(proposal_id, Vote::Agreed, Hash::from([1; 32]))
+                .unwrap();
+
+            // minimum to finalize is 0 after the final period, but the reveal period is still ongoing
+            assert!(matches!(
+                governor.finalize(proposal_id),
+                Err(GovernError::FinalizeCondition)
+            ));
+            set_time(FINAL_PERIOD_END + REVEAL_PERIOD);
+            assert!(matches!(
+                governor.finalize(proposal_id),
+                Err(GovernError::FinalizeCondition)
+            ));
+
+            set_time(FINAL_PERIOD_END + REVEAL_PERIOD + 1);
+            governor.finalize(proposal_id).unwrap();
+            assert_eq!(
+                governor.status(proposal_id),
+                Some(ProposalStatus::Succeeded)
+            );
+        }
+
+        fn draft_proposal() -> Proposal {
+            Proposal {
+                transactions: Vec::new(),
+                description_url: String::from("url"),
+                description_hash: Hash::from([0; 32]),
+                earliest_execution: None,
+            }
+        }
+
+        /// Draft of `ALICE` with 10_000 shares, `BOB` has 1_000 shares, so the minimum to propose is 110 votes.
+        fn sponsorship_setup() -> (AbaxGovernor, ProposalHash) {
+            set_time(START);
+            let mut governor =
+                AbaxGovernor::new_off_chain(ALICE.into(), BOB.into(), 180 * DAY, rules());
+            governor._mint_to(&ALICE.into(), &10_000).unwrap();
+            governor._mint_to(&BOB.into(), &1_000).unwrap();
+            ink::env::test::set_caller::<DefaultEnvironment>(ALICE.into());
+            let proposal_hash = governor.create_draft(draft_proposal()).unwrap();
+            ink::env::test::set_caller::<DefaultEnvironment>(BOB.into());
+            (governor, proposal_hash)
+        }
+
+        #[ink::test]
+        fn pledged_votes_cant_be_withdrawn() {
+            let (mut governor, proposal_hash) = sponsorship_setup();
+            let bob = AccountId::from(BOB);
+            governor.sponsor(proposal_hash, 100).unwrap();
+            // 10 shares are deposited, 990 are left
+            assert_eq!(governor.balance_of(bob), 990);
+
+            assert!(governor._ensure_pledged_votes_are_kept(&bob, &900).is_ok());
+            assert_eq!(
+                governor._withdraw(&bob, &bob, &bob, &901, &901),
+                Err(PSP22Error::Custom("PledgedVotesLocked".to_string()))
+            );
+            assert_eq!(governor.balance_of(bob), 990);
+
+            governor.unsponsor(proposal_hash).unwrap();
+            assert!(governor
+                ._ensure_pledged_votes_are_kept(&bob, &1_000)
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn sponsor_deposits_are_locked_until_finalization() {
+            let (mut governor, proposal_hash) = sponsorship_setup();
+            let bob = AccountId::from(BOB);
+            governor.sponsor(proposal_hash, 110).unwrap();
+            let proposal_id = governor.govern.proposal_hash_to_id(&proposal_hash).unwrap();
+            assert_eq!(governor.lock.locked_by_sponsors(&proposal_id), 11);
+            assert_eq!(governor.lock.locked(&proposal_id), 0);
+            assert_eq!(governor.balance_of(bob), 989);
+            assert!(governor._ensure_pledged_votes_are_kept(&bob, &890).is_ok());
+            assert!(governor._ensure_pledged_votes_are_kept(&bob, &891).is_err());
+
+            set_time(FINAL_PERIOD_END + 1);
+            governor.finalize(proposal_id).unwrap();
+            assert_eq!(governor.status(proposal_id), Some(ProposalStatus::Defeated));
+            assert_eq!(governor.lock.locked_by_sponsors(&proposal_id), 0);
+            assert_eq!(governor.balance_of(bob), 1_000);
+            assert_eq!(governor.pledged_by(bob), 0);
+            assert!(governor
+                ._ensure_pledged_votes_are_kept(&bob, &1_000)
+                .is_ok());
+        }
+
+        fn proposal(seed: u8) -> Proposal {
+            Proposal {
+                description_hash: Hash::from([seed; 32]),
+                ..draft_proposal()
+            }
+        }
+
+        #[ink::test]
+        fn propose_respects_limits_of_proposer() {
+            set_time(START);
+            let mut governor = AbaxGovernor::new_off_chain(
+                ALICE.into(),
+                BOB.into(),
+                180 * DAY,
+                VotingRules {
+                    maximum_active_proposals_per_proposer: 1,
+                    minimum_proposal_interval: DAY,
+                    ..rules()
+                },
+            );
+            governor._mint_to(&ALICE.into(), &10_000).unwrap();
+            governor._mint_to(&BOB.into(), &10_000).unwrap();
+            ink::env::test::set_caller::<DefaultEnvironment>(ALICE.into());
+            governor.propose(proposal(0)).unwrap();
+
+            set_time(START + DAY);
+            assert!(matches!(
+                governor.propose(proposal(1)),
+                Err(GovernError::TooManyActiveProposalsOfProposer)
+            ));
+            // the limit is per proposer
+            ink::env::test::set_caller::<DefaultEnvironment>(BOB.into());
+            governor.propose(proposal(1)).unwrap();
+
+            ink::env::test::set_caller::<DefaultEnvironment>(ALICE.into());
+            set_time(FINAL_PERIOD_END + 1);
+            governor.finalize(0).unwrap();
+            governor.propose(proposal(2)).unwrap();
+        }
+
+        #[ink::test]
+        fn propose_respects_minimum_proposal_interval() {
+            set_time(START);
+            let mut governor = AbaxGovernor::new_off_chain(
+                ALICE.into(),
+                BOB.into(),
+                180 * DAY,
+                VotingRules {
+                    minimum_proposal_interval: DAY,
+                    ..rules()
+                },
+            );
+            governor._mint_to(&ALICE.into(), &10_000).unwrap();
+            ink::env::test::set_caller::<DefaultEnvironment>(ALICE.into());
+            governor.propose(proposal(0)).unwrap();
+
+            set_time(START + DAY - 1);
+            assert!(matches!(
+                governor.propose(proposal(1)),
+                Err(GovernError::ProposalTooSoon)
+            ));
+            set_time(START + DAY);
+            governor.propose(proposal(1)).unwrap();
+        }
+
+        const CHARLIE: [u8; 32] = [3; 32];
+
+        /// Records a vote of `CHARLIE` casted with `votes` vested votes, as if the vester reported them.
+        fn vote_with_vested_votes(
+            governor: &mut AbaxGovernor,
+            proposal_id: ProposalId,
+            votes: Balance,
+        ) {
+            let increase = governor
+                .vested_votes
+                .set_counted(&CHARLIE.into(), &votes)
+                .unwrap();
+            governor.counter.increase_counter(increase);
+            governor
+                .govern
+                .update_vote_of_for(&CHARLIE.into(), &proposal_id, &Vote::Agreed, &votes)
+                .unwrap();
+        }
+
+        #[ink::test]
+        fn counted_vested_votes_are_included_in_total_votes() {
+            set_time(START);
+            let mut governor =
+                AbaxGovernor::new_off_chain(ALICE.into(), BOB.into(), 180 * DAY, rules());
+            governor._mint_to(&ALICE.into(), &10_000).unwrap();
+            governor
+                .vested_votes
+                .set_counted(&CHARLIE.into(), &1_000)
+                .unwrap();
+            // 1% of 11_000
+            assert_eq!(governor._minimum_votes_to_propose().unwrap(), 110);
+
+            ink::env::test::set_caller::<DefaultEnvironment>(ALICE.into());
+            let proposal_id = governor.propose(proposal(0)).unwrap();
+            let state = governor.govern.state_of(&proposal_id).unwrap();
+            assert_eq!(state.votes_at_start, 11_000);
+
+            // vested votes casted after the start are included like deposits
+            vote_with_vested_votes(&mut governor, proposal_id, 1_500);
+            assert_eq!(governor.vested_votes.total_counted(), 1_500);
+            assert_eq!(governor.counter.counter() - state.counter_at_start, 500);
+        }
+
+        #[ink::test]
+        fn released_vested_votes_are_not_counted_twice_after_sync() {
+            set_time(START);
+            let mut governor =
+                AbaxGovernor::new_off_chain(ALICE.into(), BOB.into(), 180 * DAY, rules());
+            governor._mint_to(&ALICE.into(), &10_000).unwrap();
+            ink::env::test::set_caller::<DefaultEnvironment>(ALICE.into());
+            let proposal_id = governor.propose(proposal(0)).unwrap();
+            vote_with_vested_votes(&mut governor, proposal_id, 500);
+
+            // the vested assets are released, moved to BOB and deposited, BOB votes with them
+            governor._mint_to(&BOB.into(), &500).unwrap();
+            governor._sync_votes(&BOB.into()).unwrap();
+            ink::env::test::set_caller::<DefaultEnvironment>(BOB.into());
+            governor
+                .vote(proposal_id, Vote::Agreed, Vec::new())
+                .unwrap();
+            assert_eq!(
+                governor.govern.state_of(&proposal_id).unwrap().votes_for,
+                1_000
+            );
+
+            // anyone can re-sync CHARLIE, whose assets are no longer vesting
+            governor.sync_votes(CHARLIE.into()).unwrap();
+            assert_eq!(
+                governor.govern.state_of(&proposal_id).unwrap().votes_for,
+                500
+            );
+            assert_eq!(
+                governor
+                    .govern
+                    .vote_of_for(&CHARLIE.into(), &proposal_id)
+                    .unwrap()
+                    .amount,
+                0
+            );
+            assert_eq!(governor.vested_votes.counted_of(&CHARLIE.into()), 0);
+            assert_eq!(governor.vested_votes.total_counted(), 0);
+        }
+
+        fn state_v0(
+            status: ProposalStatus,
+            proposer: [u8; 32],
+            votes_for: Balance,
+        ) -> ProposalStateV0 {
+            ProposalStateV0 {
+                status,
+                force_unstake_possible: false,
+                proposer: proposer.into(),
+                start: DAY,
+                votes_at_start: 1_000,
+                counter_at_start: 0,
+                finalized: None,
+                votes_for,
+                votes_against: 0,
+                votes_against_with_slash: 0,
+                earliest_execution: None,
+            }
+        }
+
+        #[ink::test]
+        fn migrate_upgrades_storage_from_version_0_in_batches() {
+            let mut governor =
+                AbaxGovernor::new_off_chain(ALICE.into(), BOB.into(), 180 * DAY, rules());
+
+            // storage in the layout of version 0
+            let rules_v0 = VotingRulesV0 {
+                minimum_stake_part_e3: 10,
+                proposer_deposit_part_e3: 100,
+                initial_period: 3 * DAY,
+                flat_period: 7 * DAY,
+                final_period: 4 * DAY,
+            };
+            Lazy::<VotingRulesV0, ManualKey<STORAGE_KEY_GOVERNDATA_RULES>>::new().set(&rules_v0);
+            Lazy::<ProposalId, ManualKey<STORAGE_KEY_GOVERNDATA_NEXT_PROPOSAL_ID>>::new().set(&3);
+            let mut states_v0 = Mapping::<
+                ProposalId,
+                ProposalStateV0,
+                ManualKey<STORAGE_KEY_GOVERNDATA_STATE>,
+            >::new();
+            states_v0.insert(0, &state_v0(ProposalStatus::Executed, ALICE, 700));
+            states_v0.insert(1, &state_v0(ProposalStatus::Active, BOB, 10));
+            states_v0.insert(2, &state_v0(ProposalStatus::Active, BOB, 20));
+            governor.upgradeable.set_version(0);
+
+            governor._migrate(2).unwrap();
+            assert_eq!(governor.upgradeable.version(), 0);
+            assert_eq!(governor.upgradeable.migration_cursor(), Some(2));
+            assert_eq!(governor.govern.rules(), VotingRules::from(rules_v0));
+            assert!(validate(&governor.govern.rules()).is_ok());
+            assert_eq!(governor.govern.active_proposal_ids(), vec![1]);
+
+            governor._migrate(2).unwrap();
+            assert_eq!(governor.upgradeable.version(), STORAGE_VERSION);
+            assert_eq!(governor.upgradeable.migration_cursor(), None);
+            assert_eq!(governor.govern.rules(), VotingRules::from(rules_v0));
+
+            let executed = governor.govern.state_of(&0).unwrap();
+            assert_eq!(executed.status, ProposalStatus::Executed);
+            assert_eq!(executed.proposer, AccountId::from(ALICE));
+            assert_eq!(executed.votes_for, 700);
+            assert_eq!(executed.votes_abstain, 0);
+            assert_eq!(executed.executed, None);
+            assert_eq!(governor.govern.state_of(&2).unwrap().votes_for, 20);
+            assert_eq!(governor.govern.active_proposal_ids(), vec![1, 2]);
+            assert_eq!(governor.govern.active_proposals_of(&ALICE.into()), 0);
+            assert_eq!(governor.govern.active_proposals_of(&BOB.into()), 2);
+
+            assert!(matches!(
+                governor._migrate(2),
+                Err(GovernError::AlreadyMigrated)
+            ));
+        }
+    }
 }
@@ -5,9 +5,57 @@ use pendzl::{
 
 use crate::modules::govern::{
     helpers::mul_div::mul_div_r_down,
-    traits::{ProposalState, VotingRules},
+    traits::{ProposalState, VotingPhase, VotingRules},
 };
 
+/// Returns the end of the final period of the proposal.
+pub fn final_period_end(
+    state: &ProposalState,
+    rules: &VotingRules,
+) -> Result<Timestamp, MathError> {
+    state
+        .start
+        .checked_add(rules.initial_period)
+        .ok_or(MathError::Overflow)?
+        .checked_add(rules.flat_period)
+        .ok_or(MathError::Overflow)?
+        .checked_add(rules.final_period)
+        .ok_or(MathError::Overflow)
+}
+
+/// Returns the phase of voting on the proposal at `now`.
+pub fn voting_phase(
+    state: &ProposalState,
+    rules: &VotingRules,
+    now: Timestamp,
+) -> Result<VotingPhase, MathError> {
+    let initial_period_end = state
+        .start
+        .checked_add(rules.initial_period)
+        .ok_or(MathError::Overflow)?;
+    let flat_period_end = initial_period_end
+        .checked_add(rules.flat_period)
+        .ok_or(MathError::Overflow)?;
+
+    Ok(if now <= initial_period_end {
+        VotingPhase::Initial
+    } else if now <= flat_period_end {
+        VotingPhase::Flat
+    } else if now <= final_period_end(state, rules)? {
+        VotingPhase::Final
+    } else {
+        VotingPhase::Ended
+    })
+}
+
+fn total_votes(state: &ProposalState, current_counter: u128) -> Result<Balance, MathError> {
+    let counter_diff = current_counter.overflowing_sub(state.counter_at_start).0;
+    state
+        .votes_at_start
+        .checked_add(counter_diff)
+        .ok_or(MathError::Overflow)
+}
+
 pub fn minimum_to_finalize(
     state: &ProposalState,
     rules: &VotingRules,
@@ -25,11 +73,7 @@ pub fn minimum_to_finalize(
         .checked_add(rules.final_period)
         .ok_or(MathError::Overflow)?;
 
-    let counter_diff = current_counter.overflowing_sub(state.counter_at_start).0;
-    let total_votes = state
-        .votes_at_start
-        .checked_add(counter_diff)
-        .ok_or(MathError::Overflow)?;
+    let total_votes = total_votes(state, current_counter)?;
 
     //print all of args and above
 
@@ -62,3 +106,235 @@ pub fn minimum_to_finalize(
         0
     })
 }
+
+/// Returns minimal amount of votes that has to be casted to finalize the proposal other than `Defeated` because of too low participation.
+pub fn minimum_quorum(
+    state: &ProposalState,
+    rules: &VotingRules,
+    current_counter: u128,
+) -> Result<Balance, MathError> {
+    mul_div_r_down(
+        total_votes(state, current_counter)?,
+        rules.minimum_quorum_e3 as u128,
+        1000,
+    )
+}
+
+/// Returns `now` shifted back by the duration during which voting was paused since the start of the proposal.
+pub fn voting_time(
+    state: &ProposalState,
+    now: Timestamp,
+    voting_paused_duration: Timestamp,
+) -> Result<Timestamp, MathError> {
+    let paused_since_start = voting_paused_duration
+        .checked_sub(state.voting_paused_at_start)
+        .ok_or(MathError::Underflow)?;
+    now.checked_sub(paused_since_start)
+        .ok_or(MathError::Underflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::modules::govern::traits::ProposalStatus;
+
+    const MAX_TIMESTAMP: Timestamp = 1 << 50;
+    const MAX_PERIOD: Timestamp = 1 << 40;
+    const MAX_VOTES: Balance = u128::MAX / 2;
+
+    fn state(start: Timestamp, votes_at_start: Balance, counter_at_start: u128) -> ProposalState {
+        ProposalState {
+            status: ProposalStatus::Active,
+            force_unstake_possible: false,
+            proposer: [0; 32].into(),
+            commit_reveal: false,
+            start,
+            votes_at_start,
+            counter_at_start,
+            voting_paused_at_start: 0,
+            finalized: None,
+            votes_for: 0,
+            votes_against: 0,
+            votes_against_with_slash: 0,
+            votes_abstain: 0,
+            earliest_execution: None,
+            voters_count: 0,
+            finalization_phase: None,
+            minimum_to_finalize_at_finalization: None,
+            executed: None,
+        }
+    }
+
+    fn rules(
+        initial_period: Timestamp,
+        flat_period: Timestamp,
+        final_period: Timestamp,
+    ) -> VotingRules {
+        VotingRules {
+            initial_period,
+            flat_period,
+            final_period,
+            ..Default::default()
+        }
+    }
+
+    /// Straightforward big-int implementation of `minimum_to_finalize`.
+    fn reference_minimum_to_finalize(
+        state: &ProposalState,
+        rules: &VotingRules,
+        now: Timestamp,
+        current_counter: u128,
+    ) -> Result<Balance, MathError> {
+        let total_votes = BigUint::from(state.votes_at_start)
+            + BigUint::from(current_counter.wrapping_sub(state.counter_at_start));
+        let half = total_votes / 2_u32;
+        let initial_period_end = state.start + rules.initial_period;
+        let flat_period_end = initial_period_end + rules.flat_period;
+        let final_period_end = flat_period_end + rules.final_period;
+
+        let res = if now <= initial_period_end {
+            if rules.initial_period == 0 {
+                return Err(MathError::DivByZero);
+            }
+            &half
+                + &half * BigUint::from(initial_period_end - now)
+                    / BigUint::from(rules.initial_period)
+        } else if now <= flat_period_end {
+            half
+        } else if now <= final_period_end {
+            half * BigUint::from(final_period_end - now) / BigUint::from(rules.final_period)
+        } else {
+            BigUint::from(0_u32)
+        };
+        u128::try_from(res).map_err(|_| MathError::Overflow)
+    }
+
+    fn total_votes_of(state: &ProposalState, current_counter: u128) -> Balance {
+        state.votes_at_start + current_counter.wrapping_sub(state.counter_at_start)
+    }
+
+    prop_compose! {
+        fn arb_setup()(
+            start in 0..MAX_TIMESTAMP,
+            votes_at_start in 0..=MAX_VOTES,
+            counter_at_start: u128,
+            counter_diff in 0..=MAX_VOTES,
+            initial_period in 1..MAX_PERIOD,
+            flat_period in 0..MAX_PERIOD,
+            final_period in 1..MAX_PERIOD,
+        ) -> (ProposalState, VotingRules, u128) {
+            // `counter_at_start + counter_diff` may wrap around
+            (
+                state(start, votes_at_start, counter_at_start),
+                rules(initial_period, flat_period, final_period),
+                counter_at_start.wrapping_add(counter_diff),
+            )
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(
+            (state, rules, counter) in arb_setup(),
+            initial_period in 0..MAX_PERIOD,
+            now in 0..MAX_TIMESTAMP + 4 * MAX_PERIOD,
+        ) {
+            let rules = VotingRules { initial_period, ..rules };
+            prop_assert_eq!(
+                minimum_to_finalize(&state, &rules, now, counter),
+                reference_minimum_to_finalize(&state, &rules, now, counter)
+            );
+        }
+
+        #[test]
+        fn is_at_most_total_votes(
+            (state, rules, counter) in arb_setup(),
+            elapsed in 0..4 * MAX_PERIOD,
+        ) {
+            let res = minimum_to_finalize(&state, &rules, state.start + elapsed, counter).unwrap();
+            prop_assert!(res <= total_votes_of(&state, counter));
+        }
+
+        #[test]
+        fn decreases_over_time(
+            (state, rules, counter) in arb_setup(),
+            elapsed in 0..4 * MAX_PERIOD,
+            delta in 0..2 * MAX_PERIOD,
+        ) {
+            let now = state.start + elapsed;
+            let earlier = minimum_to_finalize(&state, &rules, now, counter).unwrap();
+            let later = minimum_to_finalize(&state, &rules, now + delta, counter).unwrap();
+            prop_assert!(later <= earlier);
+        }
+
+        #[test]
+        fn is_continuous_at_period_boundaries((state, rules, counter) in arb_setup()) {
+            let half = total_votes_of(&state, counter) / 2;
+            let initial_period_end = state.start + rules.initial_period;
+            let flat_period_end = initial_period_end + rules.flat_period;
+            let final_period_end = flat_period_end + rules.final_period;
+            let at = |now| minimum_to_finalize(&state, &rules, now, counter).unwrap();
+
+            prop_assert_eq!(at(state.start), 2 * half);
+            prop_assert_eq!(at(initial_period_end), half);
+            prop_assert_eq!(at(initial_period_end + 1), half);
+            prop_assert_eq!(at(flat_period_end), half);
+            // a single millisecond in the final period lowers the threshold by at most its share of the final period
+            prop_assert!(half - at(flat_period_end + 1) <= half / rules.final_period as u128 + 1);
+            // and a millisecond before the initial period end it is at most that share above half
+            prop_assert!(at(initial_period_end - 1) - half <= half / rules.initial_period as u128 + 1);
+            prop_assert_eq!(at(final_period_end), 0);
+            prop_assert_eq!(at(final_period_end + 1), 0);
+        }
+
+        #[test]
+        fn fails_with_div_by_zero_in_empty_initial_period(
+            (state, rules, counter) in arb_setup(),
+            before_start in 0..MAX_PERIOD,
+        ) {
+            let rules = VotingRules { initial_period: 0, ..rules };
+            let now = state.start.saturating_sub(before_start);
+            prop_assert_eq!(
+                minimum_to_finalize(&state, &rules, now, counter),
+                Err(MathError::DivByZero)
+            );
+            prop_assert!(minimum_to_finalize(&state, &rules, state.start + 1, counter).is_ok());
+        }
+
+        #[test]
+        fn drops_to_zero_after_flat_period_with_empty_final_period(
+            (state, rules, counter) in arb_setup(),
+            after_flat_period in 1..MAX_PERIOD,
+        ) {
+            // `final_period` is never used as a divisor when it is 0
+            let rules = VotingRules { final_period: 0, ..rules };
+            let flat_period_end = state.start + rules.initial_period + rules.flat_period;
+            prop_assert_eq!(
+                minimum_to_finalize(&state, &rules, flat_period_end, counter),
+                Ok(total_votes_of(&state, counter) / 2)
+            );
+            prop_assert_eq!(
+                minimum_to_finalize(&state, &rules, flat_period_end + after_flat_period, counter),
+                Ok(0)
+            );
+        }
+
+        #[test]
+        fn counts_votes_staked_across_counter_wraparound(
+            votes_at_start in 0..=MAX_VOTES,
+            counter_to_max in 0..=MAX_VOTES,
+            counter_diff in 0..=MAX_VOTES,
+        ) {
+            let counter_at_start = u128::MAX - counter_to_max;
+            let state = state(0, votes_at_start, counter_at_start);
+            let rules = rules(1, 1, 1);
+            prop_assert_eq!(
+                minimum_to_finalize(&state, &rules, 2, counter_at_start.wrapping_add(counter_diff)),
+                Ok((votes_at_start + counter_diff) / 2)
+            );
+        }
+    }
+}
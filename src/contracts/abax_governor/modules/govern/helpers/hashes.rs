@@ -4,13 +4,20 @@ pub use ink::{
     primitives::Hash,
 };
 
-pub use crate::modules::govern::traits::Proposal;
+pub use crate::modules::govern::traits::{AccountId, Proposal, ProposalId, Vote};
 
 pub fn hash_description(description: &String) -> Hash {
     let mut output = <Sha2x256 as HashOutput>::Type::default();
     ink::env::hash_bytes::<Sha2x256>(description.as_bytes(), &mut output);
     output.into()
 }
+pub fn hash_vote(account: &AccountId, proposal_id: &ProposalId, vote: &Vote, salt: &Hash) -> Hash {
+    let hash_data = scale::Encode::encode(&(account, proposal_id, vote, salt));
+
+    let mut output = <Sha2x256 as HashOutput>::Type::default();
+    ink::env::hash_bytes::<Sha2x256>(&hash_data, &mut output);
+    output.into()
+}
 pub fn hash_proposal(proposal: &Proposal) -> Hash {
     let mut hash_data: Vec<u8> = Vec::new();
 
@@ -1,3 +1,5 @@
 pub mod finalization;
 pub mod hashes;
 pub mod mul_div;
+#[cfg(any(feature = "transaction-builders", test))]
+pub mod transactions;
@@ -25,3 +25,52 @@ pub fn mul_div_r_down(x: u128, y: u128, denominator: u128) -> Result<u128, MathE
 
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn reference_mul_div_r_down(x: u128, y: u128, denominator: u128) -> Result<u128, MathError> {
+        if denominator == 0 {
+            return Err(MathError::DivByZero);
+        }
+        let res = BigUint::from(x) * BigUint::from(y) / BigUint::from(denominator);
+        u128::try_from(res).map_err(|_| MathError::Overflow)
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(x: u128, y: u128, denominator: u128) {
+            prop_assert_eq!(
+                mul_div_r_down(x, y, denominator),
+                reference_mul_div_r_down(x, y, denominator)
+            );
+        }
+
+        #[test]
+        fn agrees_with_reference_when_result_fits(x: u128, y: u128, denominator in 1..=u128::MAX) {
+            // bounds the result to keep it within u128
+            let y = y % denominator;
+            prop_assert_eq!(
+                mul_div_r_down(x, y, denominator),
+                reference_mul_div_r_down(x, y, denominator)
+            );
+            prop_assert!(mul_div_r_down(x, y, denominator).unwrap() <= x);
+        }
+
+        #[test]
+        fn fails_on_zero_denominator(x: u128, y: u128) {
+            prop_assert_eq!(mul_div_r_down(x, y, 0), Err(MathError::DivByZero));
+        }
+
+        #[test]
+        fn rounds_down(x in 0..=u64::MAX as u128, y in 0..=u64::MAX as u128, denominator in 1..=u64::MAX as u128) {
+            let res = mul_div_r_down(x, y, denominator).unwrap();
+            prop_assert!(res * denominator <= x * y);
+            prop_assert!(x * y < (res + 1) * denominator);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Builders of `Transaction`s that are commonly executed by the governor.
+//! They spare hand-crafting selectors and SCALE encoded inputs when preparing a `Proposal`.
+use abax_treasury::abax_treasury::Operation;
+use ink::{
+    prelude::vec::Vec,
+    primitives::{AccountId, Hash},
+};
+use pendzl::{
+    contracts::access_control::RoleType,
+    traits::{Balance, Timestamp},
+};
+
+use crate::modules::govern::traits::{Transaction, VotingRules};
+
+fn transaction(callee: AccountId, selector: [u8; 4], input: Vec<u8>) -> Transaction {
+    Transaction {
+        callee,
+        selector,
+        input,
+        transferred_value: 0,
+    }
+}
+
+/// `AccessControl::grant_role(role, account)` called on `callee`.
+pub fn grant_role(callee: AccountId, role: RoleType, account: Option<AccountId>) -> Transaction {
+    transaction(
+        callee,
+        ink::selector_bytes!("AccessControl::grant_role"),
+        scale::Encode::encode(&(role, account)),
+    )
+}
+
+/// `AccessControl::revoke_role(role, account)` called on `callee`.
+pub fn revoke_role(callee: AccountId, role: RoleType, account: Option<AccountId>) -> Transaction {
+    transaction(
+        callee,
+        ink::selector_bytes!("AccessControl::revoke_role"),
+        scale::Encode::encode(&(role, account)),
+    )
+}
+
+/// `SetCodeHash::set_code_hash(code_hash)` called on `callee`.
+pub fn set_code_hash(callee: AccountId, code_hash: Hash) -> Transaction {
+    transaction(
+        callee,
+        ink::selector_bytes!("SetCodeHash::set_code_hash"),
+        scale::Encode::encode(&code_hash),
+    )
+}
+
+/// `AbaxGovernManage::change_voting_rules(rules)` called on the governor (`callee`).
+pub fn change_voting_rules(callee: AccountId, rules: &VotingRules) -> Transaction {
+    transaction(
+        callee,
+        ink::selector_bytes!("AbaxGovernManage::change_voting_rules"),
+        scale::Encode::encode(rules),
+    )
+}
+
+/// `AbaxTreasury::create_order(earliest_execution, latest_execution, operations)` called on the treasury (`callee`).
+pub fn create_order(
+    callee: AccountId,
+    earliest_execution: Timestamp,
+    latest_execution: Timestamp,
+    operations: &[Operation],
+) -> Transaction {
+    transaction(
+        callee,
+        ink::selector_bytes!("AbaxTreasury::create_order"),
+        scale::Encode::encode(&(earliest_execution, latest_execution, operations)),
+    )
+}
+
+/// `AbaxInflatorManage::set_inflation_distribution(inflation_distribution)` called on the inflator (`callee`).
+pub fn set_inflation_distribution(
+    callee: AccountId,
+    inflation_distribution: &[(AccountId, u16)],
+) -> Transaction {
+    transaction(
+        callee,
+        ink::selector_bytes!("AbaxInflatorManage::set_inflation_distribution"),
+        scale::Encode::encode(inflation_distribution),
+    )
+}
+
+/// `PSP22Mintable::mint(to, amount)` called on the token (`callee`).
+pub fn mint(callee: AccountId, to: AccountId, amount: Balance) -> Transaction {
+    transaction(
+        callee,
+        ink::selector_bytes!("PSP22Mintable::mint"),
+        scale::Encode::encode(&(to, amount)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use abax_treasury::abax_treasury::PSP22Transfer;
+
+    use super::*;
+
+    // selectors as found in the contracts' metadata
+    const GRANT_ROLE: [u8; 4] = [0x4a, 0xc0, 0x62, 0xfd];
+    const REVOKE_ROLE: [u8; 4] = [0x6e, 0x4f, 0x09, 0x91];
+    const SET_CODE_HASH: [u8; 4] = [0xab, 0xb6, 0x0c, 0xdf];
+    const CHANGE_VOTING_RULES: [u8; 4] = [0xac, 0xe4, 0x8f, 0xbf];
+    const CREATE_ORDER: [u8; 4] = [0x6e, 0x65, 0x1e, 0xde];
+    const SET_INFLATION_DISTRIBUTION: [u8; 4] = [0x20, 0xc8, 0xda, 0x6c];
+    const MINT: [u8; 4] = [0xfc, 0x3c, 0x75, 0xd4];
+
+    const CALLEE: [u8; 32] = [1; 32];
+    const ACCOUNT: [u8; 32] = [2; 32];
+
+    #[test]
+    fn grant_and_revoke_role_match_metadata() {
+        let role: RoleType = ink::selector_id!("EXECUTOR");
+        let mut input = role.to_le_bytes().to_vec();
+        input.push(1);
+        input.extend_from_slice(&[2; 32]);
+
+        let grant = grant_role(CALLEE.into(), role, Some(ACCOUNT.into()));
+        assert_eq!(grant.callee, AccountId::from(CALLEE));
+        assert_eq!(grant.selector, GRANT_ROLE);
+        assert_eq!(grant.input, input);
+        assert_eq!(grant.transferred_value, 0);
+
+        let revoke = revoke_role(CALLEE.into(), role, Some(ACCOUNT.into()));
+        assert_eq!(revoke.selector, REVOKE_ROLE);
+        assert_eq!(revoke.input, input);
+
+        let revoke_none = revoke_role(CALLEE.into(), role, None);
+        let mut input = role.to_le_bytes().to_vec();
+        input.push(0);
+        assert_eq!(revoke_none.input, input);
+    }
+
+    #[test]
+    fn set_code_hash_matches_metadata() {
+        let tx = set_code_hash(CALLEE.into(), Hash::from([3; 32]));
+        assert_eq!(tx.selector, SET_CODE_HASH);
+        assert_eq!(tx.input, [3; 32].to_vec());
+    }
+
+    #[test]
+    fn change_voting_rules_matches_metadata() {
+        let rules = VotingRules {
+            minimum_stake_part_e3: 10,
+            proposer_deposit_part_e3: 100,
+            initial_period: 1,
+            flat_period: 2,
+            final_period: 3,
+            minimum_quorum_e3: 4,
+            maximum_active_proposals: 5,
+            maximum_active_proposals_per_proposer: 6,
+            minimum_proposal_interval: 7,
+            force_unstake_grace_period: 8,
+        };
+        let tx = change_voting_rules(CALLEE.into(), &rules);
+        assert_eq!(tx.selector, CHANGE_VOTING_RULES);
+
+        let mut input = Vec::new();
+        input.extend_from_slice(&10_u16.to_le_bytes());
+        input.extend_from_slice(&100_u16.to_le_bytes());
+        input.extend_from_slice(&1_u64.to_le_bytes());
+        input.extend_from_slice(&2_u64.to_le_bytes());
+        input.extend_from_slice(&3_u64.to_le_bytes());
+        input.extend_from_slice(&4_u16.to_le_bytes());
+        input.extend_from_slice(&5_u32.to_le_bytes());
+        input.extend_from_slice(&6_u32.to_le_bytes());
+        input.extend_from_slice(&7_u64.to_le_bytes());
+        input.extend_from_slice(&8_u64.to_le_bytes());
+        assert_eq!(tx.input, input);
+    }
+
+    #[test]
+    fn create_order_matches_metadata() {
+        let operations = vec![Operation::PSP22Transfer(PSP22Transfer {
+            asset: CALLEE.into(),
+            to: ACCOUNT.into(),
+            amount: 5,
+        })];
+        let tx = create_order(CALLEE.into(), 7, 8, &operations);
+        assert_eq!(tx.selector, CREATE_ORDER);
+
+        let mut input = Vec::new();
+        input.extend_from_slice(&7_u64.to_le_bytes());
+        input.extend_from_slice(&8_u64.to_le_bytes());
+        // compact encoded length 1
+        input.push(4);
+        // `PSP22Transfer` variant index
+        input.push(1);
+        input.extend_from_slice(&[1; 32]);
+        input.extend_from_slice(&[2; 32]);
+        input.extend_from_slice(&5_u128.to_le_bytes());
+        assert_eq!(tx.input, input);
+    }
+
+    #[test]
+    fn set_inflation_distribution_matches_metadata() {
+        let tx = set_inflation_distribution(CALLEE.into(), &[(ACCOUNT.into(), 3)]);
+        assert_eq!(tx.selector, SET_INFLATION_DISTRIBUTION);
+
+        let mut input = vec![4];
+        input.extend_from_slice(&[2; 32]);
+        input.extend_from_slice(&3_u16.to_le_bytes());
+        assert_eq!(tx.input, input);
+    }
+
+    #[test]
+    fn mint_matches_metadata() {
+        let tx = mint(CALLEE.into(), ACCOUNT.into(), 11);
+        assert_eq!(tx.selector, MINT);
+
+        let mut input = [2; 32].to_vec();
+        input.extend_from_slice(&11_u128.to_le_bytes());
+        assert_eq!(tx.input, input);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod helpers;
 pub mod storage;
 pub mod traits;
+#[cfg(test)]
+mod vote_accounting_tests;
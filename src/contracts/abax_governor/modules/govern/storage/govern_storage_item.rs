@@ -1,5 +1,8 @@
 use ink::{
-    env::DefaultEnvironment, prelude::string::ToString, primitives::AccountId, storage::Mapping,
+    env::DefaultEnvironment,
+    prelude::{string::ToString, vec::Vec},
+    primitives::AccountId,
+    storage::{traits::ManualKey, Lazy, Mapping},
 };
 use pendzl::{
     math::errors::MathError,
@@ -7,8 +10,13 @@ use pendzl::{
 };
 
 use crate::modules::govern::{
-    helpers::finalization::minimum_to_finalize,
-    traits::{GovernError, ProposalId, ProposalState, ProposalStatus, UserVote, Vote, VotingRules},
+    helpers::finalization::{
+        final_period_end, minimum_quorum, minimum_to_finalize, voting_phase, voting_time,
+    },
+    traits::{
+        CommitRevealRules, GovernError, ProposalId, ProposalState, ProposalStateV0, ProposalStatus,
+        ProposerDeposit, UserVote, Vote, VotingPhase, VotingRules, VotingRulesV0,
+    },
 };
 
 #[derive(Debug)]
@@ -17,7 +25,14 @@ pub struct GovernData {
     #[lazy]
     rules: VotingRules,
     #[lazy]
+    commit_reveal_rules: CommitRevealRules,
+    #[lazy]
+    proposer_deposit: ProposerDeposit,
+    #[lazy]
     active_proposals: u32,
+    /// ids of active proposals. Its length is bounded by `VotingRules::maximum_active_proposals`.
+    #[lazy]
+    active_proposal_ids: Vec<ProposalId>,
     #[lazy]
     finalized_proposals: u32,
     #[lazy]
@@ -30,17 +45,26 @@ pub struct GovernData {
     proposal_hash_to_id: Mapping<Hash, ProposalId>,
     state: Mapping<ProposalId, ProposalState>,
     votes: Mapping<(AccountId, ProposalId), UserVote>,
+    /// hashes of not yet revealed votes on proposals with commit-reveal voting
+    commits: Mapping<(AccountId, ProposalId), Hash>,
     /// Last time when the user staked and had no stake before, when user has no stake it should be None.
     last_stake_timestamp: Mapping<AccountId, Timestamp>,
     /// Last proposal that account didnt vote and was in consequence force unstaked
     last_force_unstake: Mapping<AccountId, ProposalId>,
+    /// number of active proposals created by the account
+    active_proposals_of: Mapping<AccountId, u32>,
+    /// Last time when the account created a proposal
+    last_proposal_timestamp: Mapping<AccountId, Timestamp>,
 }
 
 impl GovernData {
     pub fn new(rules: &VotingRules) -> Self {
         let mut instance = Self {
             rules: Default::default(),
+            commit_reveal_rules: Default::default(),
+            proposer_deposit: Default::default(),
             active_proposals: Default::default(),
+            active_proposal_ids: Default::default(),
             finalized_proposals: Default::default(),
             executed_proposals: Default::default(),
             next_proposal_id: Default::default(),
@@ -50,8 +74,11 @@ impl GovernData {
             proposal_hash_to_id: Default::default(),
             state: Default::default(),
             votes: Default::default(),
+            commits: Default::default(),
             last_stake_timestamp: Default::default(),
             last_force_unstake: Default::default(),
+            active_proposals_of: Default::default(),
+            last_proposal_timestamp: Default::default(),
         };
         instance.rules.set(rules);
         instance
@@ -82,10 +109,38 @@ impl GovernData {
         self.rules.set(rules);
     }
 
+    pub fn commit_reveal_rules(&self) -> CommitRevealRules {
+        self.commit_reveal_rules.get().unwrap_or_default()
+    }
+
+    pub fn change_commit_reveal_rules(&mut self, rules: &CommitRevealRules) {
+        self.commit_reveal_rules.set(rules);
+    }
+
+    pub fn proposer_deposit(&self) -> ProposerDeposit {
+        self.proposer_deposit.get().unwrap_or_default()
+    }
+
+    pub fn change_proposer_deposit(&mut self, deposit: &ProposerDeposit) {
+        self.proposer_deposit.set(deposit);
+    }
+
     pub fn active_proposals(&self) -> u32 {
         self.active_proposals.get().unwrap_or_default()
     }
 
+    pub fn active_proposal_ids(&self) -> Vec<ProposalId> {
+        self.active_proposal_ids.get().unwrap_or_default()
+    }
+
+    pub fn active_proposals_of(&self, account: &AccountId) -> u32 {
+        self.active_proposals_of.get(account).unwrap_or_default()
+    }
+
+    pub fn last_proposal_timestamp(&self, account: &AccountId) -> Option<Timestamp> {
+        self.last_proposal_timestamp.get(account)
+    }
+
     pub fn finalized_proposals(&self) -> u32 {
         self.finalized_proposals.get().unwrap_or_default()
     }
@@ -122,10 +177,33 @@ impl GovernData {
         description_hash: &Hash,
         votes_at_start: Balance,
         counter_at_start: u128,
+        voting_paused_at_start: Timestamp,
+        commit_reveal: bool,
     ) -> Result<ProposalId, GovernError> {
         if self.proposal_hash_to_id(proposal_hash).is_some() {
             return Err(GovernError::ProposalAlreadyExists);
         }
+        if commit_reveal && self.commit_reveal_rules().reveal_period == 0 {
+            return Err(GovernError::CommitRevealDisabled);
+        }
+        let rules = self.rules();
+        if self.active_proposals() >= rules.maximum_active_proposals {
+            return Err(GovernError::TooManyActiveProposals);
+        }
+        let active_proposals_of = self.active_proposals_of(proposer);
+        if active_proposals_of >= rules.maximum_active_proposals_per_proposer {
+            return Err(GovernError::TooManyActiveProposalsOfProposer);
+        }
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        if let Some(last_proposal_timestamp) = self.last_proposal_timestamp(proposer) {
+            if now
+                < last_proposal_timestamp
+                    .checked_add(rules.minimum_proposal_interval)
+                    .ok_or(MathError::Overflow)?
+            {
+                return Err(GovernError::ProposalTooSoon);
+            }
+        }
 
         let proposal_id = self.next_proposal_id();
         self.next_proposal_id
@@ -144,14 +222,21 @@ impl GovernData {
                 status: ProposalStatus::Active,
                 force_unstake_possible: false,
                 proposer: *proposer,
-                start: ink::env::block_timestamp::<DefaultEnvironment>(),
+                commit_reveal,
+                start: now,
                 counter_at_start,
                 votes_at_start,
+                voting_paused_at_start,
                 finalized: None,
                 votes_for: 0,
                 votes_against: 0,
                 votes_against_with_slash: 0,
+                votes_abstain: 0,
                 earliest_execution,
+                voters_count: 0,
+                finalization_phase: None,
+                minimum_to_finalize_at_finalization: None,
+                executed: None,
             },
         );
 
@@ -161,6 +246,16 @@ impl GovernData {
                 .checked_add(1)
                 .ok_or(MathError::Overflow)?),
         );
+        let mut active_proposal_ids = self.active_proposal_ids();
+        active_proposal_ids.push(proposal_id);
+        self.active_proposal_ids.set(&active_proposal_ids);
+        self.active_proposals_of.insert(
+            proposer,
+            &(active_proposals_of
+                .checked_add(1)
+                .ok_or(MathError::Overflow)?),
+        );
+        self.last_proposal_timestamp.insert(proposer, &now);
 
         Ok(proposal_id)
     }
@@ -169,6 +264,7 @@ impl GovernData {
         &mut self,
         proposal_id: &ProposalId,
         current_counter: u128,
+        voting_paused_duration: Timestamp,
     ) -> Result<ProposalStatus, GovernError> {
         let mut state = self
             .state_of(proposal_id)
@@ -178,62 +274,85 @@ impl GovernData {
             return Err(GovernError::WrongStatus);
         }
         let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        let voting_now = voting_time(&state, now, voting_paused_duration)?;
 
-        let minimum_to_finalize = minimum_to_finalize(&state, &self.rules(), now, current_counter)?;
-
-        if state
-            .votes_against
-            .checked_add(state.votes_against_with_slash)
-            .ok_or(MathError::Overflow)?
-            < minimum_to_finalize
-            && state.votes_for < minimum_to_finalize
+        // votes are counted only after they are revealed
+        if state.commit_reveal
+            && voting_now
+                <= final_period_end(&state, &self.rules())?
+                    .checked_add(self.commit_reveal_rules().reveal_period)
+                    .ok_or(MathError::Overflow)?
         {
             return Err(GovernError::FinalizeCondition);
         }
 
-        if state
-            .votes_against
+        let minimum_quorum = minimum_quorum(&state, &self.rules(), current_counter)?;
+        let minimum_to_finalize =
+            minimum_to_finalize(&state, &self.rules(), voting_now, current_counter)?;
+        let votes_total = state
+            .votes_for
+            .checked_add(state.votes_against)
+            .ok_or(MathError::Overflow)?
             .checked_add(state.votes_against_with_slash)
             .ok_or(MathError::Overflow)?
-            >= state.votes_for
-        {
-            if state.votes_against_with_slash
+            .checked_add(state.votes_abstain)
+            .ok_or(MathError::Overflow)?;
+
+        if votes_total < minimum_quorum {
+            // the quorum wasn't reached by the end of the final period
+            if voting_now <= final_period_end(&state, &self.rules())? {
+                return Err(GovernError::FinalizeCondition);
+            }
+            state.status = ProposalStatus::Defeated;
+        } else {
+            // abstaining votes count only toward the quorum, so they can't push any side over the threshold
+            let leading_votes = state.votes_for.max(
+                state
+                    .votes_against
+                    .checked_add(state.votes_against_with_slash)
+                    .ok_or(MathError::Overflow)?,
+            );
+            if leading_votes < minimum_to_finalize {
+                return Err(GovernError::FinalizeCondition);
+            }
+
+            if state
+                .votes_against
+                .checked_add(state.votes_against_with_slash)
+                .ok_or(MathError::Overflow)?
+                >= state.votes_for
+            {
+                if state.votes_against_with_slash
+                    > state
+                        .votes_against
+                        .checked_add(state.votes_for)
+                        .ok_or(MathError::Overflow)?
+                {
+                    state.status = ProposalStatus::DefeatedWithSlash;
+                } else {
+                    state.status = ProposalStatus::Defeated;
+                }
+            } else if state.votes_for
                 > state
                     .votes_against
-                    .checked_add(state.votes_for)
+                    .checked_add(state.votes_against_with_slash)
                     .ok_or(MathError::Overflow)?
             {
-                state.status = ProposalStatus::DefeatedWithSlash;
+                state.status = ProposalStatus::Succeeded;
             } else {
                 state.status = ProposalStatus::Defeated;
             }
-        } else if state.votes_for
-            > state
-                .votes_against
-                .checked_add(state.votes_against_with_slash)
-                .ok_or(MathError::Overflow)?
-        {
-            state.status = ProposalStatus::Succeeded;
-        } else {
-            state.status = ProposalStatus::Defeated;
         }
 
-        let initital_plus_flat_duration = self
-            .rules()
-            .initial_period
-            .checked_add(self.rules().flat_period)
-            .ok_or(MathError::Overflow)?;
-
-        let is_post_flat_period = now
-            >= state
-                .start
-                .checked_add(initital_plus_flat_duration)
-                .ok_or(MathError::Overflow)?;
-        if is_post_flat_period {
+        // the flat period includes its end, the same as in `voting_phase` and `minimum_to_finalize`
+        let finalization_phase = voting_phase(&state, &self.rules(), voting_now)?;
+        if matches!(finalization_phase, VotingPhase::Final | VotingPhase::Ended) {
             state.force_unstake_possible = true;
         }
 
         state.finalized = Some(now);
+        state.finalization_phase = Some(finalization_phase);
+        state.minimum_to_finalize_at_finalization = Some(minimum_to_finalize);
 
         self.state.insert(proposal_id, &state);
         self.active_proposals.set(
@@ -242,6 +361,16 @@ impl GovernData {
                 .checked_sub(1)
                 .ok_or(MathError::Overflow)?),
         );
+        let mut active_proposal_ids = self.active_proposal_ids();
+        active_proposal_ids.retain(|id| id != proposal_id);
+        self.active_proposal_ids.set(&active_proposal_ids);
+        self.active_proposals_of.insert(
+            state.proposer,
+            &(self
+                .active_proposals_of(&state.proposer)
+                .checked_sub(1)
+                .ok_or(MathError::Underflow)?),
+        );
         self.finalized_proposals.set(
             &(self
                 .finalized_proposals()
@@ -265,10 +394,61 @@ impl GovernData {
             return Err(GovernError::WrongStatus);
         }
         state.status = ProposalStatus::Executed;
+        state.executed = Some(ink::env::block_timestamp::<DefaultEnvironment>());
         self.state.insert(proposal_id, &state);
         Ok(())
     }
 
+    /// Rewrites `rules` from the layout of storage version 0.
+    pub fn migrate_rules_from_v0(&mut self) {
+        let rules_v0: Lazy<VotingRulesV0, ManualKey<STORAGE_KEY_GOVERNDATA_RULES>> = Lazy::new();
+        if let Some(rules) = rules_v0.get() {
+            self.rules.set(&VotingRules::from(rules));
+        }
+    }
+
+    /// Rewrites states of at most `count` proposals starting from `from` from the layout of storage version 0
+    /// and registers the active ones in `active_proposal_ids` and `active_proposals_of`.
+    ///
+    /// Returns the id of the next proposal to migrate or None if all proposals are migrated.
+    pub fn migrate_proposal_states_from_v0(
+        &mut self,
+        from: ProposalId,
+        count: u32,
+    ) -> Result<Option<ProposalId>, GovernError> {
+        let state_v0: Mapping<
+            ProposalId,
+            ProposalStateV0,
+            ManualKey<STORAGE_KEY_GOVERNDATA_STATE>,
+        > = Mapping::new();
+        let next_proposal_id = self.next_proposal_id();
+        let until = from.saturating_add(count).min(next_proposal_id);
+        let mut active_proposal_ids = self.active_proposal_ids();
+        for proposal_id in from..until {
+            let state = match state_v0.get(proposal_id) {
+                Some(state) => ProposalState::from(state),
+                None => continue,
+            };
+            self.state.insert(proposal_id, &state);
+            if state.status == ProposalStatus::Active {
+                active_proposal_ids.push(proposal_id);
+                self.active_proposals_of.insert(
+                    state.proposer,
+                    &(self
+                        .active_proposals_of(&state.proposer)
+                        .checked_add(1)
+                        .ok_or(MathError::Overflow)?),
+                );
+            }
+        }
+        self.active_proposal_ids.set(&active_proposal_ids);
+        Ok(if until < next_proposal_id {
+            Some(until)
+        } else {
+            None
+        })
+    }
+
     pub fn state_of(&self, proposal_id: &ProposalId) -> Option<ProposalState> {
         self.state.get(proposal_id)
     }
@@ -300,26 +480,38 @@ impl GovernData {
 
         let existing_user_vote = self.vote_of_for(account, proposal_id);
         match existing_user_vote {
-            None => match vote {
-                Vote::Agreed => {
-                    state.votes_for = state
-                        .votes_for
-                        .checked_add(*amount)
-                        .ok_or(MathError::Overflow)?
-                }
-                Vote::Disagreed => {
-                    state.votes_against = state
-                        .votes_against
-                        .checked_add(*amount)
-                        .ok_or(MathError::Overflow)?
-                }
-                Vote::DisagreedWithProposerSlashing => {
-                    state.votes_against_with_slash = state
-                        .votes_against_with_slash
-                        .checked_add(*amount)
-                        .ok_or(MathError::Overflow)?
+            None => {
+                state.voters_count = state
+                    .voters_count
+                    .checked_add(1)
+                    .ok_or(MathError::Overflow)?;
+                match vote {
+                    Vote::Agreed => {
+                        state.votes_for = state
+                            .votes_for
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?
+                    }
+                    Vote::Disagreed => {
+                        state.votes_against = state
+                            .votes_against
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?
+                    }
+                    Vote::DisagreedWithProposerSlashing => {
+                        state.votes_against_with_slash = state
+                            .votes_against_with_slash
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?
+                    }
+                    Vote::Abstain => {
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?
+                    }
                 }
-            },
+            }
             Some(old_vote) => match old_vote.vote {
                 Vote::Agreed => match vote {
                     Vote::Agreed => {
@@ -352,6 +544,16 @@ impl GovernData {
                             .checked_add(*amount)
                             .ok_or(MathError::Overflow)?;
                     }
+                    Vote::Abstain => {
+                        state.votes_for = state
+                            .votes_for
+                            .checked_sub(old_vote.amount)
+                            .ok_or(MathError::Underflow)?;
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?;
+                    }
                 },
                 Vote::Disagreed => match vote {
                     Vote::Agreed => {
@@ -384,6 +586,16 @@ impl GovernData {
                             .checked_add(*amount)
                             .ok_or(MathError::Overflow)?;
                     }
+                    Vote::Abstain => {
+                        state.votes_against = state
+                            .votes_against
+                            .checked_sub(old_vote.amount)
+                            .ok_or(MathError::Underflow)?;
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?;
+                    }
                 },
                 Vote::DisagreedWithProposerSlashing => match vote {
                     Vote::Agreed => {
@@ -416,6 +628,58 @@ impl GovernData {
                             .checked_add(*amount)
                             .ok_or(MathError::Overflow)?;
                     }
+                    Vote::Abstain => {
+                        state.votes_against_with_slash = state
+                            .votes_against_with_slash
+                            .checked_sub(old_vote.amount)
+                            .ok_or(MathError::Underflow)?;
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?;
+                    }
+                },
+                Vote::Abstain => match vote {
+                    Vote::Agreed => {
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_sub(old_vote.amount)
+                            .ok_or(MathError::Underflow)?;
+                        state.votes_for = state
+                            .votes_for
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?;
+                    }
+                    Vote::Disagreed => {
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_sub(old_vote.amount)
+                            .ok_or(MathError::Underflow)?;
+                        state.votes_against = state
+                            .votes_against
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?;
+                    }
+                    Vote::DisagreedWithProposerSlashing => {
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_sub(old_vote.amount)
+                            .ok_or(MathError::Underflow)?;
+                        state.votes_against_with_slash = state
+                            .votes_against_with_slash
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?;
+                    }
+                    Vote::Abstain => {
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_sub(old_vote.amount)
+                            .ok_or(MathError::Underflow)?;
+                        state.votes_abstain = state
+                            .votes_abstain
+                            .checked_add(*amount)
+                            .ok_or(MathError::Overflow)?;
+                    }
                 },
             },
         }
@@ -431,6 +695,106 @@ impl GovernData {
         Ok(())
     }
 
+    /// Sets the amount of the existing vote of `account` on active `proposal_id` to `amount` and updates the tallies accordingly.
+    /// Does nothing if `account` hasn't voted.
+    pub fn sync_vote_amount(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+        amount: &Balance,
+    ) -> Result<(), MathError> {
+        let mut user_vote = match self.vote_of_for(account, proposal_id) {
+            Some(user_vote) => user_vote,
+            None => return Ok(()),
+        };
+        let mut state = match self.state_of(proposal_id) {
+            Some(state) if state.status == ProposalStatus::Active => state,
+            _ => return Ok(()),
+        };
+
+        let tally = match user_vote.vote {
+            Vote::Agreed => &mut state.votes_for,
+            Vote::Disagreed => &mut state.votes_against,
+            Vote::DisagreedWithProposerSlashing => &mut state.votes_against_with_slash,
+            Vote::Abstain => &mut state.votes_abstain,
+        };
+        *tally = tally
+            .checked_sub(user_vote.amount)
+            .ok_or(MathError::Underflow)?
+            .checked_add(*amount)
+            .ok_or(MathError::Overflow)?;
+        user_vote.amount = *amount;
+
+        self.votes.insert((*account, *proposal_id), &user_vote);
+        self.state.insert(proposal_id, &state);
+        Ok(())
+    }
+
+    pub fn commit_of_for(&self, account: &AccountId, proposal_id: &ProposalId) -> Option<Hash> {
+        self.commits.get((*account, *proposal_id))
+    }
+
+    /// Stores `vote_hash` of `account` on `proposal_id`. Overrides the previous commit.
+    pub fn commit_vote(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+        vote_hash: &Hash,
+        voting_paused_duration: Timestamp,
+    ) -> Result<(), GovernError> {
+        let state = self.active_commit_reveal_state_of(proposal_id)?;
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        if voting_time(&state, now, voting_paused_duration)?
+            > final_period_end(&state, &self.rules())?
+        {
+            return Err(GovernError::NotCommitPeriod);
+        }
+
+        self.commits.insert((*account, *proposal_id), vote_hash);
+        Ok(())
+    }
+
+    /// Removes and returns the commit of `account` on `proposal_id` if it is the reveal period.
+    pub fn take_commit(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+        voting_paused_duration: Timestamp,
+    ) -> Result<Hash, GovernError> {
+        let state = self.active_commit_reveal_state_of(proposal_id)?;
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        let voting_now = voting_time(&state, now, voting_paused_duration)?;
+        let final_period_end = final_period_end(&state, &self.rules())?;
+        if voting_now <= final_period_end
+            || voting_now
+                > final_period_end
+                    .checked_add(self.commit_reveal_rules().reveal_period)
+                    .ok_or(MathError::Overflow)?
+        {
+            return Err(GovernError::NotRevealPeriod);
+        }
+
+        self.commits
+            .take((*account, *proposal_id))
+            .ok_or(GovernError::NoCommit)
+    }
+
+    fn active_commit_reveal_state_of(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalState, GovernError> {
+        let state = self
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        if state.status != ProposalStatus::Active {
+            return Err(GovernError::WrongStatus);
+        }
+        if !state.commit_reveal {
+            return Err(GovernError::WrongVotingMode);
+        }
+        Ok(state)
+    }
+
     pub fn force_unstake(
         &mut self,
         account: &AccountId,
@@ -444,9 +808,15 @@ impl GovernData {
             return Err(GovernError::CantForceUnstake);
         }
 
-        if state.finalized.unwrap_or_default()
-            <= self.last_stake_timestamp(account).unwrap_or_default()
+        let finalized = state.finalized.unwrap_or_default();
+        if ink::env::block_timestamp::<DefaultEnvironment>()
+            < finalized
+                .checked_add(self.rules().force_unstake_grace_period)
+                .ok_or(MathError::Overflow)?
         {
+            return Err(GovernError::ForceUnstakeGracePeriod);
+        }
+        if finalized <= self.last_stake_timestamp(account).unwrap_or_default() {
             return Err(GovernError::CantForceUnstake);
         }
         if self.vote_of_for(account, proposal_id).is_some() {
@@ -462,3 +832,873 @@ impl GovernData {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ink::env::test::set_block_timestamp;
+
+    use super::*;
+
+    const DAY: Timestamp = 24 * 60 * 60 * 1000;
+    const START: Timestamp = 1_000 * DAY;
+    const VOTES_AT_START: Balance = 1_000;
+
+    const PROPOSER: [u8; 32] = [1; 32];
+    const ALICE: [u8; 32] = [2; 32];
+    const BOB: [u8; 32] = [3; 32];
+
+    const VOTES: [Vote; 4] = [
+        Vote::Agreed,
+        Vote::Disagreed,
+        Vote::DisagreedWithProposerSlashing,
+        Vote::Abstain,
+    ];
+
+    fn rules() -> VotingRules {
+        VotingRules {
+            minimum_stake_part_e3: 10,
+            proposer_deposit_part_e3: 100,
+            initial_period: 3 * DAY,
+            flat_period: 7 * DAY,
+            final_period: 4 * DAY,
+            minimum_quorum_e3: 0,
+            maximum_active_proposals: 100,
+            maximum_active_proposals_per_proposer: 100,
+            minimum_proposal_interval: 0,
+            force_unstake_grace_period: 0,
+        }
+    }
+
+    fn set_time(timestamp: Timestamp) {
+        set_block_timestamp::<DefaultEnvironment>(timestamp);
+    }
+
+    fn register(
+        govern: &mut GovernData,
+        proposer: [u8; 32],
+        seed: u8,
+        earliest_execution: Option<Timestamp>,
+    ) -> Result<ProposalId, GovernError> {
+        govern.register_new_proposal(
+            &proposer.into(),
+            &Hash::from([seed; 32]),
+            earliest_execution,
+            &String::from("url"),
+            &Hash::from([seed; 32]),
+            VOTES_AT_START,
+            0,
+            0,
+            false,
+        )
+    }
+
+    fn setup() -> (GovernData, ProposalId) {
+        set_time(START);
+        let mut govern = GovernData::new(&rules());
+        let proposal_id = register(&mut govern, PROPOSER, 0, None).unwrap();
+        (govern, proposal_id)
+    }
+
+    fn vote(
+        govern: &mut GovernData,
+        account: [u8; 32],
+        proposal_id: ProposalId,
+        vote: Vote,
+        amount: Balance,
+    ) {
+        govern
+            .update_vote_of_for(&account.into(), &proposal_id, &vote, &amount)
+            .unwrap();
+    }
+
+    fn tally(state: &ProposalState, vote: &Vote) -> Balance {
+        match vote {
+            Vote::Agreed => state.votes_for,
+            Vote::Disagreed => state.votes_against,
+            Vote::DisagreedWithProposerSlashing => state.votes_against_with_slash,
+            Vote::Abstain => state.votes_abstain,
+        }
+    }
+
+    #[ink::test]
+    fn register_creates_active_proposal() {
+        let (govern, proposal_id) = setup();
+
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert_eq!(state.status, ProposalStatus::Active);
+        assert_eq!(state.proposer, AccountId::from(PROPOSER));
+        assert_eq!(state.start, START);
+        assert_eq!(state.votes_at_start, VOTES_AT_START);
+        assert_eq!(state.finalized, None);
+        assert_eq!(state.voters_count, 0);
+        assert_eq!(govern.active_proposals(), 1);
+        assert_eq!(govern.active_proposal_ids(), vec![proposal_id]);
+        assert_eq!(govern.active_proposals_of(&PROPOSER.into()), 1);
+        assert_eq!(
+            govern.last_proposal_timestamp(&PROPOSER.into()),
+            Some(START)
+        );
+        assert_eq!(govern.next_proposal_id(), proposal_id + 1);
+        assert_eq!(
+            govern.proposal_hash_to_id(&Hash::from([0; 32])),
+            Some(proposal_id)
+        );
+    }
+
+    #[ink::test]
+    fn register_fails_for_existing_proposal() {
+        let (mut govern, _) = setup();
+
+        assert!(matches!(
+            register(&mut govern, ALICE, 0, None),
+            Err(GovernError::ProposalAlreadyExists)
+        ));
+    }
+
+    #[ink::test]
+    fn register_fails_for_commit_reveal_when_disabled() {
+        let (mut govern, _) = setup();
+
+        let res = govern.register_new_proposal(
+            &PROPOSER.into(),
+            &Hash::from([1; 32]),
+            None,
+            &String::from("url"),
+            &Hash::from([1; 32]),
+            VOTES_AT_START,
+            0,
+            0,
+            true,
+        );
+        assert!(matches!(res, Err(GovernError::CommitRevealDisabled)));
+    }
+
+    #[ink::test]
+    fn register_respects_rate_limits() {
+        set_time(START);
+        let mut govern = GovernData::new(&VotingRules {
+            maximum_active_proposals: 2,
+            maximum_active_proposals_per_proposer: 1,
+            minimum_proposal_interval: DAY,
+            ..rules()
+        });
+        register(&mut govern, PROPOSER, 0, None).unwrap();
+
+        assert!(matches!(
+            register(&mut govern, PROPOSER, 1, None),
+            Err(GovernError::TooManyActiveProposalsOfProposer)
+        ));
+        register(&mut govern, ALICE, 1, None).unwrap();
+        assert!(matches!(
+            register(&mut govern, BOB, 2, None),
+            Err(GovernError::TooManyActiveProposals)
+        ));
+
+        // finalize the first proposal to release the limits
+        vote(&mut govern, ALICE, 0, Vote::Agreed, VOTES_AT_START);
+        govern.finalize(&0, 0, 0).unwrap();
+        assert_eq!(govern.active_proposals_of(&PROPOSER.into()), 0);
+        assert!(matches!(
+            register(&mut govern, PROPOSER, 2, None),
+            Err(GovernError::ProposalTooSoon)
+        ));
+        set_time(START + DAY);
+        register(&mut govern, PROPOSER, 2, None).unwrap();
+    }
+
+    #[ink::test]
+    fn finalize_fails_for_non_existing_proposal() {
+        let (mut govern, _) = setup();
+
+        assert!(matches!(
+            govern.finalize(&7, 0, 0),
+            Err(GovernError::ProposalDoesntExist)
+        ));
+    }
+
+    #[ink::test]
+    fn finalize_fails_below_minimum_to_finalize() {
+        let (mut govern, proposal_id) = setup();
+        // in the initial period all votes are required
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START - 1,
+        );
+        assert!(matches!(
+            govern.finalize(&proposal_id, 0, 0),
+            Err(GovernError::FinalizeCondition)
+        ));
+
+        // in the flat period half of votes is required
+        set_time(START + 5 * DAY);
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START / 2 - 1,
+        );
+        assert!(matches!(
+            govern.finalize(&proposal_id, 0, 0),
+            Err(GovernError::FinalizeCondition)
+        ));
+    }
+
+    #[ink::test]
+    fn finalize_succeeded_in_initial_period() {
+        let (mut govern, proposal_id) = setup();
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert_eq!(state.status, ProposalStatus::Succeeded);
+        assert!(!state.force_unstake_possible);
+        assert_eq!(state.finalized, Some(START));
+        assert_eq!(state.finalization_phase, Some(VotingPhase::Initial));
+        assert_eq!(
+            state.minimum_to_finalize_at_finalization,
+            Some(VOTES_AT_START)
+        );
+        assert_eq!(govern.active_proposals(), 0);
+        assert!(govern.active_proposal_ids().is_empty());
+        assert_eq!(govern.finalized_proposals(), 1);
+    }
+
+    #[ink::test]
+    fn finalize_defeated_in_flat_period() {
+        let (mut govern, proposal_id) = setup();
+        set_time(START + 5 * DAY);
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Disagreed,
+            VOTES_AT_START / 2,
+        );
+        vote(
+            &mut govern,
+            BOB,
+            proposal_id,
+            Vote::DisagreedWithProposerSlashing,
+            100,
+        );
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert_eq!(state.finalization_phase, Some(VotingPhase::Flat));
+        assert_eq!(
+            state.minimum_to_finalize_at_finalization,
+            Some(VOTES_AT_START / 2)
+        );
+    }
+
+    #[ink::test]
+    fn finalize_defeated_on_tie() {
+        let (mut govern, proposal_id) = setup();
+        set_time(START + 5 * DAY);
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START / 2,
+        );
+        vote(
+            &mut govern,
+            BOB,
+            proposal_id,
+            Vote::Disagreed,
+            VOTES_AT_START / 2,
+        );
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+    }
+
+    #[ink::test]
+    fn finalize_defeated_with_slash() {
+        let (mut govern, proposal_id) = setup();
+        set_time(START + 5 * DAY);
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::DisagreedWithProposerSlashing,
+            600,
+        );
+        vote(&mut govern, BOB, proposal_id, Vote::Disagreed, 100);
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::DefeatedWithSlash
+        );
+    }
+
+    #[ink::test]
+    fn finalize_doesnt_count_abstain_toward_minimum_to_finalize() {
+        let (mut govern, proposal_id) = setup();
+        // in the flat period half of votes is required
+        set_time(START + 5 * DAY);
+        vote(&mut govern, ALICE, proposal_id, Vote::Abstain, 400);
+        vote(&mut govern, BOB, proposal_id, Vote::Agreed, 499);
+        assert!(matches!(
+            govern.finalize(&proposal_id, 0, 0),
+            Err(GovernError::FinalizeCondition)
+        ));
+
+        vote(&mut govern, BOB, proposal_id, Vote::Agreed, 500);
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+    }
+
+    #[ink::test]
+    fn finalize_counts_abstain_toward_quorum() {
+        set_time(START);
+        let mut govern = GovernData::new(&VotingRules {
+            minimum_quorum_e3: 300,
+            ..rules()
+        });
+        let without_abstain = register(&mut govern, PROPOSER, 0, None).unwrap();
+        let with_abstain = register(&mut govern, PROPOSER, 1, None).unwrap();
+        vote(&mut govern, BOB, without_abstain, Vote::Agreed, 100);
+        vote(&mut govern, BOB, with_abstain, Vote::Agreed, 100);
+        vote(&mut govern, ALICE, with_abstain, Vote::Abstain, 200);
+
+        set_time(START + 14 * DAY + 1);
+        assert_eq!(
+            govern.finalize(&without_abstain, 0, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+        assert_eq!(
+            govern.finalize(&with_abstain, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+    }
+
+    #[ink::test]
+    fn finalize_in_final_period_allows_force_unstake() {
+        let (mut govern, proposal_id) = setup();
+        set_time(START + 12 * DAY);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 250);
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert!(state.force_unstake_possible);
+        assert_eq!(state.finalization_phase, Some(VotingPhase::Final));
+    }
+
+    #[ink::test]
+    fn finalize_at_the_end_of_flat_period_doesnt_allow_force_unstake() {
+        let (mut govern, proposal_id) = setup();
+        set_time(START + 10 * DAY);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 500);
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert!(!state.force_unstake_possible);
+        assert_eq!(state.finalization_phase, Some(VotingPhase::Flat));
+    }
+
+    #[ink::test]
+    fn finalize_right_after_flat_period_allows_force_unstake() {
+        let (mut govern, proposal_id) = setup();
+        set_time(START + 10 * DAY + 1);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 500);
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert!(state.force_unstake_possible);
+        assert_eq!(state.finalization_phase, Some(VotingPhase::Final));
+    }
+
+    #[ink::test]
+    fn finalize_without_votes_after_final_period() {
+        let (mut govern, proposal_id) = setup();
+        set_time(START + 14 * DAY + 1);
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert_eq!(state.finalization_phase, Some(VotingPhase::Ended));
+        assert_eq!(state.minimum_to_finalize_at_finalization, Some(0));
+    }
+
+    #[ink::test]
+    fn finalize_defeated_when_quorum_not_reached() {
+        set_time(START);
+        let mut govern = GovernData::new(&VotingRules {
+            minimum_quorum_e3: 300,
+            ..rules()
+        });
+        let proposal_id = register(&mut govern, PROPOSER, 0, None).unwrap();
+        set_time(START + 12 * DAY);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 299);
+        assert!(matches!(
+            govern.finalize(&proposal_id, 0, 0),
+            Err(GovernError::FinalizeCondition)
+        ));
+
+        set_time(START + 14 * DAY + 1);
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+    }
+
+    fn setup_with_quorum(minimum_quorum_e3: u16) -> (GovernData, ProposalId) {
+        set_time(START);
+        let mut govern = GovernData::new(&VotingRules {
+            minimum_quorum_e3,
+            ..rules()
+        });
+        let proposal_id = register(&mut govern, PROPOSER, 0, None).unwrap();
+        (govern, proposal_id)
+    }
+
+    #[ink::test]
+    fn finalize_below_quorum_waits_until_final_period_end() {
+        let (mut govern, proposal_id) = setup_with_quorum(300);
+        // abstaining votes count toward the quorum
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 200);
+        vote(&mut govern, BOB, proposal_id, Vote::Abstain, 99);
+
+        for time in [START, START + 5 * DAY, START + 12 * DAY, START + 14 * DAY] {
+            set_time(time);
+            assert!(matches!(
+                govern.finalize(&proposal_id, 0, 0),
+                Err(GovernError::FinalizeCondition)
+            ));
+        }
+        assert_eq!(
+            govern.state_of(&proposal_id).unwrap().status,
+            ProposalStatus::Active
+        );
+    }
+
+    #[ink::test]
+    fn finalize_below_quorum_after_final_period_end_is_defeated() {
+        let (mut govern, proposal_id) = setup_with_quorum(300);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 299);
+
+        set_time(START + 14 * DAY + 1);
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert_eq!(state.finalization_phase, Some(VotingPhase::Ended));
+        assert!(state.force_unstake_possible);
+    }
+
+    #[ink::test]
+    fn finalize_above_quorum_before_final_period_end() {
+        let (mut govern, proposal_id) = setup_with_quorum(300);
+        set_time(START + 12 * DAY);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 300);
+
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+        assert_eq!(
+            govern.state_of(&proposal_id).unwrap().finalization_phase,
+            Some(VotingPhase::Final)
+        );
+    }
+
+    #[ink::test]
+    fn finalize_above_quorum_after_final_period_end() {
+        let (mut govern, proposal_id) = setup_with_quorum(300);
+        vote(&mut govern, ALICE, proposal_id, Vote::Disagreed, 200);
+        vote(&mut govern, BOB, proposal_id, Vote::Agreed, 100);
+
+        set_time(START + 14 * DAY + 1);
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+
+        let proposal_id = register(&mut govern, PROPOSER, 1, None).unwrap();
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 200);
+        vote(&mut govern, BOB, proposal_id, Vote::Abstain, 100);
+        set_time(START + 28 * DAY + 2);
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+    }
+
+    #[ink::test]
+    fn finalize_quorum_uses_counter_to_update_total_votes() {
+        let (mut govern, proposal_id) = setup_with_quorum(300);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 300);
+        set_time(START + 14 * DAY + 1);
+        // other accounts staked in the meantime so 300 votes no longer reach the quorum
+        assert_eq!(
+            govern.finalize(&proposal_id, 10, 0).unwrap(),
+            ProposalStatus::Defeated
+        );
+    }
+
+    #[ink::test]
+    fn finalize_twice_fails() {
+        let (mut govern, proposal_id) = setup();
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+
+        assert!(matches!(
+            govern.finalize(&proposal_id, 0, 0),
+            Err(GovernError::WrongStatus)
+        ));
+    }
+
+    #[ink::test]
+    fn finalize_uses_counter_to_update_total_votes() {
+        let (mut govern, proposal_id) = setup();
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+        // other accounts staked in the meantime
+        assert!(matches!(
+            govern.finalize(&proposal_id, 2, 0),
+            Err(GovernError::FinalizeCondition)
+        ));
+        assert_eq!(
+            govern.finalize(&proposal_id, 0, 0).unwrap(),
+            ProposalStatus::Succeeded
+        );
+    }
+
+    #[ink::test]
+    fn mark_as_executed_transitions() {
+        let (mut govern, proposal_id) = setup();
+        assert!(matches!(
+            govern.mark_as_executed(&proposal_id),
+            Err(GovernError::WrongStatus)
+        ));
+        assert!(matches!(
+            govern.mark_as_executed(&7),
+            Err(GovernError::ProposalDoesntExist)
+        ));
+
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+        set_time(START + DAY);
+        govern.mark_as_executed(&proposal_id).unwrap();
+
+        let state = govern.state_of(&proposal_id).unwrap();
+        assert_eq!(state.status, ProposalStatus::Executed);
+        assert_eq!(state.executed, Some(START + DAY));
+        assert!(matches!(
+            govern.mark_as_executed(&proposal_id),
+            Err(GovernError::WrongStatus)
+        ));
+    }
+
+    #[ink::test]
+    fn mark_as_executed_fails_for_defeated() {
+        let (mut govern, proposal_id) = setup();
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Disagreed,
+            VOTES_AT_START,
+        );
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+
+        assert!(matches!(
+            govern.mark_as_executed(&proposal_id),
+            Err(GovernError::WrongStatus)
+        ));
+    }
+
+    #[ink::test]
+    fn mark_as_executed_respects_earliest_execution() {
+        set_time(START);
+        let mut govern = GovernData::new(&rules());
+        let proposal_id = register(&mut govern, PROPOSER, 0, Some(START + DAY)).unwrap();
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+
+        assert!(matches!(
+            govern.mark_as_executed(&proposal_id),
+            Err(GovernError::TooEarlyToExecuteProposal)
+        ));
+        set_time(START + DAY);
+        govern.mark_as_executed(&proposal_id).unwrap();
+    }
+
+    #[ink::test]
+    fn update_vote_fails_on_invalid_input() {
+        let (mut govern, proposal_id) = setup();
+        assert!(matches!(
+            govern.update_vote_of_for(&ALICE.into(), &proposal_id, &Vote::Agreed, &0),
+            Err(GovernError::InsuficientVotes)
+        ));
+        assert!(matches!(
+            govern.update_vote_of_for(&ALICE.into(), &7, &Vote::Agreed, &1),
+            Err(GovernError::ProposalDoesntExist)
+        ));
+
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+        assert!(matches!(
+            govern.update_vote_of_for(&BOB.into(), &proposal_id, &Vote::Agreed, &1),
+            Err(GovernError::WrongStatus)
+        ));
+    }
+
+    #[ink::test]
+    fn update_vote_counts_new_vote_of_every_type() {
+        set_time(START);
+        let mut govern = GovernData::new(&rules());
+        for (seed, new_vote) in VOTES.iter().enumerate() {
+            let proposal_id = register(&mut govern, PROPOSER, seed as u8, None).unwrap();
+            vote(&mut govern, ALICE, proposal_id, *new_vote, 10);
+
+            let state = govern.state_of(&proposal_id).unwrap();
+            for counted in VOTES.iter() {
+                let expected = if counted == new_vote { 10 } else { 0 };
+                assert_eq!(tally(&state, counted), expected);
+            }
+            assert_eq!(state.voters_count, 1);
+            assert_eq!(
+                govern.vote_of_for(&ALICE.into(), &proposal_id),
+                Some(UserVote {
+                    vote: *new_vote,
+                    amount: 10
+                })
+            );
+        }
+    }
+
+    #[ink::test]
+    fn update_vote_switches_between_every_pair_of_votes() {
+        set_time(START);
+        let mut govern = GovernData::new(&rules());
+        let mut seed = 0;
+        for old_vote in VOTES.iter() {
+            for new_vote in VOTES.iter() {
+                let proposal_id = register(&mut govern, PROPOSER, seed, None).unwrap();
+                seed += 1;
+                vote(&mut govern, BOB, proposal_id, *old_vote, 5);
+                vote(&mut govern, ALICE, proposal_id, *old_vote, 10);
+                vote(&mut govern, ALICE, proposal_id, *new_vote, 7);
+
+                let state = govern.state_of(&proposal_id).unwrap();
+                for counted in VOTES.iter() {
+                    let mut expected = 0;
+                    if counted == old_vote {
+                        expected += 5;
+                    }
+                    if counted == new_vote {
+                        expected += 7;
+                    }
+                    assert_eq!(tally(&state, counted), expected);
+                }
+                // changing the vote doesn't add a voter
+                assert_eq!(state.voters_count, 2);
+                assert_eq!(
+                    govern.vote_of_for(&ALICE.into(), &proposal_id),
+                    Some(UserVote {
+                        vote: *new_vote,
+                        amount: 7
+                    })
+                );
+            }
+        }
+    }
+
+    #[ink::test]
+    fn sync_vote_amount_updates_tally() {
+        let (mut govern, proposal_id) = setup();
+        vote(&mut govern, ALICE, proposal_id, Vote::Disagreed, 10);
+
+        govern
+            .sync_vote_amount(&ALICE.into(), &proposal_id, &25)
+            .unwrap();
+        assert_eq!(govern.state_of(&proposal_id).unwrap().votes_against, 25);
+        assert_eq!(
+            govern
+                .vote_of_for(&ALICE.into(), &proposal_id)
+                .unwrap()
+                .amount,
+            25
+        );
+
+        // accounts that didn't vote are ignored
+        govern
+            .sync_vote_amount(&BOB.into(), &proposal_id, &25)
+            .unwrap();
+        assert_eq!(govern.vote_of_for(&BOB.into(), &proposal_id), None);
+        assert_eq!(govern.state_of(&proposal_id).unwrap().votes_against, 25);
+    }
+
+    #[ink::test]
+    fn sync_vote_amount_ignores_finalized_proposals() {
+        let (mut govern, proposal_id) = setup();
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+
+        govern
+            .sync_vote_amount(&ALICE.into(), &proposal_id, &1)
+            .unwrap();
+        assert_eq!(
+            govern.state_of(&proposal_id).unwrap().votes_for,
+            VOTES_AT_START
+        );
+    }
+
+    fn finalized_in_final_period(rules: &VotingRules) -> (GovernData, ProposalId) {
+        set_time(START - 1);
+        let mut govern = GovernData::new(rules);
+        govern.set_last_stake_timestamp(&BOB.into());
+        set_time(START);
+        let proposal_id = register(&mut govern, PROPOSER, 0, None).unwrap();
+        set_time(START + 12 * DAY);
+        vote(&mut govern, ALICE, proposal_id, Vote::Agreed, 250);
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+        (govern, proposal_id)
+    }
+
+    #[ink::test]
+    fn force_unstake_of_account_that_did_not_vote() {
+        let (mut govern, proposal_id) = finalized_in_final_period(&rules());
+
+        govern.force_unstake(&BOB.into(), &proposal_id).unwrap();
+        assert_eq!(govern.last_force_unstake(&BOB.into()), Some(proposal_id));
+        // can't be force unstaked twice for the same proposal
+        assert!(matches!(
+            govern.force_unstake(&BOB.into(), &proposal_id),
+            Err(GovernError::CantForceUnstake)
+        ));
+    }
+
+    #[ink::test]
+    fn force_unstake_fails_for_voter() {
+        let (mut govern, proposal_id) = finalized_in_final_period(&rules());
+
+        assert!(matches!(
+            govern.force_unstake(&ALICE.into(), &proposal_id),
+            Err(GovernError::CantForceUnstake)
+        ));
+    }
+
+    #[ink::test]
+    fn force_unstake_fails_for_account_staked_after_finalization() {
+        let (mut govern, proposal_id) = finalized_in_final_period(&rules());
+        govern.remove_last_stake_timestamp(&BOB.into());
+        set_time(START + 13 * DAY);
+        govern.set_last_stake_timestamp(&BOB.into());
+
+        assert!(matches!(
+            govern.force_unstake(&BOB.into(), &proposal_id),
+            Err(GovernError::CantForceUnstake)
+        ));
+    }
+
+    #[ink::test]
+    fn force_unstake_fails_when_finalized_before_final_period() {
+        set_time(START - 1);
+        let mut govern = GovernData::new(&rules());
+        govern.set_last_stake_timestamp(&BOB.into());
+        set_time(START);
+        let proposal_id = register(&mut govern, PROPOSER, 0, None).unwrap();
+        vote(
+            &mut govern,
+            ALICE,
+            proposal_id,
+            Vote::Agreed,
+            VOTES_AT_START,
+        );
+        govern.finalize(&proposal_id, 0, 0).unwrap();
+
+        assert!(matches!(
+            govern.force_unstake(&BOB.into(), &proposal_id),
+            Err(GovernError::CantForceUnstake)
+        ));
+        assert!(matches!(
+            govern.force_unstake(&BOB.into(), &7),
+            Err(GovernError::ProposalDoesntExist)
+        ));
+    }
+
+    #[ink::test]
+    fn force_unstake_respects_grace_period() {
+        let (mut govern, proposal_id) = finalized_in_final_period(&VotingRules {
+            force_unstake_grace_period: DAY,
+            ..rules()
+        });
+
+        assert!(matches!(
+            govern.force_unstake(&BOB.into(), &proposal_id),
+            Err(GovernError::ForceUnstakeGracePeriod)
+        ));
+        set_time(START + 13 * DAY);
+        govern.force_unstake(&BOB.into(), &proposal_id).unwrap();
+    }
+}
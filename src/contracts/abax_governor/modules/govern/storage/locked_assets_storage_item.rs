@@ -0,0 +1,93 @@
+use ink::storage::Mapping;
+use pendzl::{math::errors::MathError, traits::Balance};
+
+use crate::modules::govern::traits::{ProposalHash, ProposalId};
+
+/// Proposer deposits taken in assets. They are excluded from the vault's total assets.
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct LockedAssetsData {
+    locked: Mapping<ProposalId, Balance>,
+    /// deposits of proposers of drafts that weren't activated yet.
+    locked_for_draft: Mapping<ProposalHash, Balance>,
+    #[lazy]
+    total_locked: Balance,
+}
+
+impl LockedAssetsData {
+    pub fn locked(&self, proposal_id: &ProposalId) -> Balance {
+        self.locked.get(proposal_id).unwrap_or_default()
+    }
+
+    pub fn locked_for_draft(&self, proposal_hash: &ProposalHash) -> Balance {
+        self.locked_for_draft.get(proposal_hash).unwrap_or_default()
+    }
+
+    pub fn total_locked(&self) -> Balance {
+        self.total_locked.get().unwrap_or_default()
+    }
+
+    pub fn lock(&mut self, proposal_id: &ProposalId, amount: Balance) -> Result<(), MathError> {
+        let mut locked = self.locked(proposal_id);
+        locked = locked.checked_add(amount).ok_or(MathError::Overflow)?;
+        self.locked.insert(proposal_id, &locked);
+        self.total_locked.set(
+            &self
+                .total_locked()
+                .checked_add(amount)
+                .ok_or(MathError::Overflow)?,
+        );
+        Ok(())
+    }
+
+    pub fn lock_for_draft(
+        &mut self,
+        proposal_hash: &ProposalHash,
+        amount: Balance,
+    ) -> Result<(), MathError> {
+        let locked = self
+            .locked_for_draft(proposal_hash)
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        self.locked_for_draft.insert(proposal_hash, &locked);
+        self.total_locked.set(
+            &self
+                .total_locked()
+                .checked_add(amount)
+                .ok_or(MathError::Overflow)?,
+        );
+        Ok(())
+    }
+
+    /// Unlocks the whole deposit of the draft with `proposal_hash` and returns it.
+    pub fn unlock_for_draft(&mut self, proposal_hash: &ProposalHash) -> Result<Balance, MathError> {
+        let locked = self
+            .locked_for_draft
+            .take(proposal_hash)
+            .unwrap_or_default();
+        self.total_locked.set(
+            &self
+                .total_locked()
+                .checked_sub(locked)
+                .ok_or(MathError::Underflow)?,
+        );
+        Ok(locked)
+    }
+
+    pub fn unlock(&mut self, proposal_id: &ProposalId, amount: Balance) -> Result<(), MathError> {
+        let mut locked = self.locked(proposal_id);
+        locked = locked.checked_sub(amount).ok_or(MathError::Underflow)?;
+        if locked > 0 {
+            self.locked.insert(proposal_id, &locked);
+        } else {
+            self.locked.remove(proposal_id);
+        }
+        self.total_locked.set(
+            &self
+                .total_locked()
+                .checked_sub(amount)
+                .ok_or(MathError::Underflow)?,
+        );
+        Ok(())
+    }
+}
@@ -7,6 +7,8 @@ use crate::modules::govern::traits::ProposalId;
 #[pendzl::storage_item]
 pub struct LockedSharesData {
     locked: Mapping<ProposalId, Balance>,
+    /// deposits of sponsors of proposals activated from drafts.
+    locked_by_sponsors: Mapping<ProposalId, Balance>,
 }
 
 impl LockedSharesData {
@@ -31,4 +33,38 @@ impl LockedSharesData {
         }
         Ok(())
     }
+
+    pub fn locked_by_sponsors(&self, proposal_id: &ProposalId) -> Balance {
+        self.locked_by_sponsors.get(proposal_id).unwrap_or_default()
+    }
+
+    pub fn lock_by_sponsors(
+        &mut self,
+        proposal_id: &ProposalId,
+        amount: Balance,
+    ) -> Result<(), MathError> {
+        let locked = self
+            .locked_by_sponsors(proposal_id)
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        self.locked_by_sponsors.insert(proposal_id, &locked);
+        Ok(())
+    }
+
+    pub fn unlock_by_sponsors(
+        &mut self,
+        proposal_id: &ProposalId,
+        amount: Balance,
+    ) -> Result<(), MathError> {
+        let locked = self
+            .locked_by_sponsors(proposal_id)
+            .checked_sub(amount)
+            .ok_or(MathError::Underflow)?;
+        if locked > 0 {
+            self.locked_by_sponsors.insert(proposal_id, &locked);
+        } else {
+            self.locked_by_sponsors.remove(proposal_id);
+        }
+        Ok(())
+    }
 }
@@ -1,4 +1,9 @@
 pub mod govern_storage_item;
+pub mod locked_assets_storage_item;
 pub mod locked_shares_storage_item;
+pub mod pause_storage_item;
+pub mod sponsorship_storage_item;
 pub mod unstake_storage_item;
 pub mod vault_counter_storage_item;
+pub mod version_storage_item;
+pub mod vested_votes_storage_item;
@@ -0,0 +1,224 @@
+use pendzl::{math::errors::MathError, traits::Timestamp};
+
+use crate::modules::govern::traits::{GovernError, Pause, PausedMessages};
+
+const DAY: Timestamp = 24 * 60 * 60 * 1000;
+/// maximal duration of a single pause. After that time the pause expires automatically.
+pub const MAXIMAL_PAUSE_DURATION: Timestamp = 14 * DAY;
+/// minimal time between the end of a pause and the start of the next one.
+pub const PAUSE_COOLDOWN: Timestamp = 14 * DAY;
+
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct PauseData {
+    #[lazy]
+    pause: Option<Pause>,
+    /// total duration during which voting was paused, counted up to `settled_at`.
+    #[lazy]
+    voting_paused_duration: Timestamp,
+    #[lazy]
+    settled_at: Timestamp,
+}
+
+impl PauseData {
+    pub fn pause(&self) -> Option<Pause> {
+        self.pause.get().flatten()
+    }
+
+    pub fn is_paused(&self, now: Timestamp) -> Option<PausedMessages> {
+        self.pause()
+            .filter(|pause| now < pause.end)
+            .map(|pause| pause.messages)
+    }
+
+    /// Returns total duration during which voting was paused up to `now`.
+    pub fn voting_paused_duration(&self, now: Timestamp) -> Result<Timestamp, MathError> {
+        let settled = self.voting_paused_duration.get().unwrap_or_default();
+        let pause = match self.pause() {
+            Some(pause) if pause.messages.vote => pause,
+            _ => return Ok(settled),
+        };
+        let from = pause.start.max(self.settled_at.get().unwrap_or_default());
+        let to = pause.end.min(now);
+        if to <= from {
+            return Ok(settled);
+        }
+        settled
+            .checked_add(to.checked_sub(from).ok_or(MathError::Underflow)?)
+            .ok_or(MathError::Overflow)
+    }
+
+    /// Pauses `messages` until `now + duration`.
+    /// If there is an ongoing pause, it is updated. Its end can't be further than `MAXIMAL_PAUSE_DURATION` from its start.
+    pub fn set_pause(
+        &mut self,
+        messages: &PausedMessages,
+        duration: Timestamp,
+        now: Timestamp,
+    ) -> Result<Pause, GovernError> {
+        let end = now.checked_add(duration).ok_or(MathError::Overflow)?;
+        let start = match self.pause() {
+            Some(pause) if now < pause.end => pause.start,
+            Some(pause) => {
+                if now
+                    < pause
+                        .end
+                        .checked_add(PAUSE_COOLDOWN)
+                        .ok_or(MathError::Overflow)?
+                {
+                    return Err(GovernError::PauseCooldown);
+                }
+                now
+            }
+            None => now,
+        };
+
+        if end
+            > start
+                .checked_add(MAXIMAL_PAUSE_DURATION)
+                .ok_or(MathError::Overflow)?
+        {
+            return Err(GovernError::PauseTooLong);
+        }
+
+        self.settle(now)?;
+        let pause = Pause {
+            messages: *messages,
+            start,
+            end,
+        };
+        self.pause.set(&Some(pause));
+        Ok(pause)
+    }
+
+    /// Ends the ongoing pause at `now`.
+    pub fn unpause(&mut self, now: Timestamp) -> Result<(), GovernError> {
+        let mut pause = match self.pause() {
+            Some(pause) if now < pause.end => pause,
+            _ => return Err(GovernError::NotPaused),
+        };
+        self.settle(now)?;
+        pause.end = now;
+        self.pause.set(&Some(pause));
+        Ok(())
+    }
+
+    fn settle(&mut self, now: Timestamp) -> Result<(), MathError> {
+        let voting_paused_duration = self.voting_paused_duration(now)?;
+        self.voting_paused_duration.set(&voting_paused_duration);
+        self.settled_at.set(&now);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: Timestamp = 1_000 * DAY;
+
+    fn messages(vote: bool) -> PausedMessages {
+        PausedMessages {
+            propose: true,
+            vote,
+            ..Default::default()
+        }
+    }
+
+    #[ink::test]
+    fn set_pause_pauses_messages_until_its_end() {
+        let mut pause = PauseData::default();
+        assert_eq!(pause.is_paused(START), None);
+
+        let set = pause.set_pause(&messages(true), DAY, START).unwrap();
+        assert_eq!(set.start, START);
+        assert_eq!(set.end, START + DAY);
+        assert_eq!(pause.is_paused(START + DAY - 1), Some(messages(true)));
+        assert_eq!(pause.is_paused(START + DAY), None);
+    }
+
+    #[ink::test]
+    fn set_pause_fails_when_too_long() {
+        let mut pause = PauseData::default();
+        assert!(matches!(
+            pause.set_pause(&messages(true), MAXIMAL_PAUSE_DURATION + 1, START),
+            Err(GovernError::PauseTooLong)
+        ));
+
+        // extending the ongoing pause can't exceed the limit counted from its start
+        pause.set_pause(&messages(true), DAY, START).unwrap();
+        assert!(matches!(
+            pause.set_pause(&messages(true), MAXIMAL_PAUSE_DURATION, START + DAY / 2),
+            Err(GovernError::PauseTooLong)
+        ));
+        pause
+            .set_pause(
+                &messages(true),
+                MAXIMAL_PAUSE_DURATION - DAY / 2,
+                START + DAY / 2,
+            )
+            .unwrap();
+        assert_eq!(pause.pause().unwrap().start, START);
+    }
+
+    #[ink::test]
+    fn set_pause_fails_during_cooldown() {
+        let mut pause = PauseData::default();
+        pause.set_pause(&messages(true), DAY, START).unwrap();
+
+        assert!(matches!(
+            pause.set_pause(&messages(true), DAY, START + DAY + PAUSE_COOLDOWN - 1),
+            Err(GovernError::PauseCooldown)
+        ));
+        let set = pause
+            .set_pause(&messages(true), DAY, START + DAY + PAUSE_COOLDOWN)
+            .unwrap();
+        assert_eq!(set.start, START + DAY + PAUSE_COOLDOWN);
+    }
+
+    #[ink::test]
+    fn unpause_ends_the_ongoing_pause() {
+        let mut pause = PauseData::default();
+        assert!(matches!(pause.unpause(START), Err(GovernError::NotPaused)));
+
+        pause.set_pause(&messages(true), DAY, START).unwrap();
+        pause.unpause(START + DAY / 4).unwrap();
+        assert_eq!(pause.is_paused(START + DAY / 4), None);
+        assert_eq!(pause.pause().unwrap().end, START + DAY / 4);
+        assert!(matches!(
+            pause.unpause(START + DAY / 2),
+            Err(GovernError::NotPaused)
+        ));
+    }
+
+    #[ink::test]
+    fn voting_paused_duration_counts_only_pauses_of_voting() {
+        let mut pause = PauseData::default();
+        pause.set_pause(&messages(false), DAY, START).unwrap();
+        assert_eq!(pause.voting_paused_duration(START + DAY), Ok(0));
+
+        let start = START + DAY + PAUSE_COOLDOWN;
+        pause.set_pause(&messages(true), DAY, start).unwrap();
+        assert_eq!(pause.voting_paused_duration(start + DAY / 4), Ok(DAY / 4));
+        assert_eq!(pause.voting_paused_duration(start + 2 * DAY), Ok(DAY));
+    }
+
+    #[ink::test]
+    fn voting_paused_duration_accumulates_across_pauses() {
+        let mut pause = PauseData::default();
+        pause.set_pause(&messages(true), DAY, START).unwrap();
+        pause.unpause(START + DAY / 2).unwrap();
+        assert_eq!(pause.voting_paused_duration(START + DAY), Ok(DAY / 2));
+
+        let start = START + DAY / 2 + PAUSE_COOLDOWN;
+        pause.set_pause(&messages(true), DAY, start).unwrap();
+        assert_eq!(
+            pause.voting_paused_duration(start + DAY / 4),
+            Ok(DAY / 2 + DAY / 4)
+        );
+        assert_eq!(
+            pause.voting_paused_duration(start + 3 * DAY),
+            Ok(DAY / 2 + DAY)
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use ink::{prelude::vec::Vec, storage::Mapping};
+use pendzl::{
+    math::errors::MathError,
+    traits::{AccountId, Balance, Timestamp},
+};
+
+use crate::modules::govern::traits::{Draft, GovernError, Proposal, ProposalHash, Sponsorship};
+
+/// maximal number of sponsors of a single proposal.
+pub const MAXIMAL_SPONSORS: usize = 32;
+/// time after creation after which a draft can no longer be sponsored and can be cancelled by anyone.
+pub const DRAFT_EXPIRATION: Timestamp = 30 * 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct SponsorshipData {
+    drafts: Mapping<ProposalHash, Draft>,
+    draft_proposals: Mapping<ProposalHash, Proposal>,
+    /// sponsorships of drafts and of proposals activated from drafts that weren't finalized yet.
+    sponsorships: Mapping<ProposalHash, Vec<Sponsorship>>,
+    /// sum of votes pledged by each sponsor in all its sponsorships.
+    pledged_by: Mapping<AccountId, Balance>,
+    /// sum of shares deposited by each sponsor in all its sponsorships.
+    deposited_by: Mapping<AccountId, Balance>,
+}
+
+impl SponsorshipData {
+    pub fn draft(&self, proposal_hash: &ProposalHash) -> Option<Draft> {
+        self.drafts.get(proposal_hash)
+    }
+
+    pub fn sponsorships(&self, proposal_hash: &ProposalHash) -> Vec<Sponsorship> {
+        self.sponsorships.get(proposal_hash).unwrap_or_default()
+    }
+
+    pub fn pledged_by(&self, sponsor: &AccountId) -> Balance {
+        self.pledged_by.get(sponsor).unwrap_or_default()
+    }
+
+    pub fn deposited_by(&self, sponsor: &AccountId) -> Balance {
+        self.deposited_by.get(sponsor).unwrap_or_default()
+    }
+
+    pub fn create_draft(
+        &mut self,
+        proposer: &AccountId,
+        proposal_hash: &ProposalHash,
+        proposal: &Proposal,
+        now: Timestamp,
+    ) -> Result<(), GovernError> {
+        if self.drafts.contains(proposal_hash) {
+            return Err(GovernError::DraftAlreadyExists);
+        }
+        self.drafts.insert(
+            proposal_hash,
+            &Draft {
+                proposer: *proposer,
+                created: now,
+                pledged: 0,
+                deposit: 0,
+            },
+        );
+        self.draft_proposals.insert(proposal_hash, proposal);
+        Ok(())
+    }
+
+    /// Adds `pledged` votes and `deposit` shares of `sponsor` to the draft. Returns the updated draft.
+    pub fn sponsor(
+        &mut self,
+        proposal_hash: &ProposalHash,
+        sponsor: &AccountId,
+        pledged: Balance,
+        deposit: Balance,
+        now: Timestamp,
+    ) -> Result<Draft, GovernError> {
+        let mut draft = self
+            .draft(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        if is_expired(&draft, now)? {
+            return Err(GovernError::DraftExpired);
+        }
+        let mut sponsorships = self.sponsorships(proposal_hash);
+
+        match sponsorships.iter_mut().find(|s| s.sponsor == *sponsor) {
+            Some(sponsorship) => {
+                sponsorship.pledged = sponsorship
+                    .pledged
+                    .checked_add(pledged)
+                    .ok_or(MathError::Overflow)?;
+                sponsorship.deposit = sponsorship
+                    .deposit
+                    .checked_add(deposit)
+                    .ok_or(MathError::Overflow)?;
+            }
+            None => {
+                if sponsorships.len() >= MAXIMAL_SPONSORS {
+                    return Err(GovernError::TooManySponsors);
+                }
+                sponsorships.push(Sponsorship {
+                    sponsor: *sponsor,
+                    pledged,
+                    deposit,
+                });
+            }
+        }
+
+        draft.pledged = draft
+            .pledged
+            .checked_add(pledged)
+            .ok_or(MathError::Overflow)?;
+        draft.deposit = draft
+            .deposit
+            .checked_add(deposit)
+            .ok_or(MathError::Overflow)?;
+
+        self.drafts.insert(proposal_hash, &draft);
+        self.sponsorships.insert(proposal_hash, &sponsorships);
+        self.pledged_by.insert(
+            sponsor,
+            &self
+                .pledged_by(sponsor)
+                .checked_add(pledged)
+                .ok_or(MathError::Overflow)?,
+        );
+        self.deposited_by.insert(
+            sponsor,
+            &self
+                .deposited_by(sponsor)
+                .checked_add(deposit)
+                .ok_or(MathError::Overflow)?,
+        );
+        Ok(draft)
+    }
+
+    /// Removes sponsorship of `sponsor` from the draft and returns it.
+    pub fn unsponsor(
+        &mut self,
+        proposal_hash: &ProposalHash,
+        sponsor: &AccountId,
+    ) -> Result<Sponsorship, GovernError> {
+        let mut draft = self
+            .draft(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        let mut sponsorships = self.sponsorships(proposal_hash);
+        let index = sponsorships
+            .iter()
+            .position(|s| s.sponsor == *sponsor)
+            .ok_or(GovernError::NotSponsor)?;
+        let sponsorship = sponsorships.swap_remove(index);
+
+        draft.pledged = draft
+            .pledged
+            .checked_sub(sponsorship.pledged)
+            .ok_or(MathError::Underflow)?;
+        draft.deposit = draft
+            .deposit
+            .checked_sub(sponsorship.deposit)
+            .ok_or(MathError::Underflow)?;
+
+        self.drafts.insert(proposal_hash, &draft);
+        if sponsorships.is_empty() {
+            self.sponsorships.remove(proposal_hash);
+        } else {
+            self.sponsorships.insert(proposal_hash, &sponsorships);
+        }
+        self.release(&sponsorship)?;
+        Ok(sponsorship)
+    }
+
+    /// Removes the draft with all its sponsorships and returns them.
+    /// Only the proposer can cancel the draft before it expires.
+    pub fn cancel_draft(
+        &mut self,
+        proposal_hash: &ProposalHash,
+        caller: &AccountId,
+        now: Timestamp,
+    ) -> Result<(Draft, Vec<Sponsorship>), GovernError> {
+        let draft = self
+            .draft(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        if draft.proposer != *caller && !is_expired(&draft, now)? {
+            return Err(GovernError::DraftNotExpired);
+        }
+        self.take_draft(proposal_hash)?;
+        let sponsorships = self.take_sponsorships(proposal_hash)?;
+        Ok((draft, sponsorships))
+    }
+
+    /// Removes the draft so it can be registered as a proposal. Sponsorships are kept until the proposal is finalized.
+    pub fn take_draft(
+        &mut self,
+        proposal_hash: &ProposalHash,
+    ) -> Result<(Draft, Proposal), GovernError> {
+        let draft = self
+            .drafts
+            .take(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        let proposal = self
+            .draft_proposals
+            .take(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        Ok((draft, proposal))
+    }
+
+    /// Removes sponsorships of `proposal_hash` and returns them. Pledged votes of sponsors are released.
+    pub fn take_sponsorships(
+        &mut self,
+        proposal_hash: &ProposalHash,
+    ) -> Result<Vec<Sponsorship>, GovernError> {
+        let sponsorships = self.sponsorships.take(proposal_hash).unwrap_or_default();
+        for sponsorship in sponsorships.iter() {
+            self.release(sponsorship)?;
+        }
+        Ok(sponsorships)
+    }
+
+    fn release(&mut self, sponsorship: &Sponsorship) -> Result<(), MathError> {
+        let pledged = self
+            .pledged_by(&sponsorship.sponsor)
+            .checked_sub(sponsorship.pledged)
+            .ok_or(MathError::Underflow)?;
+        let deposited = self
+            .deposited_by(&sponsorship.sponsor)
+            .checked_sub(sponsorship.deposit)
+            .ok_or(MathError::Underflow)?;
+        if pledged == 0 && deposited == 0 {
+            self.pledged_by.remove(sponsorship.sponsor);
+            self.deposited_by.remove(sponsorship.sponsor);
+        } else {
+            self.pledged_by.insert(sponsorship.sponsor, &pledged);
+            self.deposited_by.insert(sponsorship.sponsor, &deposited);
+        }
+        Ok(())
+    }
+}
+
+fn is_expired(draft: &Draft, now: Timestamp) -> Result<bool, MathError> {
+    Ok(now
+        >= draft
+            .created
+            .checked_add(DRAFT_EXPIRATION)
+            .ok_or(MathError::Overflow)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use pendzl::traits::String;
+
+    use super::*;
+
+    const NOW: Timestamp = 1_000;
+    const PROPOSER: [u8; 32] = [1; 32];
+    const ALICE: [u8; 32] = [2; 32];
+
+    fn proposal() -> Proposal {
+        Proposal {
+            transactions: Vec::new(),
+            description_hash: ProposalHash::from([0; 32]),
+            description_url: String::from("url"),
+            earliest_execution: None,
+        }
+    }
+
+    fn setup(drafts: u8) -> SponsorshipData {
+        let mut sponsorship = SponsorshipData::default();
+        for seed in 0..drafts {
+            sponsorship
+                .create_draft(
+                    &PROPOSER.into(),
+                    &ProposalHash::from([seed; 32]),
+                    &proposal(),
+                    NOW,
+                )
+                .unwrap();
+        }
+        sponsorship
+    }
+
+    #[ink::test]
+    fn pledges_are_summed_over_sponsorships() {
+        let mut sponsorship = setup(2);
+        let alice = AccountId::from(ALICE);
+        sponsorship
+            .sponsor(&ProposalHash::from([0; 32]), &alice, 100, 10, NOW)
+            .unwrap();
+        sponsorship
+            .sponsor(&ProposalHash::from([1; 32]), &alice, 50, 5, NOW)
+            .unwrap();
+        assert_eq!(sponsorship.pledged_by(&alice), 150);
+        assert_eq!(sponsorship.deposited_by(&alice), 15);
+
+        sponsorship
+            .unsponsor(&ProposalHash::from([0; 32]), &alice)
+            .unwrap();
+        assert_eq!(sponsorship.pledged_by(&alice), 50);
+        assert_eq!(sponsorship.deposited_by(&alice), 5);
+
+        // pledges of activated drafts are released once the proposal is finalized
+        sponsorship
+            .take_draft(&ProposalHash::from([1; 32]))
+            .unwrap();
+        assert_eq!(sponsorship.pledged_by(&alice), 50);
+        let sponsorships = sponsorship
+            .take_sponsorships(&ProposalHash::from([1; 32]))
+            .unwrap();
+        assert_eq!(sponsorships.len(), 1);
+        assert_eq!(sponsorship.pledged_by(&alice), 0);
+        assert_eq!(sponsorship.deposited_by(&alice), 0);
+    }
+
+    #[ink::test]
+    fn expired_draft_cant_be_sponsored() {
+        let mut sponsorship = setup(1);
+        let proposal_hash = ProposalHash::from([0; 32]);
+        assert!(matches!(
+            sponsorship.sponsor(
+                &proposal_hash,
+                &ALICE.into(),
+                100,
+                10,
+                NOW + DRAFT_EXPIRATION
+            ),
+            Err(GovernError::DraftExpired)
+        ));
+        sponsorship
+            .sponsor(
+                &proposal_hash,
+                &ALICE.into(),
+                100,
+                10,
+                NOW + DRAFT_EXPIRATION - 1,
+            )
+            .unwrap();
+    }
+
+    #[ink::test]
+    fn cancel_draft_by_proposer_or_after_expiration() {
+        let mut sponsorship = setup(2);
+        let alice = AccountId::from(ALICE);
+        let proposal_hash = ProposalHash::from([0; 32]);
+        sponsorship
+            .sponsor(&proposal_hash, &alice, 100, 10, NOW)
+            .unwrap();
+
+        assert!(matches!(
+            sponsorship.cancel_draft(&proposal_hash, &alice, NOW + DRAFT_EXPIRATION - 1),
+            Err(GovernError::DraftNotExpired)
+        ));
+        let (draft, sponsorships) = sponsorship
+            .cancel_draft(&proposal_hash, &PROPOSER.into(), NOW)
+            .unwrap();
+        assert_eq!(draft.pledged, 100);
+        assert_eq!(sponsorships.len(), 1);
+        assert_eq!(sponsorship.draft(&proposal_hash), None);
+        assert!(sponsorship.sponsorships(&proposal_hash).is_empty());
+        assert_eq!(sponsorship.pledged_by(&alice), 0);
+        assert!(matches!(
+            sponsorship.cancel_draft(&proposal_hash, &PROPOSER.into(), NOW),
+            Err(GovernError::DraftDoesntExist)
+        ));
+
+        sponsorship
+            .cancel_draft(&ProposalHash::from([1; 32]), &alice, NOW + DRAFT_EXPIRATION)
+            .unwrap();
+    }
+}
@@ -0,0 +1,38 @@
+use crate::modules::govern::traits::ProposalId;
+
+/// Version of the storage layout of this code. Contracts instantiated before the version marker was introduced have version 0.
+pub const STORAGE_VERSION: u16 = 1;
+
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct VersionData {
+    #[lazy]
+    version: u16,
+    /// id of the next proposal to migrate in the ongoing migration step. None if the step has not started yet.
+    #[lazy]
+    migration_cursor: Option<ProposalId>,
+}
+
+impl VersionData {
+    pub fn new() -> Self {
+        let mut instance = Self::default();
+        instance.version.set(&STORAGE_VERSION);
+        instance
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version.get().unwrap_or_default()
+    }
+
+    pub fn set_version(&mut self, version: u16) {
+        self.version.set(&version);
+    }
+
+    pub fn migration_cursor(&self) -> Option<ProposalId> {
+        self.migration_cursor.get().flatten()
+    }
+
+    pub fn set_migration_cursor(&mut self, cursor: Option<ProposalId>) {
+        self.migration_cursor.set(&cursor);
+    }
+}
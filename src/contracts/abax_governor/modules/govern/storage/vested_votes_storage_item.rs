@@ -0,0 +1,60 @@
+use ink::storage::Mapping;
+use pendzl::{
+    math::errors::MathError,
+    traits::{AccountId, Balance},
+};
+
+use crate::modules::govern::traits::VestedVotes;
+
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct VestedVotesData {
+    #[lazy]
+    vested_votes: Option<VestedVotes>,
+    /// vested votes of accounts as counted at their last vote or stake change.
+    counted: Mapping<AccountId, Balance>,
+    /// sum of `counted`.
+    #[lazy]
+    total_counted: Balance,
+}
+
+impl VestedVotesData {
+    pub fn vested_votes(&self) -> Option<VestedVotes> {
+        self.vested_votes.get().unwrap_or_default()
+    }
+
+    pub fn set_vested_votes(&mut self, vested_votes: &Option<VestedVotes>) {
+        self.vested_votes.set(vested_votes);
+    }
+
+    pub fn counted_of(&self, account: &AccountId) -> Balance {
+        self.counted.get(account).unwrap_or_default()
+    }
+
+    pub fn total_counted(&self) -> Balance {
+        self.total_counted.get().unwrap_or_default()
+    }
+
+    /// Sets vested votes counted for `account` to `votes`.
+    /// Returns by how much the counted votes of `account` increased (0 if they decreased).
+    pub fn set_counted(
+        &mut self,
+        account: &AccountId,
+        votes: &Balance,
+    ) -> Result<Balance, MathError> {
+        let counted = self.counted_of(account);
+        let total_counted = self
+            .total_counted()
+            .checked_sub(counted)
+            .ok_or(MathError::Underflow)?
+            .checked_add(*votes)
+            .ok_or(MathError::Overflow)?;
+        self.total_counted.set(&total_counted);
+        if *votes == 0 {
+            self.counted.remove(account);
+        } else {
+            self.counted.insert(account, votes);
+        }
+        Ok(votes.saturating_sub(counted))
+    }
+}
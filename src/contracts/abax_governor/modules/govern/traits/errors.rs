@@ -17,8 +17,34 @@ pub enum GovernError {
     FinalizeCondition,
     UnderlyingTransactionReverted(String),
     CantForceUnstake,
+    ForceUnstakeGracePeriod,
     AccessControlError(AccessControlError),
     UnstakeShorterThanVotingPeriod,
+    InvalidQuorum,
+    TooManyActiveProposals,
+    TooManyActiveProposalsOfProposer,
+    ProposalTooSoon,
+    DraftAlreadyExists,
+    DraftDoesntExist,
+    TooManySponsors,
+    NotSponsor,
+    CommitRevealDisabled,
+    WrongVotingMode,
+    NotCommitPeriod,
+    NotRevealPeriod,
+    NoCommit,
+    CommitMismatch,
+    Paused,
+    NotPaused,
+    PauseTooLong,
+    PauseCooldown,
+    AlreadyMigrated,
+    InvalidDiscount,
+    InvalidMaximumActiveProposals,
+    InvalidProposerDeposit,
+    DraftExpired,
+    DraftNotExpired,
+    InvalidMaximumActiveProposalsPerProposer,
 }
 
 impl From<MathError> for GovernError {
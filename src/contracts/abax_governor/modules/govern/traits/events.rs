@@ -1,6 +1,9 @@
-use pendzl::traits::{AccountId, Timestamp};
+use pendzl::traits::{AccountId, Balance, Timestamp};
 
-use super::{Proposal, ProposalHash, ProposalId, ProposalStatus, Vote, VotingRules};
+use super::{
+    CommitRevealRules, PausedMessages, Proposal, ProposalHash, ProposalId, ProposalStatus,
+    ProposerDeposit, VestedVotes, Vote, VotingRules,
+};
 
 #[ink::event]
 pub struct ProposalCreated {
@@ -12,6 +15,43 @@ pub struct ProposalCreated {
     pub proposal: Proposal,
 }
 
+#[ink::event]
+pub struct DraftCreated {
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub proposer: AccountId,
+    pub proposal: Proposal,
+}
+
+#[ink::event]
+pub struct Sponsored {
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub sponsor: AccountId,
+    pub pledged: Balance,
+    pub deposit: Balance,
+}
+
+#[ink::event]
+pub struct Unsponsored {
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub sponsor: AccountId,
+    pub pledged: Balance,
+    pub deposit: Balance,
+}
+
+#[ink::event]
+pub struct DraftCancelled {
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub caller: AccountId,
+}
+
 #[ink::event]
 pub struct ProposalFinalized {
     #[ink(topic)]
@@ -35,12 +75,50 @@ pub struct VoteCasted {
     pub vote: Vote,
 }
 
+#[ink::event]
+pub struct VoteCommitted {
+    #[ink(topic)]
+    pub account: AccountId,
+    #[ink(topic)]
+    pub proposal_id: ProposalId,
+}
+
 #[ink::event]
 pub struct VotingRulesChanged {
     pub rules: VotingRules,
 }
 
+#[ink::event]
+pub struct CommitRevealRulesChanged {
+    pub rules: CommitRevealRules,
+}
+
+#[ink::event]
+pub struct ProposerDepositChanged {
+    pub deposit: ProposerDeposit,
+}
+
 #[ink::event]
 pub struct UnstakePeriodChanged {
     pub unstake_period: Timestamp,
 }
+
+#[ink::event]
+pub struct Paused {
+    pub messages: PausedMessages,
+    pub until: Timestamp,
+}
+
+#[ink::event]
+pub struct Unpaused {}
+
+#[ink::event]
+pub struct Migrated {
+    pub from: u16,
+    pub to: u16,
+}
+
+#[ink::event]
+pub struct VestedVotesChanged {
+    pub vested_votes: Option<VestedVotes>,
+}
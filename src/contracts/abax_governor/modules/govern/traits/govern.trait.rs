@@ -12,10 +12,100 @@ pub trait AbaxGovern {
     ///
     /// # Errors
     /// Returns `ProposalAlreadyExists` if `propsal` with the same `proposal_description` exists,
-    /// Returns `InsuficientVotes` if `caller` has insufficient amount of votes to create a proposal.
+    /// Returns `InsuficientVotes` if `caller` has insufficient amount of votes to create a proposal. Non-stakers can propose only through sponsored drafts.
+    /// Returns `TooManyActiveProposals` if the maximal number of active proposals is reached.
+    /// Returns `TooManyActiveProposalsOfProposer` if `caller` has the maximal number of active proposals.
+    /// Returns `ProposalTooSoon` if the minimal interval since the last proposal of `caller` hasn't passed.
+    /// Returns `PSP22Error` if the deposit is taken in assets and `caller` hasn't approved enough of them.
     #[ink(message)]
     fn propose(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError>;
 
+    /// Propose a `proposal` with commit-reveal voting.
+    /// Votes are committed with `commit_vote` until the end of the final period and counted only when revealed with `reveal_vote` during the reveal period.
+    ///
+    /// On success emits `ProposalCreated` event.
+    ///
+    /// # Returns
+    ///
+    /// Returns `ProposalId` of the created proposal.
+    ///
+    /// # Errors
+    /// Returns `ProposalAlreadyExists` if `propsal` with the same `proposal_description` exists,
+    /// Returns `InsuficientVotes` if `caller` has insufficient amount of votes to create a proposal.
+    /// Returns `CommitRevealDisabled` if the reveal period is 0.
+    #[ink(message)]
+    fn propose_commit_reveal(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError>;
+
+    /// Creates a draft of `proposal` that becomes an active proposal once sponsors pledge enough votes.
+    /// `caller` becomes the proposer.
+    /// If the proposer deposit is taken in assets, it is pulled from `caller` now and `caller` doesn't need to be a staker.
+    ///
+    /// On success emits `DraftCreated` event.
+    ///
+    /// # Errors
+    /// Returns `InsuficientVotes` if `caller` has no votes and the proposer deposit is taken in shares.
+    /// Returns `PSP22Error` if the deposit is taken in assets and `caller` hasn't approved enough of them.
+    /// Returns `ProposalAlreadyExists` if `proposal` already exists.
+    /// Returns `DraftAlreadyExists` if draft of `proposal` already exists.
+    #[ink(message)]
+    fn create_draft(&mut self, proposal: Proposal) -> Result<ProposalHash, GovernError>;
+
+    /// Pledges `amount` of `caller` votes to the draft with `proposal_hash` and deposits the proposer deposit part of `amount` in shares.
+    /// Once the pledged votes reach the minimum stake part, the draft becomes an active proposal.
+    /// Pledged votes stay locked - `caller` can't withdraw shares below them until the sponsorship ends.
+    ///
+    /// On success emits `Sponsored` event and, if draft is activated, `ProposalCreated` event.
+    ///
+    /// # Errors
+    /// Returns `DraftDoesntExist` if draft doesn't exist.
+    /// Returns `InsuficientVotes` if `amount` is 0 or together with votes already pledged by `caller` in other sponsorships exceeds `caller` votes.
+    /// Returns `DraftExpired` if `DRAFT_EXPIRATION` has passed since the draft was created.
+    /// Returns `TooManySponsors` if the draft has the maximal number of sponsors.
+    #[ink(message)]
+    fn sponsor(&mut self, proposal_hash: ProposalHash, amount: Balance) -> Result<(), GovernError>;
+
+    /// Withdraws `caller` sponsorship of the draft with `proposal_hash` and returns the deposit.
+    ///
+    /// On success emits `Unsponsored` event.
+    ///
+    /// # Errors
+    /// Returns `DraftDoesntExist` if draft doesn't exist (or was already activated).
+    /// Returns `NotSponsor` if `caller` doesn't sponsor the draft.
+    #[ink(message)]
+    fn unsponsor(&mut self, proposal_hash: ProposalHash) -> Result<(), GovernError>;
+
+    /// Cancels the draft with `proposal_hash`, returning deposits of all sponsors and the proposer deposit in assets.
+    /// The proposer can cancel the draft at any time, anyone else only after it expired.
+    ///
+    /// On success emits `DraftCancelled` event.
+    ///
+    /// # Errors
+    /// Returns `DraftDoesntExist` if draft doesn't exist (or was already activated).
+    /// Returns `DraftNotExpired` if `caller` is not the proposer and the draft hasn't expired yet.
+    #[ink(message)]
+    fn cancel_draft(&mut self, proposal_hash: ProposalHash) -> Result<(), GovernError>;
+
+    /// Deposits `assets` in the name of `caller` for `receiver` like `PSP22Vault::deposit`,
+    /// using `caller` signed permit over the asset instead of a prior approve transaction.
+    /// The permit must allow the governor to spend `assets` until `deadline`.
+    ///
+    /// On success emits `Approval` event on the asset and `Deposit` event.
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount of minted shares.
+    ///
+    /// # Errors
+    /// Returns `PSP22Error` if the permit is expired or invalid or the deposit fails.
+    #[ink(message)]
+    fn deposit_with_permit(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        deadline: Timestamp,
+        signature: [u8; 65],
+    ) -> Result<Balance, GovernError>;
+
     /// Finilize `proposal_id` if the finalization conditions are met.  
     ///
     /// On success emits `ProposalFinalized` event.
@@ -23,7 +113,7 @@ pub trait AbaxGovern {
     /// # Errors
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
     /// Returns `WrongStatus` if proposal is not `Active``.
-    /// Returns `FinalizeCondition` if finalize condition isn't met.
+    /// Returns `FinalizeCondition` if finalize condition isn't met or the quorum wasn't reached and the final period hasn't ended yet.
     #[ink(message)]
     fn finalize(&mut self, proposal_id: ProposalId) -> Result<(), GovernError>;
 
@@ -46,6 +136,7 @@ pub trait AbaxGovern {
     /// Returns `InsuficientVotes` if `caller` has no votes.
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
     /// Returns `WrongStatus` if proposal status isn't `Active`.
+    /// Returns `WrongVotingMode` if proposal uses commit-reveal voting.
     #[ink(message)]
     fn vote(
         &mut self,
@@ -54,9 +145,43 @@ pub trait AbaxGovern {
         reason: Vec<u8>,
     ) -> Result<(), GovernError>;
 
+    /// Commits `vote_hash` in the name of `caller` on commit-reveal `proposal_id`. Overrides the previous commit.
+    /// `vote_hash` should be computed with `hash_vote`.
+    ///
+    /// On Success emits `VoteCommitted` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal status isn't `Active`.
+    /// Returns `WrongVotingMode` if proposal doesn't use commit-reveal voting.
+    /// Returns `NotCommitPeriod` if the final period has ended.
+    #[ink(message)]
+    fn commit_vote(&mut self, proposal_id: ProposalId, vote_hash: Hash) -> Result<(), GovernError>;
+
+    /// Reveals the vote committed by `caller` on commit-reveal `proposal_id` and casts it.
+    ///
+    /// On Success emits `VoteCasted` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal status isn't `Active`.
+    /// Returns `WrongVotingMode` if proposal doesn't use commit-reveal voting.
+    /// Returns `NotRevealPeriod` if it isn't the reveal period.
+    /// Returns `NoCommit` if `caller` has no commit on the proposal.
+    /// Returns `CommitMismatch` if `vote` and `salt` don't match the commit.
+    /// Returns `InsuficientVotes` if `caller` has no votes.
+    #[ink(message)]
+    fn reveal_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        vote: Vote,
+        salt: Hash,
+    ) -> Result<(), GovernError>;
+
     /// Forcefully unstakes all tokens of `account` if:
     /// 1. proposal with `proposal_id` was finalized in Final phase
     /// 2. `account` has staked some tokens before the proposal was created.
+    /// 3. the grace period after the proposal finalization has passed.
     ///
     /// On success emits `ForcefullyUnstaked` event.
     ///
@@ -64,10 +189,16 @@ pub trait AbaxGovern {
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
     /// Returns `WrongStatus` if proposal wasn't finalized in final phase.
     /// Returns `CantForceUnstake` if proposal doesnt allow for force unstake or the 'account' was already force unstaked for not voting on proposal with id >= 'proposal_id'.
+    /// Returns `ForceUnstakeGracePeriod` if the grace period after the proposal finalization hasn't passed yet.
     #[ink(message)]
     fn force_unstake(
         &mut self,
         account: AccountId,
         proposal_id: ProposalId,
     ) -> Result<(), GovernError>;
+
+    /// Updates votes of `account` on all active proposals to its current votes.
+    /// Can be called by anyone, e.g. to remove votes of vesting assets that were released.
+    #[ink(message)]
+    fn sync_votes(&mut self, account: AccountId) -> Result<(), GovernError>;
 }
@@ -13,8 +13,36 @@ pub trait AbaxGovernInternal {
         &mut self,
         proposer: &AccountId,
         proposal: &Proposal,
+        commit_reveal: bool,
     ) -> Result<ProposalId, GovernError>;
 
+    fn _create_draft(
+        &mut self,
+        proposer: &AccountId,
+        proposal: &Proposal,
+    ) -> Result<ProposalHash, GovernError>;
+
+    /// Adds sponsorship of `sponsor` to the draft and activates it if enough votes are pledged.
+    fn _sponsor(
+        &mut self,
+        sponsor: &AccountId,
+        proposal_hash: &ProposalHash,
+        amount: Balance,
+    ) -> Result<(), GovernError>;
+
+    fn _unsponsor(
+        &mut self,
+        sponsor: &AccountId,
+        proposal_hash: &ProposalHash,
+    ) -> Result<(), GovernError>;
+
+    /// Removes the draft and returns deposits to its sponsors and proposer.
+    fn _cancel_draft(
+        &mut self,
+        caller: &AccountId,
+        proposal_hash: &ProposalHash,
+    ) -> Result<(), GovernError>;
+
     fn _cast_vote(
         &mut self,
         voter: &AccountId,
@@ -23,6 +51,21 @@ pub trait AbaxGovernInternal {
         #[allow(unused_variables)] reason: Vec<u8>,
     ) -> Result<(), GovernError>;
 
+    fn _commit_vote(
+        &mut self,
+        voter: &AccountId,
+        proposal_id: ProposalId,
+        vote_hash: Hash,
+    ) -> Result<(), GovernError>;
+
+    fn _reveal_vote(
+        &mut self,
+        voter: &AccountId,
+        proposal_id: ProposalId,
+        vote: Vote,
+        salt: Hash,
+    ) -> Result<(), GovernError>;
+
     /// Finalizes proposal identified by `proposal_id`
     ///
     /// On success emits `ProposalFinalized` event.
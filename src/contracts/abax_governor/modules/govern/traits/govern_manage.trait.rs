@@ -7,10 +7,35 @@ pub trait AbaxGovernManage {
     /// #Errors
     ///
     /// Returns `UnstakeShorterThanVotingPeriod` if the unstake period is shorter than the rules' total voting period.
+    /// Returns `InvalidQuorum` if the `minimum_quorum_e3` exceeds 1000.
+    /// Returns `InvalidMaximumActiveProposals` if the `maximum_active_proposals` is 0 or exceeds `MAXIMUM_ACTIVE_PROPOSALS_CAP`.
+    /// Returns `InvalidMaximumActiveProposalsPerProposer` if the `maximum_active_proposals_per_proposer` is 0 or exceeds `maximum_active_proposals`.
     /// Returns `AccessControlError` if the `caller` has not access to the method.
     #[ink(message)]
     fn change_voting_rules(&mut self, rules: VotingRules) -> Result<(), GovernError>;
 
+    /// change `CommitRevealRules` used for proposals with commit-reveal voting
+    ///
+    /// On Success emits `CommitRevealRulesChanged` event.
+    ///
+    /// #Errors
+    ///
+    /// Returns `UnstakeShorterThanVotingPeriod` if the unstake period is shorter than the total voting period including the reveal period.
+    /// Returns `AccessControlError` if the `caller` has not access to the method.
+    #[ink(message)]
+    fn change_commit_reveal_rules(&mut self, rules: CommitRevealRules) -> Result<(), GovernError>;
+
+    /// change the way `ProposerDeposit` is taken from proposers of new proposals
+    ///
+    /// On Success emits `ProposerDepositChanged` event.
+    ///
+    /// #Errors
+    ///
+    /// Returns `InvalidProposerDeposit` if the `deposit` is `FixedAssets(0)`.
+    /// Returns `AccessControlError` if the `caller` has not access to the method.
+    #[ink(message)]
+    fn change_proposer_deposit(&mut self, deposit: ProposerDeposit) -> Result<(), GovernError>;
+
     /// change unstake period
     ///
     /// On Success emits `UnstakePeriodChanged` event.
@@ -21,4 +46,53 @@ pub trait AbaxGovernManage {
     /// Returns `AccessControlError` if the `caller` has not access to the method.
     #[ink(message)]
     fn change_unstake_period(&mut self, period: Timestamp) -> Result<(), GovernError>;
+
+    /// pauses `messages` for `duration`. If there is an ongoing pause it is replaced, but its end can't exceed the maximal pause duration counted from its start.
+    /// While `vote` is paused, voting periods of active proposals are extended by the paused duration.
+    ///
+    /// On Success emits `Paused` event.
+    ///
+    /// #Errors
+    ///
+    /// Returns `PauseTooLong` if the pause would last longer than the maximal pause duration.
+    /// Returns `PauseCooldown` if the previous pause has ended recently.
+    /// Returns `AccessControlError` if the `caller` has not access to the method.
+    #[ink(message)]
+    fn pause(&mut self, messages: PausedMessages, duration: Timestamp) -> Result<(), GovernError>;
+
+    /// ends the ongoing pause
+    ///
+    /// On Success emits `Unpaused` event.
+    ///
+    /// #Errors
+    ///
+    /// Returns `NotPaused` if there is no ongoing pause.
+    /// Returns `AccessControlError` if the `caller` has not access to the method.
+    #[ink(message)]
+    fn unpause(&mut self) -> Result<(), GovernError>;
+
+    /// runs the migration step from the current storage version to the next one on at most `count` proposals.
+    /// Should be called after the code hash is updated to the code with a newer storage layout,
+    /// repeatedly until `storage_version` reaches the version of the code. Each step is run exactly once.
+    /// Until then storage in the old layout can't be read, so all messages should be paused.
+    ///
+    /// On Success emits `Migrated` event if the step is finished.
+    ///
+    /// #Errors
+    ///
+    /// Returns `AlreadyMigrated` if the storage is already at the version of the code.
+    /// Returns `AccessControlError` if the `caller` has not access to the method.
+    #[ink(message)]
+    fn migrate(&mut self, count: u32) -> Result<(), GovernError>;
+
+    /// sets the source of votes of assets that are still vesting. `None` disables counting them.
+    ///
+    /// On Success emits `VestedVotesChanged` event.
+    ///
+    /// #Errors
+    ///
+    /// Returns `InvalidDiscount` if `discount_e3` is greater than 1000.
+    /// Returns `AccessControlError` if the `caller` has not access to the method.
+    #[ink(message)]
+    fn set_vested_votes(&mut self, vested_votes: Option<VestedVotes>) -> Result<(), GovernError>;
 }
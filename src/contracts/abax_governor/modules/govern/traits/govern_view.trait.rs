@@ -20,6 +20,34 @@ pub trait AbaxGovernView {
     #[ink(message)]
     fn rules(&self) -> VotingRules;
 
+    /// Returns 'CommitRevealRules' used for proposals with commit-reveal voting.
+    #[ink(message)]
+    fn commit_reveal_rules(&self) -> CommitRevealRules;
+
+    /// Returns the way the proposer deposit is taken.
+    #[ink(message)]
+    fn proposer_deposit(&self) -> ProposerDeposit;
+
+    /// Returns amount of assets deposited by the proposer of `proposal_id` that are still locked.
+    #[ink(message)]
+    fn locked_assets(&self, proposal_id: ProposalId) -> Balance;
+
+    /// Returns amount of assets deposited by the proposer of the draft with `proposal_hash`.
+    #[ink(message)]
+    fn locked_draft_assets(&self, proposal_hash: ProposalHash) -> Balance;
+
+    /// Returns draft with `proposal_hash` if it exists.
+    #[ink(message)]
+    fn draft(&self, proposal_hash: ProposalHash) -> Option<Draft>;
+
+    /// Returns sponsorships of draft or not yet finalized proposal with `proposal_hash`.
+    #[ink(message)]
+    fn sponsorships(&self, proposal_hash: ProposalHash) -> Vec<Sponsorship>;
+
+    /// Returns sum of votes pledged by `sponsor` in sponsorships of drafts and not yet finalized proposals.
+    #[ink(message)]
+    fn pledged_by(&self, sponsor: AccountId) -> Balance;
+
     /// Returns ProposalStatus of proposal with proposal_id (proposal Hash) if it exists.
     #[ink(message)]
     fn status(&self, proposal_id: ProposalId) -> Option<ProposalStatus>;
@@ -28,7 +56,12 @@ pub trait AbaxGovernView {
     #[ink(message)]
     fn minimum_to_finalize(&self, proposal_id: ProposalId) -> Option<Balance>;
 
-    /// Returns ProposalStatus of proposal with proposal_id (proposal Hash) if it exists.
+    /// Returns amount of votes that has to be casted in total to finalize proposal other than `Defeated`.
+    #[ink(message)]
+    fn minimum_quorum(&self, proposal_id: ProposalId) -> Option<Balance>;
+
+    /// Returns ProposalState of proposal with proposal_id if it exists.
+    /// Besides the tallies it contains the number of voters, the phase and the threshold at finalization and the execution time.
     #[ink(message)]
     fn state(&self, proposal_id: ProposalId) -> Option<ProposalState>;
 
@@ -36,6 +69,20 @@ pub trait AbaxGovernView {
     #[ink(message)]
     fn vote_of_for(&self, account: AccountId, proposal_id: ProposalId) -> Option<UserVote>;
 
+    /// Returns `account` not yet revealed vote hash for proposal `proposal_id` if it exists.
+    #[ink(message)]
+    fn commit_of_for(&self, account: AccountId, proposal_id: ProposalId) -> Option<Hash>;
+
+    /// Returns hash to be committed by `account` to vote for `vote` on `proposal_id`.
+    #[ink(message)]
+    fn hash_vote(
+        &self,
+        account: AccountId,
+        proposal_id: ProposalId,
+        vote: Vote,
+        salt: Hash,
+    ) -> Hash;
+
     /// Returns `account` last proposalId that was used for force unstake.
     #[ink(message)]
     fn last_force_unstakes(&self, account: AccountId) -> Option<ProposalId>;
@@ -48,6 +95,18 @@ pub trait AbaxGovernView {
     #[ink(message)]
     fn active_proposals(&self) -> u32;
 
+    /// Returns ids of active proposals.
+    #[ink(message)]
+    fn active_proposal_ids(&self) -> Vec<ProposalId>;
+
+    /// Returns the number of active proposals created by `account`.
+    #[ink(message)]
+    fn active_proposals_of(&self, account: AccountId) -> u32;
+
+    /// Returns the time when `account` created its last proposal.
+    #[ink(message)]
+    fn last_proposal_timestamp_of(&self, account: AccountId) -> Option<Timestamp>;
+
     /// Returns the number of finalized proposals.
     #[ink(message)]
     fn finalized_proposals(&self) -> u32;
@@ -67,4 +126,20 @@ pub trait AbaxGovernView {
     /// Returns the `description_hash` of the proposal with `proposal_id`.
     #[ink(message)]
     fn description_hash_by_proposal_id(&self, proposal_id: ProposalId) -> Option<Hash>;
+
+    /// Returns the last pause. It is ongoing if its `end` is in the future.
+    #[ink(message)]
+    fn pause_info(&self) -> Option<Pause>;
+
+    /// Returns the version of the storage layout.
+    #[ink(message)]
+    fn storage_version(&self) -> u16;
+
+    /// Returns the source of votes of assets that are still vesting.
+    #[ink(message)]
+    fn vested_votes(&self) -> Option<VestedVotes>;
+
+    /// Returns votes of `account` coming from its assets that are still vesting.
+    #[ink(message)]
+    fn vested_votes_of(&self, account: AccountId) -> Balance;
 }
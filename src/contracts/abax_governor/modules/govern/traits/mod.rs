@@ -6,7 +6,7 @@ pub use errors::*;
 pub use events::*;
 pub use structs::*;
 
-pub use pendzl::traits::String;
+pub use pendzl::{contracts::psp22::PSP22Error, traits::String};
 
 pub use ink::{prelude::vec::Vec, primitives::Hash};
 
@@ -14,3 +14,4 @@ include!("govern.trait.rs");
 include!("govern_manage.trait.rs");
 include!("govern_view.trait.rs");
 include!("govern_internal.trait.rs");
+include!("psp22_permit.trait.rs");
@@ -0,0 +1,16 @@
+pub type PSP22PermitRef = ink::contract_ref!(PSP22Permit, ink::env::DefaultEnvironment);
+
+/// Signed approvals of the asset. Selectors match the `PSP22Permit` of the ABAX token.
+/// Only `permit` is used by the governor, so the views are not declared here.
+#[ink::trait_definition]
+pub trait PSP22Permit {
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: [u8; 65],
+    ) -> Result<(), PSP22Error>;
+}
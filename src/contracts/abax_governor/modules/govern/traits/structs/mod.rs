@@ -4,9 +4,17 @@ pub use pendzl::traits::{AccountId, Balance, Timestamp};
 pub type ProposalId = u32;
 
 include!("voting_rules.rs");
+include!("voting_rules_v0.rs");
+include!("commit_reveal_rules.rs");
+include!("proposer_deposit.rs");
+include!("sponsorship.rs");
 include!("proposal_state.rs");
+include!("proposal_state_v0.rs");
 include!("proposal_status.rs");
 include!("proposal.rs");
 include!("transaction.rs");
 include!("user_vote.rs");
 include!("vote.rs");
+include!("voting_phase.rs");
+include!("pause.rs");
+include!("vested_votes.rs");
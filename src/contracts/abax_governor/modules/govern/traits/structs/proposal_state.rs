@@ -10,12 +10,16 @@ pub struct ProposalState {
     pub force_unstake_possible: bool,
     /// the proposer
     pub proposer: AccountId,
+    /// if true, votes are committed as hashes and counted only when revealed after the final period
+    pub commit_reveal: bool,
     /// time of proposition
     pub start: Timestamp,
     /// Stake::total_stake at start
     pub votes_at_start: Balance,
     /// Stake::counter_stake at start
     pub counter_at_start: Balance,
+    /// total duration during which voting was paused, at start
+    pub voting_paused_at_start: Timestamp,
     /// time of proposal finalization. Some if proposal finalized. None if porposal is not finalized yet.
     pub finalized: Option<Timestamp>,
     /// amount of votes to accept the proposal
@@ -24,6 +28,16 @@ pub struct ProposalState {
     pub votes_against: Balance,
     /// amount of votes to reject proposal and slash the proposer
     pub votes_against_with_slash: Balance,
+    /// amount of votes that participated without taking a side
+    pub votes_abstain: Balance,
     /// earliest time when proposal can be executed
     pub earliest_execution: Option<Timestamp>,
+    /// number of accounts that voted on the proposal
+    pub voters_count: u32,
+    /// phase of voting in which the proposal was finalized. Some if proposal finalized.
+    pub finalization_phase: Option<VotingPhase>,
+    /// minimum votes to finalize at the moment of finalization. Some if proposal finalized.
+    pub minimum_to_finalize_at_finalization: Option<Balance>,
+    /// time of proposal execution. Some if proposal executed.
+    pub executed: Option<Timestamp>,
 }
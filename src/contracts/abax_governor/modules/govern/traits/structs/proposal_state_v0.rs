@@ -0,0 +1,57 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// Layout of `ProposalState` in storage version 0.
+pub struct ProposalStateV0 {
+    /// proposal status
+    pub status: ProposalStatus,
+    /// if proposal was finalized in final phase, then it's possible to force unstake
+    pub force_unstake_possible: bool,
+    /// the proposer
+    pub proposer: AccountId,
+    /// time of proposition
+    pub start: Timestamp,
+    /// Stake::total_stake at start
+    pub votes_at_start: Balance,
+    /// Stake::counter_stake at start
+    pub counter_at_start: Balance,
+    /// time of proposal finalization. Some if proposal finalized. None if porposal is not finalized yet.
+    pub finalized: Option<Timestamp>,
+    /// amount of votes to accept the proposal
+    pub votes_for: Balance,
+    /// amount of votes to reject proposal
+    pub votes_against: Balance,
+    /// amount of votes to reject proposal and slash the proposer
+    pub votes_against_with_slash: Balance,
+    /// earliest time when proposal can be executed
+    pub earliest_execution: Option<Timestamp>,
+}
+
+/// Fields that were not tracked in storage version 0 are left empty - the number of voters, the phase and
+/// the minimum to finalize at finalization and the time of execution are unknown for such proposals.
+impl From<ProposalStateV0> for ProposalState {
+    fn from(state: ProposalStateV0) -> Self {
+        ProposalState {
+            status: state.status,
+            force_unstake_possible: state.force_unstake_possible,
+            proposer: state.proposer,
+            commit_reveal: false,
+            start: state.start,
+            votes_at_start: state.votes_at_start,
+            counter_at_start: state.counter_at_start,
+            voting_paused_at_start: 0,
+            finalized: state.finalized,
+            votes_for: state.votes_for,
+            votes_against: state.votes_against,
+            votes_against_with_slash: state.votes_against_with_slash,
+            votes_abstain: 0,
+            earliest_execution: state.earliest_execution,
+            voters_count: 0,
+            finalization_phase: None,
+            minimum_to_finalize_at_finalization: None,
+            executed: None,
+        }
+    }
+}
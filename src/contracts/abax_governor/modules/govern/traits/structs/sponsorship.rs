@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// Proposal that awaits sponsors before it becomes active.
+pub struct Draft {
+    /// the creator of the draft. Becomes the proposer once the draft is activated.
+    pub proposer: AccountId,
+    /// creation time. The draft expires `DRAFT_EXPIRATION` after it.
+    pub created: Timestamp,
+    /// sum of votes pledged by sponsors
+    pub pledged: Balance,
+    /// sum of shares deposited by sponsors
+    pub deposit: Balance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Sponsorship {
+    pub sponsor: AccountId,
+    /// votes pledged by the sponsor
+    pub pledged: Balance,
+    /// shares deposited by the sponsor. Returned when proposal is finalized unless it is 'DefeatedWithSlash'.
+    pub deposit: Balance,
+}
@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// Source of votes of assets that are still vesting (e.g. ABAX bought in the TGE).
+pub struct VestedVotes {
+    /// GeneralVest contract holding the vesting assets, e.g. the vester of the TGE.
+    pub vester: AccountId,
+    /// part of the unreleased assets (converted to shares) that is counted as votes.
+    pub discount_e3: u16,
+}
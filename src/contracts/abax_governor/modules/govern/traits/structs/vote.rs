@@ -8,4 +8,6 @@ pub enum Vote {
     Disagreed,
     /// Disagree and slash the proposal. Should be chosen if the proposition is made to hurt the DAO.
     DisagreedWithProposerSlashing,
+    /// Participate without taking a side. Counts toward the quorum, but not toward the amount required to finalize nor for or against.
+    Abstain,
 }
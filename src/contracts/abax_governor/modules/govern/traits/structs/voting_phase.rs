@@ -0,0 +1,13 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// Periods of voting on a proposal
+pub enum VotingPhase {
+    /// required amount to finalize falls from 100% to 50% of total votes
+    Initial,
+    /// required amount to finalize is flat at 50% of total votes
+    Flat,
+    /// required amount to finalize falls from 50% to 0% of total votes
+    Final,
+    /// after the final period
+    Ended,
+}
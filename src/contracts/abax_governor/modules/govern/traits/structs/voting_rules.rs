@@ -15,4 +15,15 @@ pub struct VotingRules {
     pub flat_period: Timestamp,
     /// time after flat_period during which the required amount to finalize proposal linearly falls to 0.
     pub final_period: Timestamp,
+    /// minimal part of total votes that has to participate in voting to finalize proposal, regardless of the time passed.
+    /// If it is not reached by the end of final_period, the proposal is finalized as Defeated.
+    pub minimum_quorum_e3: u16,
+    /// maximal number of simultaneously active proposals. Has to be in range 1..=`MAXIMUM_ACTIVE_PROPOSALS_CAP`.
+    pub maximum_active_proposals: u32,
+    /// maximal number of simultaneously active proposals created by a single proposer.
+    pub maximum_active_proposals_per_proposer: u32,
+    /// minimal time between two proposals created by the same proposer.
+    pub minimum_proposal_interval: Timestamp,
+    /// time after finalization of a proposal during which accounts that didn't vote can't be force unstaked yet.
+    pub force_unstake_grace_period: Timestamp,
 }
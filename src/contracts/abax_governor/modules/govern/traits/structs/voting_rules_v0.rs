@@ -0,0 +1,37 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// Layout of `VotingRules` in storage version 0.
+pub struct VotingRulesV0 {
+    /// minimal part of proposer stake in total stake to propose.
+    pub minimum_stake_part_e3: u16,
+    /// part of total
+    pub proposer_deposit_part_e3: u16,
+    /// during initial period required amount to finalize proposal falls from 100% to 50% of total votes.
+    pub initial_period: Timestamp,
+    /// time after start of proposal during which the required amount to finalize proposal is flat at 50%.
+    pub flat_period: Timestamp,
+    /// time after flat_period during which the required amount to finalize proposal linearly falls to 0.
+    pub final_period: Timestamp,
+}
+
+/// Rules introduced after storage version 0 are set so that they don't restrict the governance more than before,
+/// except for the number of active proposals which is bounded by `MAXIMUM_ACTIVE_PROPOSALS_CAP`.
+impl From<VotingRulesV0> for VotingRules {
+    fn from(rules: VotingRulesV0) -> Self {
+        VotingRules {
+            minimum_stake_part_e3: rules.minimum_stake_part_e3,
+            proposer_deposit_part_e3: rules.proposer_deposit_part_e3,
+            initial_period: rules.initial_period,
+            flat_period: rules.flat_period,
+            final_period: rules.final_period,
+            minimum_quorum_e3: 0,
+            maximum_active_proposals: crate::abax_governor::MAXIMUM_ACTIVE_PROPOSALS_CAP,
+            maximum_active_proposals_per_proposer: crate::abax_governor::MAXIMUM_ACTIVE_PROPOSALS_CAP,
+            minimum_proposal_interval: 0,
+            force_unstake_grace_period: 0,
+        }
+    }
+}
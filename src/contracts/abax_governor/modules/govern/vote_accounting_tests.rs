@@ -0,0 +1,278 @@
+//! Stateful model-checking harness for the vote accounting of the governor.
+//!
+//! Random sequences of actions are driven against the messages of the governor in the off-chain ink environment.
+//! Cross-contract calls (asset transfers, vesting) are not available off-chain, so deposits, withdrawals and force unstakes
+//! replay the share accounting of `_deposit` and `_withdraw` without moving the assets and proposer deposits are taken in shares.
+//! After every step the vote accounting invariants are asserted.
+use ink::{
+    env::{
+        test::{run_test, set_block_timestamp, set_callee, set_caller},
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+    primitives::AccountId,
+};
+use pendzl::{
+    contracts::{access_control::AccessControlInternal, psp22::PSP22Internal},
+    traits::{Balance, Hash, StorageAsMut, StorageAsRef, String, Timestamp},
+};
+use proptest::prelude::*;
+
+use crate::{
+    abax_governor::{AbaxGovernor, EXECUTOR},
+    modules::govern::{
+        storage::{
+            govern_storage_item::GovernData, locked_shares_storage_item::LockedSharesData,
+            vault_counter_storage_item::VaultCounterData,
+        },
+        traits::{AbaxGovern, Proposal, ProposalId, ProposalStatus, Vote, VotingRules},
+    },
+};
+
+const DAY: Timestamp = 24 * 60 * 60 * 1000;
+const START: Timestamp = 1_000 * DAY;
+const USERS: u8 = 4;
+const GOVERNOR: [u8; 32] = [0xff; 32];
+const VESTER: [u8; 32] = [0xfe; 32];
+const ASSET: [u8; 32] = [0xfd; 32];
+const EXECUTOR_ACCOUNT: [u8; 32] = [0xfc; 32];
+const VOTES: [Vote; 4] = [
+    Vote::Agreed,
+    Vote::Disagreed,
+    Vote::DisagreedWithProposerSlashing,
+    Vote::Abstain,
+];
+
+#[derive(Debug, Clone)]
+enum Action {
+    Deposit { user: u8, amount: Balance },
+    Withdraw { user: u8, part_e3: u16 },
+    Propose { user: u8 },
+    Vote { user: u8, proposal: u8, vote: u8 },
+    Finalize { proposal: u8 },
+    ForceUnstake { user: u8, proposal: u8 },
+    Execute { proposal: u8 },
+    AdvanceTime { duration: Timestamp },
+}
+
+fn arb_action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        1 => (0..USERS, 1..10_000_u128).prop_map(|(user, amount)| Action::Deposit { user, amount }),
+        1 => (0..USERS, 1..=1000_u16).prop_map(|(user, part_e3)| Action::Withdraw { user, part_e3 }),
+        1 => (0..USERS).prop_map(|user| Action::Propose { user }),
+        // votes and re-votes are the most interesting
+        3 => (0..USERS, 0..8_u8, 0..VOTES.len() as u8)
+            .prop_map(|(user, proposal, vote)| Action::Vote { user, proposal, vote }),
+        1 => (0..8_u8).prop_map(|proposal| Action::Finalize { proposal }),
+        1 => (0..USERS, 0..8_u8).prop_map(|(user, proposal)| Action::ForceUnstake { user, proposal }),
+        1 => (0..8_u8).prop_map(|proposal| Action::Execute { proposal }),
+        1 => (0..2 * DAY).prop_map(|duration| Action::AdvanceTime { duration }),
+    ]
+}
+
+fn user(index: u8) -> AccountId {
+    AccountId::from([index + 1; 32])
+}
+
+struct Harness {
+    governor: AbaxGovernor,
+    proposals: Vec<Proposal>,
+    now: Timestamp,
+}
+
+impl Harness {
+    fn new() -> Self {
+        set_block_timestamp::<DefaultEnvironment>(START);
+        set_callee::<DefaultEnvironment>(GOVERNOR.into());
+        let mut governor = AbaxGovernor::new_off_chain(
+            ASSET.into(),
+            VESTER.into(),
+            180 * DAY,
+            VotingRules {
+                minimum_stake_part_e3: 100,
+                proposer_deposit_part_e3: 500,
+                initial_period: DAY,
+                flat_period: DAY,
+                final_period: DAY,
+                minimum_quorum_e3: 0,
+                maximum_active_proposals: 5,
+                maximum_active_proposals_per_proposer: 2,
+                minimum_proposal_interval: 0,
+                force_unstake_grace_period: 0,
+            },
+        );
+        governor
+            ._grant_role(EXECUTOR, Some(EXECUTOR_ACCOUNT.into()))
+            .unwrap();
+        Self {
+            governor,
+            proposals: Vec::new(),
+            now: START,
+        }
+    }
+
+    fn govern(&self) -> &GovernData {
+        self.governor.data::<GovernData>()
+    }
+
+    fn lock(&self) -> &LockedSharesData {
+        self.governor.data::<LockedSharesData>()
+    }
+
+    /// votes of `account` on `proposal_id` - its votes and, if it is the proposer, the locked deposit.
+    fn votes_of(&self, account: &AccountId, proposal_id: &ProposalId) -> Balance {
+        let votes = self.governor._votes_of(account).unwrap();
+        if self.govern().state_of(proposal_id).unwrap().proposer == *account {
+            votes + self.lock().locked(proposal_id)
+        } else {
+            votes
+        }
+    }
+
+    /// `_deposit` without the transfer of the assets.
+    fn stake(&mut self, receiver: &AccountId, shares: Balance) {
+        StorageAsMut::data::<VaultCounterData>(&mut self.governor).increase_counter(shares);
+        StorageAsMut::data::<GovernData>(&mut self.governor).set_last_stake_timestamp(receiver);
+        self.governor._mint_to(receiver, &shares).unwrap();
+        self.governor._sync_votes(receiver).unwrap();
+    }
+
+    /// `_withdraw` without the vesting of the assets.
+    fn unstake(&mut self, owner: &AccountId, shares: Balance) {
+        self.governor._burn_from(owner, &shares).unwrap();
+        self.governor._sync_votes(owner).unwrap();
+        if self.governor._balance_of(owner) == 0 {
+            StorageAsMut::data::<GovernData>(&mut self.governor).remove_last_stake_timestamp(owner);
+        }
+    }
+
+    fn apply(&mut self, action: &Action) {
+        match *action {
+            Action::Deposit {
+                user: index,
+                amount,
+            } => {
+                self.stake(&user(index), amount);
+            }
+            Action::Withdraw {
+                user: index,
+                part_e3,
+            } => {
+                let account = user(index);
+                let amount = self.governor._balance_of(&account) * part_e3 as u128 / 1000;
+                self.unstake(&account, amount);
+            }
+            Action::Propose { user: index } => {
+                let proposal = Proposal {
+                    transactions: Vec::new(),
+                    description_hash: Hash::from([self.proposals.len() as u8; 32]),
+                    description_url: String::from("url"),
+                    earliest_execution: None,
+                };
+                set_caller::<DefaultEnvironment>(user(index));
+                if self.governor.propose(proposal.clone()).is_ok() {
+                    self.proposals.push(proposal);
+                }
+            }
+            Action::Vote {
+                user: index,
+                proposal,
+                vote,
+            } => {
+                let account = user(index);
+                set_caller::<DefaultEnvironment>(account);
+                let _ =
+                    self.governor
+                        .vote(proposal as ProposalId, VOTES[vote as usize], Vec::new());
+            }
+            Action::Finalize { proposal } => {
+                let _ = self.governor.finalize(proposal as ProposalId);
+            }
+            Action::ForceUnstake {
+                user: index,
+                proposal,
+            } => {
+                // `force_unstake` without the vesting of the assets
+                let account = user(index);
+                if StorageAsMut::data::<GovernData>(&mut self.governor)
+                    .force_unstake(&account, &(proposal as ProposalId))
+                    .is_ok()
+                {
+                    let balance = self.governor._balance_of(&account);
+                    self.unstake(&account, balance);
+                }
+            }
+            Action::Execute { proposal } => {
+                if let Some(proposal) = self.proposals.get(proposal as usize).cloned() {
+                    set_caller::<DefaultEnvironment>(EXECUTOR_ACCOUNT.into());
+                    let _ = self.governor.execute(proposal);
+                }
+            }
+            Action::AdvanceTime { duration } => {
+                self.now += duration;
+                set_block_timestamp::<DefaultEnvironment>(self.now);
+            }
+        }
+    }
+
+    fn assert_invariants(&self) {
+        // shares locked as proposer deposits (including slashed ones) are held by the governor
+        let locked: Balance = (0..self.govern().next_proposal_id())
+            .map(|proposal_id| self.lock().locked(&proposal_id))
+            .sum();
+        assert_eq!(locked, self.governor._balance_of(&GOVERNOR.into()));
+
+        let mut active_proposal_ids = Vec::new();
+        for proposal_id in 0..self.govern().next_proposal_id() {
+            let state = self.govern().state_of(&proposal_id).unwrap();
+            let mut tallies = [0; VOTES.len()];
+            let mut voters_count = 0;
+            for index in 0..USERS {
+                let account = user(index);
+                let Some(user_vote) = self.govern().vote_of_for(&account, &proposal_id) else {
+                    continue;
+                };
+                voters_count += 1;
+                let position = VOTES.iter().position(|v| *v == user_vote.vote).unwrap();
+                tallies[position] += user_vote.amount;
+                // votes on active proposals follow the stake
+                if state.status == ProposalStatus::Active {
+                    assert_eq!(user_vote.amount, self.votes_of(&account, &proposal_id));
+                }
+            }
+            assert_eq!(
+                tallies,
+                [
+                    state.votes_for,
+                    state.votes_against,
+                    state.votes_against_with_slash,
+                    state.votes_abstain
+                ]
+            );
+            assert_eq!(voters_count, state.voters_count);
+            if state.status == ProposalStatus::Active {
+                active_proposal_ids.push(proposal_id);
+            }
+        }
+        assert_eq!(active_proposal_ids, self.govern().active_proposal_ids());
+        assert_eq!(
+            active_proposal_ids.len() as u32,
+            self.govern().active_proposals()
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn vote_accounting_invariants_hold(actions in prop::collection::vec(arb_action(), 1..200)) {
+        run_test::<DefaultEnvironment, _>(|_| {
+            let mut harness = Harness::new();
+            for action in &actions {
+                harness.apply(action);
+                harness.assert_invariants();
+            }
+            Ok(())
+        })
+        .unwrap();
+    }
+}
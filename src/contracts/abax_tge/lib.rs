@@ -10,16 +10,15 @@ mod modules;
 pub mod abax_tge_contract {
     pub use crate::{
         constants::{
-            ALL_TO_PUBLIC_RATIO, BONUS_DENOMINATOR, BONUS_FOR_REFERRER_USE_E3, BONUS_MAX_E3,
-            E12_U128, E3_U128, E6_U128, E8_U128, INSTANT_CONTRIBUTOR_RELEASE_E3,
-            INSTANT_FOUNDERS_RELEASE_E3, PART_OD_FOUNDATION_E3, PART_OF_FOUNDERS_E3,
-            REWARD_FOR_REFERER_E3, VEST_DURATION,
+            BONUS_DENOMINATOR, BONUS_FOR_REFERRER_USE_E3, BONUS_MAX_E3, E12_U128, E3_U128, E6_U128,
+            E8_U128, REWARD_FOR_REFERER_E3,
         },
         modules::tge::{
             errors::TGEError,
             events::{BonusMultiplierSet, Contribution, PhaseChanged, Stakedrop},
             storage_fields::public_contribution::PublicContributionStorage,
-            traits::{AbaxTGE, AbaxTGEView, AbaxToken, AbaxTokenRef},
+            structs::{Allocation, TGEParameters, TGEPhase, TokenAllocationDistribution},
+            traits::{AbaxTGE, AbaxTGEView, AbaxToken, AbaxTokenRef, PSP22Permit, PSP22PermitRef},
         },
     };
     pub use ink::{
@@ -77,8 +76,12 @@ pub mod abax_tge_contract {
             strategic_reserves_address: AccountId,
             phase_one_token_cap: u128,
             cost_to_mint_milliard_tokens: u128,
-        ) -> Self {
-            Self {
+            distribution: TokenAllocationDistribution,
+        ) -> Result<Self, TGEError> {
+            if !distribution.is_valid() {
+                return Err(TGEError::InvalidTokenAllocationDistribution);
+            }
+            Ok(Self {
                 access_control: AccessControlData::new(Some(Self::env().caller())),
                 tge: PublicContributionStorage::new(
                     start_time,
@@ -91,8 +94,9 @@ pub mod abax_tge_contract {
                     strategic_reserves_address,
                     phase_one_token_cap,
                     cost_to_mint_milliard_tokens,
+                    distribution,
                 ),
-            }
+            })
         }
     }
 
@@ -103,30 +107,31 @@ pub mod abax_tge_contract {
                 return Err(TGEError::AlreadyInitialized);
             }
 
-            self.generate_to_self(mul_div(
-                80,
-                self.tge.phase_one_token_cap,
-                100,
-                Rounding::Down,
+            let distribution = self.tge.distribution;
+            let cap = self.tge.phase_one_token_cap;
+            self.generate_to_self(mul_denom_e3(
+                cap,
+                E3_U128
+                    .checked_sub(distribution.public_contribution.part_e3 as u128)
+                    .ok_or(MathError::Underflow)?,
             )?)?;
             self.tge.reserve_tokens(
                 self.tge.founders_address,
-                mul_div(20, self.tge.phase_one_token_cap, 100, Rounding::Down)?,
+                mul_denom_e3(cap, distribution.founders.part_e3 as u128)?,
             )?;
             self.tge.reserve_tokens(
                 self.tge.foundation_address,
-                mul_div(2, self.tge.phase_one_token_cap, 100, Rounding::Down)?,
+                mul_denom_e3(cap, distribution.foundation.part_e3 as u128)?,
             )?;
             self.tge.reserve_tokens(
                 self.tge.strategic_reserves_address,
-                mul_div(58, self.tge.phase_one_token_cap, 100, Rounding::Down)?,
+                mul_denom_e3(cap, distribution.strategic_reserves.part_e3 as u128)?,
             )?;
             Ok(())
         }
 
         // creates tokens for the contributor (amount + bonus)
-        // 40% of the tokens are instantly transfered to the contributor
-        // the rest is scheduled to be vested over 4 years
+        // tokens are instantly transfered to the contributor or vested according to the public contribution allocation
         // takes into account the exp bonus, contribution bonus and refferer bonus
         // if refferer is passed generates tokens for the referer
         // updates the base created and bonus created amounts
@@ -187,6 +192,34 @@ pub mod abax_tge_contract {
             Ok(cost)
         }
 
+        // consumes the permit of the contributor over the contribution token
+        // the contribution fails if the cost exceeds the permitted value
+        #[ink(message)]
+        fn contribute_with_permit(
+            &mut self,
+            to_create: Balance,
+            receiver: AccountId,
+            referrer: Option<AccountId>,
+            permit_value: Balance,
+            deadline: Timestamp,
+            signature: [u8; 65],
+        ) -> Result<u128, TGEError> {
+            let mut contribution_token: PSP22PermitRef =
+                self.tge.contribution_token.to_account_id().into();
+            contribution_token
+                .call_mut()
+                .permit(
+                    self.env().caller(),
+                    self.env().account_id(),
+                    permit_value,
+                    deadline,
+                    signature,
+                )
+                .call_v1()
+                .invoke()?;
+            self.contribute(to_create, receiver, referrer)
+        }
+
         // reserves amount.checked_add(bonus).ok_or(MathError::Overflow)? of tokens for the receiver
         // updates the contributed amount of the  by the fee_paid
         // updates the base created and bonus created amounts
@@ -225,15 +258,17 @@ pub mod abax_tge_contract {
 
             let reserved_amount = self.tge.collect_reserved_tokens(account)?;
 
-            if account == self.tge.strategic_reserves_address
-                || account == self.tge.foundation_address
-            {
-                self.distribute(account, reserved_amount, E3_U128 as u16)?;
+            let distribution = self.tge.distribution;
+            let allocation = if account == self.tge.strategic_reserves_address {
+                distribution.strategic_reserves
+            } else if account == self.tge.foundation_address {
+                distribution.foundation
             } else if account == self.tge.founders_address {
-                self.distribute(account, reserved_amount, INSTANT_FOUNDERS_RELEASE_E3)?;
+                distribution.founders
             } else {
-                self.distribute(account, reserved_amount, INSTANT_CONTRIBUTOR_RELEASE_E3)?;
-            }
+                distribution.public_contribution
+            };
+            self.distribute(account, reserved_amount, &allocation)?;
             Ok(reserved_amount)
         }
 
@@ -262,7 +297,31 @@ pub mod abax_tge_contract {
     }
 
     impl AbaxTGEView for TGEContract {
+        #[ink(message)]
+        fn parameters(&self) -> TGEParameters {
+            TGEParameters {
+                start_time: self.tge.start_time,
+                phase_two_start_time: self.tge.phase_two_start_time,
+                phase_two_duration: self.tge.phase_two_duration,
+                phase_two_end_time: self._phase_two_end_time(),
+                generated_token_address: self.tge.generated_token_address,
+                contribution_token_address: self.tge.contribution_token.to_account_id(),
+                vester_address: self.tge.vester.to_account_id(),
+                founders_address: self.tge.founders_address,
+                foundation_address: self.tge.foundation_address,
+                strategic_reserves_address: self.tge.strategic_reserves_address,
+                phase_one_token_cap: self.tge.phase_one_token_cap,
+                phase_one_remaining_capacity: self
+                    .tge
+                    .phase_one_token_cap
+                    .saturating_sub(self.tge.total_amount_minted()),
+                cost_to_mint_milliard_tokens: self.tge.cost_to_mint_milliard_tokens,
+                current_phase: self._current_phase(),
+            }
+        }
+
         //returns a tuple with all of the TGE state properties that are not mappings
+        // deprecated - kept for backward compatibility, use `parameters` instead
         #[ink(message)]
         fn tge_parameters(
             &self,
@@ -399,6 +458,24 @@ pub mod abax_tge_contract {
         fn _is_phase_one(&self) -> bool {
             self.tge.phase_two_start_time.is_none()
         }
+
+        fn _phase_two_end_time(&self) -> Option<Timestamp> {
+            self.tge
+                .phase_two_start_time
+                .map(|start| start.saturating_add(self.tge.phase_two_duration))
+        }
+
+        // mirrors the checks done by _ensure_has_started and _ensure_is_not_finished
+        fn _current_phase(&self) -> TGEPhase {
+            if self._ensure_has_started().is_err() {
+                return TGEPhase::NotStarted;
+            }
+            match self._phase_two_end_time() {
+                None => TGEPhase::PhaseOne,
+                Some(end) if self.env().block_timestamp() > end => TGEPhase::Ended,
+                Some(_) => TGEPhase::PhaseTwo,
+            }
+        }
     }
     impl TGEContract {
         // return bonus multiplier awarded for contribution
@@ -489,10 +566,8 @@ pub mod abax_tge_contract {
                 if amount_phase2 == 0 {
                     0
                 } else {
-                    // take into account that during 2nd phase contributor also generates tokens to founders foundation and strategic reserves to keep 20/20/2/58 ratio.
-                    let effective_tokens = amount_phase2
-                        .checked_mul(ALL_TO_PUBLIC_RATIO)
-                        .ok_or(MathError::Overflow)?;
+                    // take into account that during 2nd phase contributor also generates tokens to founders foundation and strategic reserves to keep the distribution ratio.
+                    let effective_tokens = self.all_to_public(amount_phase2)?;
 
                     let averaged_amount =
                         if self.tge.total_amount_minted() <= self.tge.phase_one_token_cap {
@@ -562,10 +637,9 @@ pub mod abax_tge_contract {
                     .ok_or(MathError::Underflow)?;
             }
 
-            // in phase 2 whenever a token is generated during contribution appropariate amount of tokens is created for foundation, founders, strategic reserves to keep the 20/20/2/58 ratio.
-            let amount_to_mint_phase2 = ALL_TO_PUBLIC_RATIO
-                .checked_mul(amount_phase2)
-                .ok_or(MathError::Overflow)?;
+            // in phase 2 whenever a token is generated during contribution appropariate amount of tokens is created for foundation, founders, strategic reserves to keep the distribution ratio.
+            let distribution = self.tge.distribution;
+            let amount_to_mint_phase2 = self.all_to_public(amount_phase2)?;
             let amount_to_mint = amount_phase1
                 .checked_add(amount_to_mint_phase2)
                 .ok_or(MathError::Overflow)?;
@@ -577,17 +651,19 @@ pub mod abax_tge_contract {
                     self.tge.reserve_tokens(to, amount)?;
                 }
                 Generate::Distribute => {
-                    self.distribute(to, amount, INSTANT_CONTRIBUTOR_RELEASE_E3)?;
+                    self.distribute(to, amount, &distribution.public_contribution)?;
                 }
             }
 
             if amount_phase2 > 0 {
                 let founders_amount =
-                    mul_denom_e3(amount_to_mint_phase2, PART_OF_FOUNDERS_E3 as u128)?;
+                    mul_denom_e3(amount_to_mint_phase2, distribution.founders.part_e3 as u128)?;
                 self.tge
                     .reserve_tokens(self.tge.founders_address, founders_amount)?;
-                let foundation_amount =
-                    mul_denom_e3(amount_to_mint_phase2, PART_OD_FOUNDATION_E3 as u128)?;
+                let foundation_amount = mul_denom_e3(
+                    amount_to_mint_phase2,
+                    distribution.foundation.part_e3 as u128,
+                )?;
                 self.tge
                     .reserve_tokens(self.tge.foundation_address, foundation_amount)?;
                 let strategic_reserves_amount = amount_to_mint_phase2
@@ -619,16 +695,27 @@ pub mod abax_tge_contract {
             Ok(())
         }
 
-        /// Distributes tokens to "to"
-        /// instant_e3 / E3 part of the amount is instantly transfered to "to"
+        // Returns the amount of all tokens that are generated together with `amount` of public contribution tokens
+        fn all_to_public(&self, amount: Balance) -> Result<Balance, MathError> {
+            mul_div(
+                amount,
+                E3_U128,
+                self.tge.distribution.public_contribution.part_e3 as u128,
+                Rounding::Down,
+            )
+        }
+
+        /// Distributes tokens to "to" according to the `allocation`
+        /// instant_release_percentage_e3 / E3 part of the amount is instantly transfered to "to"
         /// the rest is scheduled to be vested
         fn distribute(
             &self,
             to: AccountId,
             amount: Balance,
-            instant_e3: u16,
+            allocation: &Allocation,
         ) -> Result<(), TGEError> {
-            let amount_to_transfer = mul_denom_e3(amount, instant_e3 as u128)?;
+            let amount_to_transfer =
+                mul_denom_e3(amount, allocation.instant_release_percentage_e3 as u128)?;
             let amount_to_vest = amount
                 .checked_sub(amount_to_transfer)
                 .ok_or(MathError::Underflow)?;
@@ -640,15 +727,22 @@ pub mod abax_tge_contract {
                 .call_v1()
                 .invoke()?;
 
-            if amount_to_vest > 0 {
-                self.schedule_vest(to, amount_to_vest)?;
+            if let Some(vesting_params) = allocation.vesting_params {
+                if amount_to_vest > 0 {
+                    self.schedule_vest(to, amount_to_vest, vesting_params.duration)?;
+                }
             }
 
             Ok(())
         }
 
-        // creates a vesting schedule for "to" with "amount" of tokens
-        fn schedule_vest(&self, to: AccountId, amount: Balance) -> Result<(), TGEError> {
+        // creates a vesting schedule for "to" with "amount" of tokens vested linearly over "duration"
+        fn schedule_vest(
+            &self,
+            to: AccountId,
+            amount: Balance,
+            duration: Timestamp,
+        ) -> Result<(), TGEError> {
             let mut general_vest: GeneralVestRef = self.tge.vester.to_account_id().into();
             let mut psp22: PSP22Ref = self.tge.generated_token_address.into();
 
@@ -663,7 +757,7 @@ pub mod abax_tge_contract {
                     to,
                     Some(psp22.to_account_id()),
                     amount,
-                    VestingSchedule::Constant(0, VEST_DURATION),
+                    VestingSchedule::Constant(0, duration),
                     vec![],
                 )
                 .call_v1()
@@ -22,6 +22,7 @@ pub enum TGEError {
     InvalidReferrer,
     NoReservedTokens,
     AlreadyInitialized,
+    InvalidTokenAllocationDistribution,
 }
 
 impl From<PSP22Error> for TGEError {
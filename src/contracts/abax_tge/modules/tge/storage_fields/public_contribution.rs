@@ -5,7 +5,7 @@ use pendzl::{
     traits::{AccountId, Balance, Timestamp},
 };
 
-use crate::modules::tge::errors::TGEError;
+use crate::modules::tge::{errors::TGEError, structs::TokenAllocationDistribution};
 
 #[derive(Debug)]
 #[pendzl::storage_item]
@@ -33,6 +33,8 @@ pub struct PublicContributionStorage {
     pub phase_one_token_cap: Balance,
     // cost to mint 1 milliard tokens (in absolute units) [ in contribution_token]
     pub cost_to_mint_milliard_tokens: u128,
+    // split of generated tokens and their release.
+    pub distribution: TokenAllocationDistribution,
     // total amount of distributed tokens.
     total_amount_minted: Balance,
     // bonus multiplier based on the Zealy EXP.
@@ -62,6 +64,7 @@ impl PublicContributionStorage {
         strategic_reserves_address: AccountId,
         phase_one_token_cap: u128,
         cost_to_mint_milliard_tokens: u128,
+        distribution: TokenAllocationDistribution,
     ) -> Self {
         Self {
             start_time,
@@ -75,6 +78,7 @@ impl PublicContributionStorage {
             foundation_address,
             strategic_reserves_address,
             cost_to_mint_milliard_tokens,
+            distribution,
             total_amount_minted: 0,
             exp_bonus_multiplier_e3_by_address: Default::default(),
             contributed_amount_by_account: Default::default(),
@@ -1,21 +1,203 @@
-use pendzl::traits::Timestamp;
+use pendzl::traits::{AccountId, Balance, Timestamp};
 
-#[derive(Debug, Copy, Clone, scale::Encode, scale::Decode)]
+use crate::constants::E3_U128;
+
+/// Split of all tokens generated by the TGE between the public contribution, founders, foundation and strategic reserves.
+#[derive(Debug, Copy, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
 pub struct TokenAllocationDistribution {
+    /// allocation of contributors (and referrers).
     pub public_contribution: Allocation,
     pub founders: Allocation,
     pub foundation: Allocation,
     pub strategic_reserves: Allocation,
 }
 
-#[derive(Debug, Copy, Clone, scale::Encode, scale::Decode)]
+#[derive(Debug, Copy, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
 pub struct Allocation {
+    /// part of all generated tokens.
+    pub part_e3: u16,
+    /// part of the allocation that is instantly released.
     pub instant_release_percentage_e3: u16,
+    /// vesting of the rest of the allocation. Required if and only if not all of the allocation is instantly released.
     pub vesting_params: Option<VestingParams>,
 }
 
-#[derive(Debug, Copy, Clone, scale::Encode, scale::Decode)]
+#[derive(Debug, Copy, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
 pub struct VestingParams {
-    pub amount_to_release_percentage_e3: u16,
+    /// duration of the linear vesting.
     pub duration: Timestamp,
 }
+
+/// Phase of the TGE at the time of the query.
+#[derive(Debug, Copy, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum TGEPhase {
+    /// TGE was not initialized or its start time has not passed yet.
+    NotStarted,
+    /// tokens are generated until the phase one token cap is reached.
+    PhaseOne,
+    /// phase one token cap was reached, contributions are possible until the end of phase two.
+    PhaseTwo,
+    /// phase two is over, contributions are no longer possible.
+    Ended,
+}
+
+/// All of the TGE state properties that are not mappings.
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct TGEParameters {
+    /// after that timestamp accounts can start to contribute.
+    pub start_time: Timestamp,
+    /// the timestamp at which phase one was concluded and phase two started.
+    pub phase_two_start_time: Option<Timestamp>,
+    /// the duration of phase two.
+    pub phase_two_duration: Timestamp,
+    /// the timestamp at which phase two ends. None if phase two has not started yet.
+    pub phase_two_end_time: Option<Timestamp>,
+    /// token that is generated by the TGE.
+    pub generated_token_address: AccountId,
+    /// token that is contributed by the public.
+    pub contribution_token_address: AccountId,
+    /// contract used to create vesting schedules.
+    pub vester_address: AccountId,
+    pub founders_address: AccountId,
+    pub foundation_address: AccountId,
+    pub strategic_reserves_address: AccountId,
+    /// total amount of tokens created in phase one and stakedrop.
+    pub phase_one_token_cap: Balance,
+    /// amount of tokens that can still be created before phase two starts.
+    pub phase_one_remaining_capacity: Balance,
+    /// cost to mint 1 milliard tokens (in absolute units) [ in contribution_token]
+    pub cost_to_mint_milliard_tokens: u128,
+    pub current_phase: TGEPhase,
+}
+
+impl TokenAllocationDistribution {
+    /// Checks that parts of the allocations sum to 100%, the public contribution has a non-zero part
+    /// and each allocation is fully released - instantly or by vesting.
+    pub fn is_valid(&self) -> bool {
+        let allocations = [
+            self.public_contribution,
+            self.founders,
+            self.foundation,
+            self.strategic_reserves,
+        ];
+        let parts_sum: u128 = allocations.iter().map(|a| a.part_e3 as u128).sum();
+        parts_sum == E3_U128
+            && self.public_contribution.part_e3 > 0
+            && allocations.iter().all(Allocation::is_valid)
+    }
+}
+
+impl Allocation {
+    fn is_valid(&self) -> bool {
+        match self.vesting_params {
+            Some(_) => (self.instant_release_percentage_e3 as u128) < E3_U128,
+            None => self.instant_release_percentage_e3 as u128 == E3_U128,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VESTING_PARAMS: Option<VestingParams> = Some(VestingParams { duration: 1_000 });
+
+    fn allocation(part_e3: u16, instant_release_percentage_e3: u16) -> Allocation {
+        Allocation {
+            part_e3,
+            instant_release_percentage_e3,
+            vesting_params: if instant_release_percentage_e3 < 1000 {
+                VESTING_PARAMS
+            } else {
+                None
+            },
+        }
+    }
+
+    fn distribution() -> TokenAllocationDistribution {
+        TokenAllocationDistribution {
+            public_contribution: allocation(200, 400),
+            founders: allocation(200, 200),
+            foundation: allocation(20, 1000),
+            strategic_reserves: allocation(580, 1000),
+        }
+    }
+
+    #[test]
+    fn distribution_is_valid() {
+        assert!(distribution().is_valid());
+        assert!(TokenAllocationDistribution {
+            public_contribution: allocation(1000, 0),
+            founders: allocation(0, 1000),
+            foundation: allocation(0, 1000),
+            strategic_reserves: allocation(0, 1000),
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn parts_must_sum_to_100_percent() {
+        assert!(!TokenAllocationDistribution {
+            strategic_reserves: allocation(581, 1000),
+            ..distribution()
+        }
+        .is_valid());
+        assert!(!TokenAllocationDistribution {
+            strategic_reserves: allocation(579, 1000),
+            ..distribution()
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn public_contribution_part_must_not_be_zero() {
+        assert!(!TokenAllocationDistribution {
+            public_contribution: allocation(0, 400),
+            strategic_reserves: allocation(780, 1000),
+            ..distribution()
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn allocation_must_be_fully_released() {
+        // the rest of the allocation isn't vested
+        assert!(!TokenAllocationDistribution {
+            founders: Allocation {
+                vesting_params: None,
+                ..allocation(200, 200)
+            },
+            ..distribution()
+        }
+        .is_valid());
+        // nothing is left to be vested
+        assert!(!TokenAllocationDistribution {
+            foundation: Allocation {
+                vesting_params: VESTING_PARAMS,
+                ..allocation(20, 1000)
+            },
+            ..distribution()
+        }
+        .is_valid());
+        // more than the allocation is released
+        assert!(!TokenAllocationDistribution {
+            foundation: allocation(20, 1001),
+            ..distribution()
+        }
+        .is_valid());
+    }
+}
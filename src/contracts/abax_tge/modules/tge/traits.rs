@@ -1,9 +1,9 @@
 use pendzl::{
     contracts::psp22::PSP22Error,
-    traits::{AccountId, Balance},
+    traits::{AccountId, Balance, Timestamp},
 };
 
-use crate::modules::tge::errors::TGEError;
+use crate::modules::tge::{errors::TGEError, structs::TGEParameters};
 
 #[ink::trait_definition]
 /// Trait defining the functions for the TGE module.
@@ -35,6 +35,31 @@ pub trait AbaxTGE {
         referrer: Option<AccountId>,
     ) -> Result<u128, TGEError>;
 
+    /// Contribute function that uses `caller` signed permit over the contribution token instead of a prior approve transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `to_create` - The amount of tokens to create.
+    /// * `receiver` - The account ID of the receiver.
+    /// * `referrer` - An optional account ID of the referrer.
+    /// * `permit_value` - The amount of contribution tokens the permit allows the TGE to spend. Bounds the cost of the contribution.
+    /// * `deadline` - The deadline of the permit.
+    /// * `signature` - The signature of the permit.
+    ///
+    /// # Returns
+    ///
+    /// Returns the cost of the contribution, or an error if the permit or the contribution fails.
+    #[ink(message)]
+    fn contribute_with_permit(
+        &mut self,
+        to_create: Balance,
+        receiver: AccountId,
+        referrer: Option<AccountId>,
+        permit_value: Balance,
+        deadline: Timestamp,
+        signature: [u8; 65],
+    ) -> Result<u128, TGEError>;
+
     /// Stakedrop function for the TGE module.
     ///
     /// # Arguments
@@ -75,6 +100,13 @@ pub trait AbaxTGE {
 
 #[ink::trait_definition]
 pub trait AbaxTGEView {
+    /// Returns all of the TGE state properties that are not mappings together with the current phase.
+    #[ink(message)]
+    fn parameters(&self) -> TGEParameters;
+
+    /// Returns a tuple with all of the TGE state properties that are not mappings.
+    ///
+    /// Deprecated: use `parameters` instead. The tuple is kept for backward compatibility and will not be extended.
     #[ink(message)]
     fn tge_parameters(
         &self,
@@ -121,6 +153,7 @@ pub trait AbaxTGEView {
 
 use ink::{contract_ref, env::DefaultEnvironment};
 pub type AbaxTokenRef = contract_ref!(AbaxToken, DefaultEnvironment);
+pub type PSP22PermitRef = contract_ref!(PSP22Permit, DefaultEnvironment);
 
 #[ink::trait_definition]
 pub trait AbaxToken {
@@ -133,3 +166,16 @@ pub trait AbaxToken {
     #[ink(message)]
     fn cap(&self) -> Balance;
 }
+
+#[ink::trait_definition]
+pub trait PSP22Permit {
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: [u8; 65],
+    ) -> Result<(), PSP22Error>;
+}
@@ -5,12 +5,40 @@ mod modules;
 #[pendzl::implementation(PSP22, PSP22Metadata, AccessControl, SetCodeHash)]
 #[ink::contract]
 pub mod abax_token {
-    pub use crate::modules::capped_inflation::{
-        capped_infaltion_storage_field::CappedInflation, reserved::Reserved, traits::AbaxToken,
+    pub use crate::modules::{
+        capped_inflation::{
+            capped_infaltion_storage_field::CappedInflation,
+            errors::CappedInflationError,
+            reserved::Reserved,
+            structs::{InflationSchedule, TokenInfo, MINIMUM_HALVING_PERIOD},
+            traits::AbaxToken,
+        },
+        distribution::{
+            airdrop_storage_field::AirdropData,
+            merkle::{hash_leaf, verify_proof},
+            traits::{AbaxTokenAirdrop, PSP22Batch},
+        },
+        minter_allowance::{
+            minter_allowance_storage_field::MinterAllowanceData, structs::MinterAllowance,
+            traits::AbaxTokenMinterAllowance,
+        },
+        permit::{
+            permit_storage_field::{permit_message_hash, recover_signer, PermitData},
+            traits::PSP22Permit,
+        },
+        receiver::{
+            receivers_storage_field::ReceiversData,
+            traits::{AbaxTokenReceivers, PSP22Receiver, PSP22ReceiverError, PSP22ReceiverRef},
+        },
+        votes::{traits::PSP22Votes, votes_storage_field::VotesData},
+    };
+    pub use ink::{
+        codegen::{StaticEnv, TraitCallBuilder},
+        prelude::{format, string::String},
     };
-    pub use ink::prelude::string::String;
     pub use pendzl::contracts::psp22::{
-        mintable::PSP22Mintable, PSP22Error, PSP22Internal, PSP22InternalDefaultImpl,
+        burnable::PSP22Burnable, mintable::PSP22Mintable, PSP22Error, PSP22Internal,
+        PSP22InternalDefaultImpl,
     };
     use pendzl::math::errors::MathError;
 
@@ -20,10 +48,78 @@ pub mod abax_token {
         cap: Balance,
     }
 
+    #[ink(event)]
+    pub struct InflationScheduleChanged {
+        schedule: InflationSchedule,
+    }
+
+    #[ink(event)]
+    pub struct MinterAllowanceSet {
+        #[ink(topic)]
+        minter: AccountId,
+        window_duration: Timestamp,
+        max_per_window: Balance,
+        lifetime_budget: Balance,
+    }
+
+    #[ink(event)]
+    pub struct MinterAllowanceRemoved {
+        #[ink(topic)]
+        minter: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct DelegateChanged {
+        #[ink(topic)]
+        delegator: AccountId,
+        from_delegate: Option<AccountId>,
+        to_delegate: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct DelegateVotesChanged {
+        #[ink(topic)]
+        delegate: AccountId,
+        previous_votes: Balance,
+        new_votes: Balance,
+    }
+
+    #[ink(event)]
+    pub struct AirdropRootSet {
+        round: u32,
+        root: Hash,
+    }
+
+    #[ink(event)]
+    pub struct AirdropClaimed {
+        round: u32,
+        index: u32,
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct HookReceiverSet {
+        #[ink(topic)]
+        account: AccountId,
+        allowed: bool,
+    }
+
+    #[ink(event)]
+    pub struct Burned {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        burner: AccountId,
+        amount: Balance,
+    }
+
     const YEAR: u128 = 365 * 24 * 60 * 60 * 1000;
     const TEN_YEARS: u128 = 10 * YEAR;
     const MINTER: RoleType = ink::selector_id!("MINTER");
     const GENERATOR: RoleType = ink::selector_id!("GENERATOR");
+    const BURNER: RoleType = ink::selector_id!("BURNER");
 
     #[ink(storage)]
     #[derive(Default, StorageFieldGetter)]
@@ -37,6 +133,16 @@ pub mod abax_token {
         #[storage_field]
         capped_inflation: CappedInflation,
         #[storage_field]
+        minter_allowance: MinterAllowanceData,
+        #[storage_field]
+        permit: PermitData,
+        #[storage_field]
+        votes: VotesData,
+        #[storage_field]
+        airdrop: AirdropData,
+        #[storage_field]
+        receivers: ReceiversData,
+        #[storage_field]
         upgradeable: Reserved,
     }
 
@@ -53,20 +159,129 @@ pub mod abax_token {
         self._mint_to_default_impl(to, amount)
     }
 
+    #[overrider(PSP22)]
+    fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
+        let caller = self.env().caller();
+        self._transfer(&caller, &to, &value)?;
+        self._notify_receiver(caller, caller, to, value, data)
+    }
+
+    #[overrider(PSP22)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        let caller = self.env().caller();
+        self._decrease_allowance_from_to(&from, &caller, &value)?;
+        self._transfer(&from, &to, &value)?;
+        self._notify_receiver(caller, from, to, value, data)
+    }
+
+    // moves votes between delegates of `from` and `to` and checkpoints the total supply on mint and burn
+    #[overrider(PSP22Internal)]
+    fn _update(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._update_default_impl(from, to, amount)?;
+        if from.is_none() || to.is_none() {
+            self.votes
+                .push_total_supply(self._total_supply(), Self::env().block_number())?;
+        }
+        let from_delegate = from.and_then(|from| self.votes.delegate_of(from));
+        let to_delegate = to.and_then(|to| self.votes.delegate_of(to));
+        self._move_delegate_votes(from_delegate, to_delegate, *amount)?;
+        Ok(())
+    }
+
     impl AbaxTokenContract {
+        /// `chain_id` identifies the chain in the permit domain separator, e.g. the genesis hash of the chain.
         #[ink(constructor)]
-        pub fn new(name: String, symbol: String, decimal: u8) -> Result<Self, PSP22Error> {
+        pub fn new(
+            name: String,
+            symbol: String,
+            decimal: u8,
+            chain_id: Hash,
+        ) -> Result<Self, PSP22Error> {
             let mut instance = Self::default();
             instance.metadata.name.set(&name.into());
             instance.metadata.symbol.set(&symbol.into());
             instance.metadata.decimals.set(&decimal);
+            instance.permit.set_chain_id(&chain_id);
 
-            instance._grant_role(0, Some(Self::env().caller()))?;
+            instance._grant_role(DEFAULT_ADMIN_ROLE, Some(Self::env().caller()))?;
 
             Ok(instance)
         }
 
-        pub fn _inflate_cap(&mut self) -> Result<(), MathError> {
+        // calls `PSP22Receiver::on_received` of allowlisted `to`, error of the receiver reverts the transfer.
+        // `to` pulling tokens itself (e.g. the governor in `deposit`) is not notified as it is still executing,
+        // so calling it back would fail on reentrancy. The receiver may call back into the token from `on_received`
+        // as the balances are already updated when it is called.
+        fn _notify_receiver(
+            &mut self,
+            operator: AccountId,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            if operator == to || !self.receivers.is_receiver(&to) {
+                return Ok(());
+            }
+            let mut receiver: PSP22ReceiverRef = to.into();
+            match receiver
+                .call_mut()
+                .on_received(operator, from, value, data)
+                .call_v1()
+                .call_flags(ink::env::CallFlags::ALLOW_REENTRY)
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(PSP22ReceiverError::TransferRejected(reason)))) => Err(
+                    PSP22Error::Custom(format!("TR:TransferRejected:{}", reason)),
+                ),
+                _ => Err(PSP22Error::Custom("TR:ReceiverCallFailed".into())),
+            }
+        }
+
+        fn _move_delegate_votes(
+            &mut self,
+            from: Option<AccountId>,
+            to: Option<AccountId>,
+            amount: Balance,
+        ) -> Result<(), MathError> {
+            if from == to || amount == 0 {
+                return Ok(());
+            }
+            let block = self.env().block_number();
+            if let Some(delegate) = from {
+                let (previous_votes, new_votes) =
+                    self.votes.decrease_votes(&delegate, amount, block)?;
+                self.env().emit_event(DelegateVotesChanged {
+                    delegate,
+                    previous_votes,
+                    new_votes,
+                });
+            }
+            if let Some(delegate) = to {
+                let (previous_votes, new_votes) =
+                    self.votes.increase_votes(&delegate, amount, block)?;
+                self.env().emit_event(DelegateVotesChanged {
+                    delegate,
+                    previous_votes,
+                    new_votes,
+                });
+            }
+            Ok(())
+        }
+
+        pub fn _inflate_cap(&mut self) -> Result<(), CappedInflationError> {
             self.capped_inflation
                 .inflate(self.env().block_timestamp())?;
             self.env().emit_event(CapUpdated {
@@ -81,6 +296,7 @@ pub mod abax_token {
         // inflates the cap
         // increases the cap by the `amount`
         // increases the inflation rate  by the 10% of the `amount` per year
+        // the increased rate decays with the inflation schedule
         #[ink(message)]
         fn generate(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
             self._ensure_has_role(GENERATOR, Some(self.env().caller()))?;
@@ -101,14 +317,687 @@ pub mod abax_token {
         fn cap(&self) -> Balance {
             self.capped_inflation.cap()
         }
+
+        #[ink(message)]
+        fn current_cap(&self) -> Balance {
+            // with a regressed clock the cap isn't inflated
+            self.capped_inflation
+                .projected_cap_at(self.env().block_timestamp())
+                .unwrap_or_else(|_| self.capped_inflation.cap())
+        }
+
+        #[ink(message)]
+        fn mintable_headroom(&self) -> Balance {
+            self.current_cap().saturating_sub(self._total_supply())
+        }
+
+        #[ink(message)]
+        fn last_cap_update(&self) -> Timestamp {
+            self.capped_inflation.last_cap_update()
+        }
+
+        #[ink(message)]
+        fn token_info(&self) -> TokenInfo {
+            TokenInfo {
+                name: self.metadata.name.get().unwrap_or_default(),
+                symbol: self.metadata.symbol.get().unwrap_or_default(),
+                decimals: self.metadata.decimals.get().unwrap_or_default(),
+                total_supply: self._total_supply(),
+                total_burned: self.capped_inflation.total_burned(),
+                cap: self.capped_inflation.cap(),
+                current_cap: self.current_cap(),
+                mintable_headroom: self.mintable_headroom(),
+                inflation_rate_per_milisecond: self
+                    .capped_inflation
+                    .inflation_rate_per_milisecond(),
+                last_cap_update: self.capped_inflation.last_cap_update(),
+                schedule: self.capped_inflation.schedule(),
+            }
+        }
+
+        #[ink(message)]
+        fn projected_cap_at(&self, timestamp: Timestamp) -> Result<Balance, PSP22Error> {
+            Ok(self.capped_inflation.projected_cap_at(timestamp)?)
+        }
+
+        #[ink(message)]
+        fn inflation_schedule(&self) -> InflationSchedule {
+            self.capped_inflation.schedule()
+        }
+
+        #[ink(message)]
+        fn set_inflation_schedule(
+            &mut self,
+            halving_period: Timestamp,
+            terminal_rate_per_milisecond: Balance,
+        ) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            let schedule = InflationSchedule {
+                start: self.env().block_timestamp(),
+                halving_period,
+                terminal_rate_per_milisecond,
+            };
+            schedule.validate()?;
+            self._inflate_cap()?;
+            self.capped_inflation.set_schedule(&schedule);
+            self.env().emit_event(InflationScheduleChanged { schedule });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn sanitize_clock_regression(&self) -> bool {
+            self.capped_inflation.sanitize_clock_regression()
+        }
+
+        #[ink(message)]
+        fn set_sanitize_clock_regression(&mut self, sanitize: bool) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            self.capped_inflation
+                .set_sanitize_clock_regression(sanitize);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn total_burned(&self) -> Balance {
+            self.capped_inflation.total_burned()
+        }
+
+        #[ink(message)]
+        fn burn_from_restricted(&self) -> bool {
+            self.capped_inflation.burn_from_restricted()
+        }
+
+        #[ink(message)]
+        fn set_burn_from_restricted(&mut self, restricted: bool) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            self.capped_inflation.set_burn_from_restricted(restricted);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn set_chain_id(&mut self, chain_id: Hash) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            self.permit.set_chain_id(&chain_id);
+            Ok(())
+        }
+    }
+
+    impl PSP22Permit for AbaxTokenContract {
+        #[ink(message)]
+        fn permit(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: Balance,
+            deadline: Timestamp,
+            signature: [u8; 65],
+        ) -> Result<(), PSP22Error> {
+            if self.env().block_timestamp() > deadline {
+                return Err(PSP22Error::Custom("PermitExpired".into()));
+            }
+            let nonce = self.permit.nonce_of(&owner);
+            let message_hash = permit_message_hash(
+                &self.domain_separator(),
+                &owner,
+                &spender,
+                &value,
+                nonce,
+                &deadline,
+            );
+            if recover_signer(&signature, &message_hash) != Some(owner) {
+                return Err(PSP22Error::Custom("InvalidSignature".into()));
+            }
+            self.permit.use_nonce(&owner)?;
+            self._approve(&owner, &spender, &value)
+        }
+
+        #[ink(message)]
+        fn nonces(&self, owner: AccountId) -> u64 {
+            self.permit.nonce_of(&owner)
+        }
+
+        #[ink(message)]
+        fn domain_separator(&self) -> Hash {
+            self.permit.domain_separator(&self.env().account_id())
+        }
     }
 
     impl PSP22Mintable for AbaxTokenContract {
         #[ink(message)]
         fn mint(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-            self._ensure_has_role(MINTER, Some(self.env().caller()))?;
+            let caller = self.env().caller();
+            self._ensure_has_role(MINTER, Some(caller))?;
+            self.minter_allowance
+                .use_allowance(&caller, amount, self.env().block_timestamp())?;
             self._inflate_cap()?;
             self._mint_to(&to, &amount)
         }
     }
+
+    impl AbaxTokenMinterAllowance for AbaxTokenContract {
+        #[ink(message)]
+        fn minter_allowance(&self, minter: AccountId) -> Option<MinterAllowance> {
+            self.minter_allowance.allowance_of(&minter)
+        }
+
+        #[ink(message)]
+        fn remaining_minter_allowance(&self, minter: AccountId) -> Option<Balance> {
+            self.minter_allowance
+                .allowance_of(&minter)
+                .map(|allowance| allowance.remaining(self.env().block_timestamp()))
+        }
+
+        #[ink(message)]
+        fn set_minter_allowance(
+            &mut self,
+            minter: AccountId,
+            window_duration: Timestamp,
+            max_per_window: Balance,
+            lifetime_budget: Balance,
+        ) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            self.minter_allowance.set_allowance(
+                &minter,
+                window_duration,
+                max_per_window,
+                lifetime_budget,
+                self.env().block_timestamp(),
+            )?;
+            self.env().emit_event(MinterAllowanceSet {
+                minter,
+                window_duration,
+                max_per_window,
+                lifetime_budget,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn remove_minter_allowance(&mut self, minter: AccountId) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            self.minter_allowance.remove_allowance(&minter);
+            self.env().emit_event(MinterAllowanceRemoved { minter });
+            Ok(())
+        }
+    }
+
+    impl PSP22Votes for AbaxTokenContract {
+        #[ink(message)]
+        fn delegates(&self, account: AccountId) -> Option<AccountId> {
+            self.votes.delegate_of(&account)
+        }
+
+        #[ink(message)]
+        fn get_votes(&self, account: AccountId) -> Balance {
+            self.votes.votes_of(&account)
+        }
+
+        #[ink(message)]
+        fn get_past_votes(
+            &self,
+            account: AccountId,
+            block: BlockNumber,
+        ) -> Result<Balance, PSP22Error> {
+            if block >= self.env().block_number() {
+                return Err(PSP22Error::Custom("VO:FutureLookup".into()));
+            }
+            Ok(self.votes.past_votes_of(&account, block))
+        }
+
+        #[ink(message)]
+        fn get_past_total_supply(&self, block: BlockNumber) -> Result<Balance, PSP22Error> {
+            if block >= self.env().block_number() {
+                return Err(PSP22Error::Custom("VO:FutureLookup".into()));
+            }
+            Ok(self.votes.past_total_supply(block))
+        }
+
+        #[ink(message)]
+        fn num_checkpoints(&self, account: AccountId) -> u32 {
+            self.votes.checkpoints_count(&account)
+        }
+
+        #[ink(message)]
+        fn delegate(&mut self, delegatee: Option<AccountId>) -> Result<(), PSP22Error> {
+            let delegator = self.env().caller();
+            let from_delegate = self.votes.delegate_of(&delegator);
+            self.votes.set_delegate(&delegator, &delegatee);
+            self.env().emit_event(DelegateChanged {
+                delegator,
+                from_delegate,
+                to_delegate: delegatee,
+            });
+            let balance = self._balance_of(&delegator);
+            self._move_delegate_votes(from_delegate, delegatee, balance)?;
+            Ok(())
+        }
+    }
+
+    impl AbaxTokenReceivers for AbaxTokenContract {
+        #[ink(message)]
+        fn is_hook_receiver(&self, account: AccountId) -> bool {
+            self.receivers.is_receiver(&account)
+        }
+
+        #[ink(message)]
+        fn set_hook_receiver(
+            &mut self,
+            account: AccountId,
+            allowed: bool,
+        ) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            self.receivers.set_receiver(&account, allowed);
+            self.env().emit_event(HookReceiverSet { account, allowed });
+            Ok(())
+        }
+    }
+
+    impl PSP22Batch for AbaxTokenContract {
+        #[ink(message)]
+        fn transfer_batch(
+            &mut self,
+            transfers: Vec<(AccountId, Balance)>,
+        ) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            for (to, amount) in transfers {
+                self._transfer(&caller, &to, &amount)?;
+                self._notify_receiver(caller, caller, to, amount, Vec::new())?;
+            }
+            Ok(())
+        }
+    }
+
+    impl AbaxTokenAirdrop for AbaxTokenContract {
+        #[ink(message)]
+        fn set_airdrop_root(&mut self, root: Hash) -> Result<(), PSP22Error> {
+            self._ensure_has_role(DEFAULT_ADMIN_ROLE, Some(self.env().caller()))?;
+            let round = self.airdrop.set_root(&root)?;
+            self.env().emit_event(AirdropRootSet { round, root });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn airdrop_root(&self) -> Option<(u32, Hash)> {
+            self.airdrop.root()
+        }
+
+        #[ink(message)]
+        fn is_claimed(&self, index: u32) -> bool {
+            match self.airdrop.root() {
+                Some((round, _)) => self.airdrop.is_claimed(round, index),
+                None => false,
+            }
+        }
+
+        #[ink(message)]
+        fn claim(
+            &mut self,
+            index: u32,
+            amount: Balance,
+            proof: Vec<Hash>,
+        ) -> Result<(), PSP22Error> {
+            let account = self.env().caller();
+            let (round, root) = self
+                .airdrop
+                .root()
+                .ok_or(PSP22Error::Custom("AD:NoAirdrop".into()))?;
+            if self.airdrop.is_claimed(round, index) {
+                return Err(PSP22Error::Custom("AD:AlreadyClaimed".into()));
+            }
+            if !verify_proof(&root, hash_leaf(index, &account, &amount), &proof) {
+                return Err(PSP22Error::Custom("AD:InvalidProof".into()));
+            }
+            self.airdrop.set_claimed(round, index);
+            // `caller` receives the tokens itself, so it isn't notified
+            self._transfer(&self.env().account_id(), &account, &amount)?;
+            self.env().emit_event(AirdropClaimed {
+                round,
+                index,
+                account,
+                amount,
+            });
+            Ok(())
+        }
+    }
+
+    impl PSP22Burnable for AbaxTokenContract {
+        // burns `amount` of tokens of `from`
+        // burning tokens of another account requires allowance and, if restricted, BURNER role
+        // lowers the cap by the `amount` so the burned tokens can't be minted again
+        #[ink(message)]
+        fn burn(&mut self, from: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            if from != caller {
+                if self.capped_inflation.burn_from_restricted() {
+                    self._ensure_has_role(BURNER, Some(caller))?;
+                }
+                self._decrease_allowance_from_to(&from, &caller, &amount)?;
+            }
+            self._burn_from(&from, &amount)?;
+            self.capped_inflation.record_burn(amount)?;
+            self.env().emit_event(Burned {
+                from,
+                burner: caller,
+                amount,
+            });
+            self.env().emit_event(CapUpdated {
+                cap: self.capped_inflation.cap(),
+            });
+            Ok(())
+        }
+    }
+    #[cfg(test)]
+    mod tests {
+        use ink::env::{
+            test::{advance_block, set_caller},
+            DefaultEnvironment,
+        };
+        use pendzl::contracts::psp22::PSP22;
+
+        use super::*;
+
+        const ALICE: [u8; 32] = [1; 32];
+        const BOB: [u8; 32] = [2; 32];
+        const CHARLIE: [u8; 32] = [3; 32];
+
+        fn token() -> AbaxTokenContract {
+            set_caller::<DefaultEnvironment>(ALICE.into());
+            let mut token = AbaxTokenContract::new(
+                String::from("ABAX"),
+                String::from("ABAX"),
+                12,
+                Hash::from([7; 32]),
+            )
+            .unwrap();
+            // minted without the cap check
+            token._update(None, Some(&ALICE.into()), &100).unwrap();
+            token
+        }
+
+        #[ink::test]
+        fn tokens_carry_votes_only_when_delegated() {
+            let mut token = token();
+            assert_eq!(token.get_votes(ALICE.into()), 0);
+
+            token.delegate(Some(BOB.into())).unwrap();
+            assert_eq!(token.delegates(ALICE.into()), Some(BOB.into()));
+            assert_eq!(token.get_votes(BOB.into()), 100);
+
+            token.delegate(Some(ALICE.into())).unwrap();
+            assert_eq!(token.get_votes(BOB.into()), 0);
+            assert_eq!(token.get_votes(ALICE.into()), 100);
+
+            token.delegate(None).unwrap();
+            assert_eq!(token.get_votes(ALICE.into()), 0);
+        }
+
+        #[ink::test]
+        fn transfers_move_votes_between_delegates() {
+            let mut token = token();
+            token.delegate(Some(BOB.into())).unwrap();
+            set_caller::<DefaultEnvironment>(CHARLIE.into());
+            token.delegate(Some(CHARLIE.into())).unwrap();
+
+            set_caller::<DefaultEnvironment>(ALICE.into());
+            token.transfer(CHARLIE.into(), 30, Vec::new()).unwrap();
+            assert_eq!(token.get_votes(BOB.into()), 70);
+            assert_eq!(token.get_votes(CHARLIE.into()), 30);
+        }
+
+        #[ink::test]
+        fn past_votes_and_total_supply_are_checkpointed() {
+            let mut token = token();
+            token.delegate(Some(BOB.into())).unwrap();
+            let block = ink::env::block_number::<DefaultEnvironment>();
+            assert_eq!(
+                token.get_past_votes(BOB.into(), block),
+                Err(PSP22Error::Custom("VO:FutureLookup".into()))
+            );
+
+            advance_block::<DefaultEnvironment>();
+            token._update(None, Some(&ALICE.into()), &50).unwrap();
+            advance_block::<DefaultEnvironment>();
+
+            assert_eq!(token.get_past_votes(BOB.into(), block), Ok(100));
+            assert_eq!(token.get_past_votes(BOB.into(), block + 1), Ok(150));
+            assert_eq!(token.get_past_total_supply(block), Ok(100));
+            assert_eq!(token.get_past_total_supply(block + 1), Ok(150));
+            assert_eq!(token.num_checkpoints(BOB.into()), 2);
+        }
+
+        #[ink::test]
+        fn transfers_to_accounts_that_are_not_hook_receivers_are_not_notified() {
+            let mut token = token();
+            token.transfer(BOB.into(), 30, Vec::from([1])).unwrap();
+            assert_eq!(token.balance_of(BOB.into()), 30);
+        }
+
+        #[ink::test]
+        fn hook_receiver_pulling_tokens_is_not_notified() {
+            let mut token = token();
+            // e.g. the governor pulling the deposited tokens in `deposit`
+            token.set_hook_receiver(BOB.into(), true).unwrap();
+            token.approve(BOB.into(), 30).unwrap();
+
+            set_caller::<DefaultEnvironment>(BOB.into());
+            token
+                .transfer_from(ALICE.into(), BOB.into(), 30, Vec::new())
+                .unwrap();
+            assert_eq!(token.balance_of(BOB.into()), 30);
+            assert_eq!(token.balance_of(ALICE.into()), 70);
+        }
+
+        #[ink::test]
+        fn transfer_batch_to_accounts_that_are_not_hook_receivers_is_not_notified() {
+            let mut token = token();
+            token
+                .transfer_batch(Vec::from([(BOB.into(), 30), (CHARLIE.into(), 20)]))
+                .unwrap();
+            assert_eq!(token.balance_of(BOB.into()), 30);
+            assert_eq!(token.balance_of(CHARLIE.into()), 20);
+        }
+
+        // the notification is a contract call, which isn't supported off-chain
+        #[ink::test]
+        #[should_panic(expected = "off-chain environment does not support contract invocation")]
+        fn transfer_batch_notifies_hook_receivers() {
+            let mut token = token();
+            token.set_hook_receiver(CHARLIE.into(), true).unwrap();
+            let _ = token.transfer_batch(Vec::from([(BOB.into(), 30), (CHARLIE.into(), 20)]));
+        }
+
+        #[ink::test]
+        fn hook_receiver_claiming_airdrop_is_not_notified() {
+            let mut token = token();
+            let contract = ink::env::account_id::<DefaultEnvironment>();
+            token._update(None, Some(&contract), &50).unwrap();
+            token.set_hook_receiver(BOB.into(), true).unwrap();
+            token
+                .set_airdrop_root(hash_leaf(0, &BOB.into(), &50))
+                .unwrap();
+
+            set_caller::<DefaultEnvironment>(BOB.into());
+            token.claim(0, 50, Vec::new()).unwrap();
+            assert_eq!(token.balance_of(BOB.into()), 50);
+        }
+
+        #[ink::test]
+        fn set_inflation_schedule_validates_schedule() {
+            let mut token = token();
+            for (halving_period, terminal_rate) in [(0, 1), (1, 0), (MINIMUM_HALVING_PERIOD - 1, 0)]
+            {
+                assert_eq!(
+                    token.set_inflation_schedule(halving_period, terminal_rate),
+                    Err(PSP22Error::Custom("CI:InvalidInflationSchedule".into()))
+                );
+            }
+            assert_eq!(token.inflation_schedule(), InflationSchedule::default());
+
+            token.set_inflation_schedule(0, 0).unwrap();
+            token
+                .set_inflation_schedule(MINIMUM_HALVING_PERIOD, 1)
+                .unwrap();
+            assert_eq!(
+                token.inflation_schedule().halving_period,
+                MINIMUM_HALVING_PERIOD
+            );
+        }
+
+        #[ink::test]
+        fn mint_is_limited_by_minter_allowance() {
+            let mut token = token();
+            token.capped_inflation.increase_cap(1_000).unwrap();
+            token._grant_role(MINTER, Some(BOB.into())).unwrap();
+
+            set_caller::<DefaultEnvironment>(BOB.into());
+            assert_eq!(
+                token.mint(CHARLIE.into(), 10),
+                Err(PSP22Error::Custom("MA:NoAllowance".into()))
+            );
+
+            set_caller::<DefaultEnvironment>(ALICE.into());
+            token
+                .set_minter_allowance(BOB.into(), 1_000, 50, 60)
+                .unwrap();
+
+            set_caller::<DefaultEnvironment>(BOB.into());
+            token.mint(CHARLIE.into(), 50).unwrap();
+            assert_eq!(
+                token.mint(CHARLIE.into(), 1),
+                Err(PSP22Error::Custom("MA:WindowLimitExceeded".into()))
+            );
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            assert_eq!(
+                token.mint(CHARLIE.into(), 20),
+                Err(PSP22Error::Custom("MA:LifetimeBudgetExceeded".into()))
+            );
+            token.mint(CHARLIE.into(), 10).unwrap();
+            assert_eq!(token.balance_of(CHARLIE.into()), 60);
+
+            set_caller::<DefaultEnvironment>(ALICE.into());
+            token.remove_minter_allowance(BOB.into()).unwrap();
+            set_caller::<DefaultEnvironment>(BOB.into());
+            assert_eq!(
+                token.mint(CHARLIE.into(), 0),
+                Err(PSP22Error::Custom("MA:NoAllowance".into()))
+            );
+        }
+
+        const DEADLINE: Timestamp = 1_000;
+
+        /// Account of the ECDSA key derived from `seed` and the key itself.
+        fn signer(seed: u8) -> (AccountId, secp256k1::SecretKey) {
+            let secret_key = secp256k1::SecretKey::from_slice(&[seed; 32]).unwrap();
+            let public_key = secret_key.public_key(&secp256k1::Secp256k1::new());
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(
+                &public_key.serialize(),
+                &mut account,
+            );
+            (account.into(), secret_key)
+        }
+
+        fn sign(secret_key: &secp256k1::SecretKey, message_hash: &Hash) -> [u8; 65] {
+            let message_hash: [u8; 32] = (*message_hash).into();
+            let (recovery_id, compact) = secp256k1::Secp256k1::new()
+                .sign_ecdsa_recoverable(&secp256k1::Message::from_digest(message_hash), secret_key)
+                .serialize_compact();
+            let mut signature = [0u8; 65];
+            signature[..64].copy_from_slice(&compact);
+            signature[64] = recovery_id.to_i32() as u8;
+            signature
+        }
+
+        fn permit_signature(
+            token: &AbaxTokenContract,
+            owner: &AccountId,
+            secret_key: &secp256k1::SecretKey,
+            value: Balance,
+        ) -> [u8; 65] {
+            let message_hash = permit_message_hash(
+                &token.domain_separator(),
+                owner,
+                &BOB.into(),
+                &value,
+                token.nonces(*owner),
+                &DEADLINE,
+            );
+            sign(secret_key, &message_hash)
+        }
+
+        #[ink::test]
+        fn permit_approves_spender() {
+            let mut token = token();
+            let (owner, secret_key) = signer(1);
+            let signature = permit_signature(&token, &owner, &secret_key, 50);
+
+            token
+                .permit(owner, BOB.into(), 50, DEADLINE, signature)
+                .unwrap();
+            assert_eq!(token.allowance(owner, BOB.into()), 50);
+            assert_eq!(token.nonces(owner), 1);
+        }
+
+        #[ink::test]
+        fn permit_fails_after_deadline() {
+            let mut token = token();
+            let (owner, secret_key) = signer(1);
+            let signature = permit_signature(&token, &owner, &secret_key, 50);
+
+            ink::env::test::set_block_timestamp::<DefaultEnvironment>(DEADLINE + 1);
+            assert_eq!(
+                token.permit(owner, BOB.into(), 50, DEADLINE, signature),
+                Err(PSP22Error::Custom("PermitExpired".into()))
+            );
+            assert_eq!(token.allowance(owner, BOB.into()), 0);
+            assert_eq!(token.nonces(owner), 0);
+        }
+
+        #[ink::test]
+        fn permit_fails_with_signature_of_other_account() {
+            let mut token = token();
+            let (owner, _) = signer(1);
+            let (_, other_secret_key) = signer(2);
+            let signature = permit_signature(&token, &owner, &other_secret_key, 50);
+
+            assert_eq!(
+                token.permit(owner, BOB.into(), 50, DEADLINE, signature),
+                Err(PSP22Error::Custom("InvalidSignature".into()))
+            );
+            assert_eq!(token.allowance(owner, BOB.into()), 0);
+        }
+
+        #[ink::test]
+        fn permit_fails_with_signature_of_other_value() {
+            let mut token = token();
+            let (owner, secret_key) = signer(1);
+            let signature = permit_signature(&token, &owner, &secret_key, 50);
+
+            assert_eq!(
+                token.permit(owner, BOB.into(), 51, DEADLINE, signature),
+                Err(PSP22Error::Custom("InvalidSignature".into()))
+            );
+            assert_eq!(token.allowance(owner, BOB.into()), 0);
+        }
+
+        #[ink::test]
+        fn permit_cant_be_replayed() {
+            let mut token = token();
+            let (owner, secret_key) = signer(1);
+            let signature = permit_signature(&token, &owner, &secret_key, 50);
+            token
+                .permit(owner, BOB.into(), 50, DEADLINE, signature)
+                .unwrap();
+            // the owner revokes the approval
+            set_caller::<DefaultEnvironment>(owner);
+            token.approve(BOB.into(), 0).unwrap();
+
+            assert_eq!(
+                token.permit(owner, BOB.into(), 50, DEADLINE, signature),
+                Err(PSP22Error::Custom("InvalidSignature".into()))
+            );
+            assert_eq!(token.allowance(owner, BOB.into()), 0);
+            assert_eq!(token.nonces(owner), 1);
+        }
+    }
 }
@@ -3,6 +3,8 @@ use pendzl::{
     traits::{Balance, Timestamp},
 };
 
+use crate::modules::capped_inflation::{errors::CappedInflationError, structs::InflationSchedule};
+
 #[derive(Default, Debug)]
 #[pendzl::storage_item]
 pub struct CappedInflation {
@@ -12,6 +14,14 @@ pub struct CappedInflation {
     inflation_rate_per_milisecond: Balance,
     #[lazy]
     last_cap_update: Timestamp,
+    #[lazy]
+    total_burned: Balance,
+    #[lazy]
+    burn_from_restricted: bool,
+    #[lazy]
+    schedule: InflationSchedule,
+    #[lazy]
+    sanitize_clock_regression: bool,
 }
 
 impl CappedInflation {
@@ -26,6 +36,32 @@ impl CappedInflation {
         Ok(())
     }
 
+    /// Lowers the cap by the burned `amount` so that burned tokens can't be minted again.
+    pub fn record_burn(&mut self, amount: Balance) -> Result<(), MathError> {
+        let cap = self.cap.get().unwrap_or(0);
+        self.cap
+            .set(&(cap.checked_sub(amount).ok_or(MathError::Underflow)?));
+        let total_burned = self.total_burned();
+        self.total_burned.set(
+            &(total_burned
+                .checked_add(amount)
+                .ok_or(MathError::Overflow)?),
+        );
+        Ok(())
+    }
+
+    pub fn total_burned(&self) -> Balance {
+        self.total_burned.get().unwrap_or(0)
+    }
+
+    pub fn burn_from_restricted(&self) -> bool {
+        self.burn_from_restricted.get().unwrap_or(false)
+    }
+
+    pub fn set_burn_from_restricted(&mut self, restricted: bool) {
+        self.burn_from_restricted.set(&restricted);
+    }
+
     pub fn inflation_rate_per_milisecond(&self) -> Balance {
         self.inflation_rate_per_milisecond.get().unwrap_or(0)
     }
@@ -51,20 +87,194 @@ impl CappedInflation {
         self.last_cap_update.get().unwrap_or(0)
     }
 
-    pub fn inflate(&mut self, now: Timestamp) -> Result<(), MathError> {
+    pub fn schedule(&self) -> InflationSchedule {
+        self.schedule.get().unwrap_or_default()
+    }
+
+    /// Sets the `schedule`. Should be called right after `inflate` so that the new schedule applies only from now on.
+    pub fn set_schedule(&mut self, schedule: &InflationSchedule) {
+        self.schedule.set(schedule);
+    }
+
+    /// Returns whether `inflate` with `now` before the last cap update is a no-op instead of an error.
+    pub fn sanitize_clock_regression(&self) -> bool {
+        self.sanitize_clock_regression.get().unwrap_or(false)
+    }
+
+    pub fn set_sanitize_clock_regression(&mut self, sanitize: bool) {
+        self.sanitize_clock_regression.set(&sanitize);
+    }
+
+    /// Returns the cap at `timestamp` assuming that the inflation rate isn't increased meanwhile.
+    pub fn projected_cap_at(&self, timestamp: Timestamp) -> Result<Balance, CappedInflationError> {
+        if timestamp < self.last_cap_update() {
+            return Err(CappedInflationError::TimestampBeforeLastCapUpdate);
+        }
+        let (increase_cap_by, _) = self.schedule().inflation_between(
+            self.inflation_rate_per_milisecond(),
+            self.last_cap_update(),
+            timestamp,
+        )?;
+        Ok(self
+            .cap()
+            .checked_add(increase_cap_by)
+            .ok_or(MathError::Overflow)?)
+    }
+
+    /// Increases the cap by the inflation since the last cap update.
+    ///
+    /// If `now` is before the last cap update, returns `TimestampBeforeLastCapUpdate`
+    /// or, if `sanitize_clock_regression` is set, leaves the cap untouched.
+    pub fn inflate(&mut self, now: Timestamp) -> Result<(), CappedInflationError> {
         if now < self.last_cap_update() {
-            panic!("Invalid timestamp");
+            if self.sanitize_clock_regression() {
+                return Ok(());
+            }
+            return Err(CappedInflationError::TimestampBeforeLastCapUpdate);
         }
-        let time_diff = now
-            .checked_sub(self.last_cap_update.get().unwrap_or(0))
-            .ok_or(MathError::Underflow)? as u128;
-        let increase_cap_by = self
-            .inflation_rate_per_milisecond()
-            .checked_mul(time_diff)
-            .ok_or(MathError::Overflow)?;
+        let (increase_cap_by, rate) = self.schedule().inflation_between(
+            self.inflation_rate_per_milisecond(),
+            self.last_cap_update(),
+            now,
+        )?;
         self.increase_cap(increase_cap_by)?;
+        self.set_inflation_rate_per_milisecond(rate);
 
         self.last_cap_update.set(&now);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pendzl::contracts::psp22::PSP22Error;
+
+    use super::*;
+
+    const RATE: Balance = 1_000;
+
+    fn inflated_at(timestamp: Timestamp) -> CappedInflation {
+        let mut capped_inflation = CappedInflation::default();
+        capped_inflation.set_inflation_rate_per_milisecond(RATE);
+        capped_inflation.inflate(timestamp).unwrap();
+        capped_inflation
+    }
+
+    #[ink::test]
+    fn inflate_increases_cap_by_rate_times_elapsed_time() {
+        let mut capped_inflation = inflated_at(100);
+        assert_eq!(capped_inflation.cap(), 100 * RATE);
+
+        assert_eq!(capped_inflation.inflate(150), Ok(()));
+        assert_eq!(capped_inflation.cap(), 150 * RATE);
+        assert_eq!(capped_inflation.last_cap_update(), 150);
+    }
+
+    #[ink::test]
+    fn inflate_before_last_cap_update_returns_error() {
+        let mut capped_inflation = inflated_at(100);
+
+        assert_eq!(
+            capped_inflation.inflate(99),
+            Err(CappedInflationError::TimestampBeforeLastCapUpdate)
+        );
+        assert_eq!(capped_inflation.cap(), 100 * RATE);
+        assert_eq!(capped_inflation.last_cap_update(), 100);
+        assert_eq!(
+            PSP22Error::from(CappedInflationError::TimestampBeforeLastCapUpdate),
+            PSP22Error::Custom("CI:TimestampBeforeLastCapUpdate".into())
+        );
+    }
+
+    #[ink::test]
+    fn sanitized_inflate_before_last_cap_update_is_noop() {
+        let mut capped_inflation = inflated_at(100);
+        capped_inflation.set_sanitize_clock_regression(true);
+
+        assert_eq!(capped_inflation.inflate(99), Ok(()));
+        assert_eq!(capped_inflation.cap(), 100 * RATE);
+        assert_eq!(capped_inflation.last_cap_update(), 100);
+
+        // the cap keeps growing from the last cap update once the clock catches up
+        assert_eq!(capped_inflation.inflate(120), Ok(()));
+        assert_eq!(capped_inflation.cap(), 120 * RATE);
+    }
+
+    #[ink::test]
+    fn projected_cap_before_last_cap_update_returns_error_in_both_modes() {
+        let mut capped_inflation = inflated_at(100);
+        assert_eq!(
+            capped_inflation.projected_cap_at(99),
+            Err(CappedInflationError::TimestampBeforeLastCapUpdate)
+        );
+        capped_inflation.set_sanitize_clock_regression(true);
+        assert_eq!(
+            capped_inflation.projected_cap_at(99),
+            Err(CappedInflationError::TimestampBeforeLastCapUpdate)
+        );
+    }
+
+    const HALVING_PERIOD: Timestamp = 100;
+
+    /// Lazy fields of every instance share the same storage cells, so the cap and the last cap update are reset too.
+    fn with_schedule(halving_period: Timestamp, terminal_rate: Balance) -> CappedInflation {
+        let mut capped_inflation = CappedInflation::default();
+        capped_inflation.cap.set(&0);
+        capped_inflation.last_cap_update.set(&0);
+        capped_inflation.set_inflation_rate_per_milisecond(RATE);
+        capped_inflation.set_schedule(&InflationSchedule {
+            start: 0,
+            halving_period,
+            terminal_rate_per_milisecond: terminal_rate,
+        });
+        capped_inflation
+    }
+
+    #[ink::test]
+    fn inflate_halves_rate_every_halving_period() {
+        let mut capped_inflation = with_schedule(HALVING_PERIOD, 0);
+
+        assert_eq!(capped_inflation.inflate(350), Ok(()));
+        assert_eq!(
+            capped_inflation.cap(),
+            100 * RATE + 100 * RATE / 2 + 100 * RATE / 4 + 50 * RATE / 8
+        );
+        assert_eq!(capped_inflation.inflation_rate_per_milisecond(), RATE / 8);
+    }
+
+    #[ink::test]
+    fn one_long_inflate_equals_many_short_ones_across_halvings() {
+        let mut once = with_schedule(HALVING_PERIOD, 0);
+        assert_eq!(once.inflate(1_000), Ok(()));
+        let (cap, rate) = (once.cap(), once.inflation_rate_per_milisecond());
+
+        let mut many = with_schedule(HALVING_PERIOD, 0);
+        for now in [7, 99, 100, 101, 250, 333, 700, 999, 1_000] {
+            assert_eq!(many.inflate(now), Ok(()));
+        }
+        assert_eq!(many.cap(), cap);
+        assert_eq!(many.inflation_rate_per_milisecond(), rate);
+    }
+
+    #[ink::test]
+    fn rate_is_not_halved_below_terminal_rate() {
+        let mut capped_inflation = with_schedule(HALVING_PERIOD, 300);
+
+        assert_eq!(capped_inflation.inflate(1_000), Ok(()));
+        // 1000 -> 500 -> 300 instead of 250
+        assert_eq!(
+            capped_inflation.cap(),
+            100 * RATE + 100 * RATE / 2 + 800 * 300
+        );
+        assert_eq!(capped_inflation.inflation_rate_per_milisecond(), 300);
+    }
+
+    #[ink::test]
+    fn zero_halving_period_keeps_inflation_linear() {
+        let mut capped_inflation = with_schedule(0, 0);
+
+        assert_eq!(capped_inflation.inflate(10 * HALVING_PERIOD), Ok(()));
+        assert_eq!(capped_inflation.cap(), 10 * HALVING_PERIOD as u128 * RATE);
+        assert_eq!(capped_inflation.inflation_rate_per_milisecond(), RATE);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod capped_infaltion_storage_field;
+pub mod errors;
 pub mod reserved;
+pub mod structs;
 pub mod traits;
@@ -0,0 +1,106 @@
+use ink::prelude::string::String;
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, Timestamp},
+};
+
+use crate::modules::capped_inflation::errors::CappedInflationError;
+
+/// The shortest halving period - one day. Shorter periods would decay the inflation almost immediately.
+pub const MINIMUM_HALVING_PERIOD: Timestamp = 24 * 60 * 60 * 1000;
+
+/// Decay of the inflation rate.
+/// Every `halving_period` counted from `start` the inflation rate is halved, but never below `terminal_rate_per_milisecond`.
+/// The rate that is already below the terminal rate isn't changed.
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct InflationSchedule {
+    /// time from which the halving periods are counted.
+    pub start: Timestamp,
+    /// duration of one halving epoch. 0 disables the decay.
+    pub halving_period: Timestamp,
+    /// inflation rate below which the rate isn't halved anymore.
+    pub terminal_rate_per_milisecond: Balance,
+}
+
+impl InflationSchedule {
+    /// Returns `InvalidInflationSchedule` if the halving is enabled with a period shorter than `MINIMUM_HALVING_PERIOD`
+    /// or the terminal rate is set while the halving is disabled.
+    pub fn validate(&self) -> Result<(), CappedInflationError> {
+        let is_valid = if self.halving_period == 0 {
+            self.terminal_rate_per_milisecond == 0
+        } else {
+            self.halving_period >= MINIMUM_HALVING_PERIOD
+        };
+        if !is_valid {
+            return Err(CappedInflationError::InvalidInflationSchedule);
+        }
+        Ok(())
+    }
+
+    /// Returns the increase of the cap between `from` and `to` and the inflation rate at `to`,
+    /// given the inflation `rate` at `from`.
+    ///
+    /// The halving boundaries are absolute, so the result doesn't depend on how the interval is split between updates.
+    /// The rate reaches the terminal rate (or 0) after at most 128 halvings, after which the growth is linear.
+    pub fn inflation_between(
+        &self,
+        mut rate: Balance,
+        mut from: Timestamp,
+        to: Timestamp,
+    ) -> Result<(Balance, Balance), MathError> {
+        let mut increase: Balance = 0;
+        while self.halving_period > 0 && rate > self.terminal_rate_per_milisecond {
+            let next_halving = self.next_halving_after(from)?;
+            if next_halving > to {
+                break;
+            }
+            increase = increase
+                .checked_add(linear_increase(rate, from, next_halving)?)
+                .ok_or(MathError::Overflow)?;
+            rate = (rate / 2).max(self.terminal_rate_per_milisecond);
+            from = next_halving;
+        }
+        increase = increase
+            .checked_add(linear_increase(rate, from, to)?)
+            .ok_or(MathError::Overflow)?;
+        Ok((increase, rate))
+    }
+
+    fn next_halving_after(&self, timestamp: Timestamp) -> Result<Timestamp, MathError> {
+        let epochs_passed = timestamp.saturating_sub(self.start) / self.halving_period;
+        epochs_passed
+            .checked_add(1)
+            .and_then(|epochs| epochs.checked_mul(self.halving_period))
+            .and_then(|duration| self.start.checked_add(duration))
+            .ok_or(MathError::Overflow)
+    }
+}
+
+fn linear_increase(rate: Balance, from: Timestamp, to: Timestamp) -> Result<Balance, MathError> {
+    let time_diff = to.checked_sub(from).ok_or(MathError::Underflow)? as u128;
+    rate.checked_mul(time_diff).ok_or(MathError::Overflow)
+}
+
+/// Metadata, supply and cap of the token at once.
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct TokenInfo {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: u8,
+    pub total_supply: Balance,
+    pub total_burned: Balance,
+    /// cap at the last cap update.
+    pub cap: Balance,
+    /// cap including the inflation since the last cap update.
+    pub current_cap: Balance,
+    /// amount that can be minted now.
+    pub mintable_headroom: Balance,
+    pub inflation_rate_per_milisecond: Balance,
+    pub last_cap_update: Timestamp,
+    pub schedule: InflationSchedule,
+}
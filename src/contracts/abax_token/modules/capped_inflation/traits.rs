@@ -1,9 +1,20 @@
-use pendzl::traits::{AccountId, Balance};
+use ink::primitives::Hash;
+use pendzl::traits::{AccountId, Balance, Timestamp};
+
+use crate::modules::capped_inflation::structs::{InflationSchedule, TokenInfo};
 
 use pendzl::contracts::psp22::PSP22Error;
 
 #[ink::trait_definition]
 pub trait AbaxToken {
+    /// Mints `amount` to `to` and increases the cap by `amount`.
+    ///
+    /// Unlike `mint` it isn't limited by minter allowances: the GENERATOR role is granted only to the TGE,
+    /// which bounds the generated amount by its own parameters, and the generated amount raises the cap
+    /// instead of using up the headroom left for minters.
+    ///
+    /// # Errors
+    /// Returns `AccessControlError` if `caller` doesn't have the GENERATOR role.
     #[ink(message)]
     fn generate(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 
@@ -12,4 +23,69 @@ pub trait AbaxToken {
 
     #[ink(message)]
     fn cap(&self) -> Balance;
+
+    /// Returns the cap including the inflation since the last cap update, without updating it.
+    #[ink(message)]
+    fn current_cap(&self) -> Balance;
+
+    /// Returns the amount that can be minted now - `current_cap` minus the total supply.
+    #[ink(message)]
+    fn mintable_headroom(&self) -> Balance;
+
+    #[ink(message)]
+    fn last_cap_update(&self) -> Timestamp;
+
+    /// Returns metadata, supply and cap of the token.
+    #[ink(message)]
+    fn token_info(&self) -> TokenInfo;
+
+    /// Returns the cap at `timestamp` assuming that the inflation rate isn't increased by `generate` meanwhile.
+    ///
+    /// # Errors
+    /// Returns `Custom("CI:TimestampBeforeLastCapUpdate")` if `timestamp` is before the last cap update.
+    /// Returns `MathError` if the cap overflows.
+    #[ink(message)]
+    fn projected_cap_at(&self, timestamp: Timestamp) -> Result<Balance, PSP22Error>;
+
+    #[ink(message)]
+    fn inflation_schedule(&self) -> InflationSchedule;
+
+    /// Inflates the cap and sets the inflation schedule with halving epochs counted from now.
+    ///
+    /// On success emits `InflationScheduleChanged` event.
+    ///
+    /// # Errors
+    /// Returns `Custom("CI:InvalidInflationSchedule")` if `halving_period` is shorter than `MINIMUM_HALVING_PERIOD`
+    /// or `terminal_rate_per_milisecond` is set while `halving_period` is 0.
+    #[ink(message)]
+    fn set_inflation_schedule(
+        &mut self,
+        halving_period: Timestamp,
+        terminal_rate_per_milisecond: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns whether inflating the cap with the block timestamp before the last cap update is a no-op.
+    /// Otherwise `mint` and `generate` fail with `Custom("CI:TimestampBeforeLastCapUpdate")`.
+    #[ink(message)]
+    fn sanitize_clock_regression(&self) -> bool;
+
+    /// Sets whether inflating the cap with the block timestamp before the last cap update is a no-op.
+    #[ink(message)]
+    fn set_sanitize_clock_regression(&mut self, sanitize: bool) -> Result<(), PSP22Error>;
+
+    /// Returns the total amount of burned tokens. Each burn lowers the cap by the burned amount.
+    #[ink(message)]
+    fn total_burned(&self) -> Balance;
+
+    /// Returns whether burning tokens of another account requires BURNER role (in addition to the allowance).
+    #[ink(message)]
+    fn burn_from_restricted(&self) -> bool;
+
+    /// Sets whether burning tokens of another account requires BURNER role.
+    #[ink(message)]
+    fn set_burn_from_restricted(&mut self, restricted: bool) -> Result<(), PSP22Error>;
+
+    /// Sets the chain id that is part of the permit domain separator. Invalidates all unused permits.
+    #[ink(message)]
+    fn set_chain_id(&mut self, chain_id: Hash) -> Result<(), PSP22Error>;
 }
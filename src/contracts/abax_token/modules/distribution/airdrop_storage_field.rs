@@ -0,0 +1,47 @@
+use ink::{primitives::Hash, storage::Mapping};
+use pendzl::math::errors::MathError;
+
+/// Merkle root airdrop. Publishing a new root starts a new round in which all leaves are claimable again.
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct AirdropData {
+    #[lazy]
+    round: u32,
+    #[lazy]
+    root: Option<Hash>,
+    claimed: Mapping<(u32, u32), ()>,
+}
+
+impl AirdropData {
+    /// Returns the current round and its root.
+    pub fn root(&self) -> Option<(u32, Hash)> {
+        self.root
+            .get()
+            .unwrap_or_default()
+            .map(|root| (self.round.get().unwrap_or_default(), root))
+    }
+
+    /// Publishes `root` as a new round. Returns the round.
+    pub fn set_root(&mut self, root: &Hash) -> Result<u32, MathError> {
+        let round = match self.root.get().unwrap_or_default() {
+            Some(_) => self
+                .round
+                .get()
+                .unwrap_or_default()
+                .checked_add(1)
+                .ok_or(MathError::Overflow)?,
+            None => 0,
+        };
+        self.round.set(&round);
+        self.root.set(&Some(*root));
+        Ok(round)
+    }
+
+    pub fn is_claimed(&self, round: u32, index: u32) -> bool {
+        self.claimed.contains((round, index))
+    }
+
+    pub fn set_claimed(&mut self, round: u32, index: u32) {
+        self.claimed.insert((round, index), &());
+    }
+}
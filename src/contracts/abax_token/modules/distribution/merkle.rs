@@ -0,0 +1,68 @@
+pub use ink::{
+    env::hash::{HashOutput, Sha2x256},
+    primitives::Hash,
+};
+use pendzl::traits::{AccountId, Balance};
+
+/// Returns the leaf of the airdrop Merkle tree that entitles `account` at `index` to `amount`.
+pub fn hash_leaf(index: u32, account: &AccountId, amount: &Balance) -> Hash {
+    sha2_256(&scale::Encode::encode(&(index, account, amount)))
+}
+
+/// Returns the parent of two nodes. Nodes are sorted before hashing so the proof doesn't carry the positions.
+pub fn hash_pair(a: &Hash, b: &Hash) -> Hash {
+    if a <= b {
+        sha2_256(&scale::Encode::encode(&(a, b)))
+    } else {
+        sha2_256(&scale::Encode::encode(&(b, a)))
+    }
+}
+
+/// Checks that `leaf` belongs to the tree with `root`.
+pub fn verify_proof(root: &Hash, leaf: Hash, proof: &[Hash]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling))
+        == *root
+}
+
+fn sha2_256(input: &[u8]) -> Hash {
+    let mut output = <Sha2x256 as HashOutput>::Type::default();
+    ink::env::hash_bytes::<Sha2x256>(input, &mut output);
+    output.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves() -> [Hash; 3] {
+        [
+            hash_leaf(0, &AccountId::from([1; 32]), &100),
+            hash_leaf(1, &AccountId::from([2; 32]), &200),
+            hash_leaf(2, &AccountId::from([3; 32]), &300),
+        ]
+    }
+
+    #[ink::test]
+    fn proofs_of_all_leaves_are_valid() {
+        let [a, b, c] = leaves();
+        let ab = hash_pair(&a, &b);
+        let root = hash_pair(&ab, &c);
+
+        assert!(verify_proof(&root, a, &[b, c]));
+        assert!(verify_proof(&root, b, &[a, c]));
+        assert!(verify_proof(&root, c, &[ab]));
+    }
+
+    #[ink::test]
+    fn proof_of_other_amount_is_invalid() {
+        let [a, b, c] = leaves();
+        let root = hash_pair(&hash_pair(&a, &b), &c);
+
+        let forged = hash_leaf(0, &AccountId::from([1; 32]), &101);
+        assert!(!verify_proof(&root, forged, &[b, c]));
+        assert!(!verify_proof(&root, a, &[c, b]));
+        assert!(!verify_proof(&root, a, &[]));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod airdrop_storage_field;
+pub mod merkle;
+pub mod traits;
@@ -0,0 +1,51 @@
+use ink::{prelude::vec::Vec, primitives::Hash};
+use pendzl::traits::{AccountId, Balance};
+
+use pendzl::contracts::psp22::PSP22Error;
+
+#[ink::trait_definition]
+pub trait PSP22Batch {
+    /// Transfers to each of `transfers` the corresponding amount of `caller` tokens.
+    /// Hook receivers are notified after their transfer with empty `data`.
+    ///
+    /// On success emits `Transfer` event for each of `transfers`.
+    ///
+    /// # Errors
+    /// Returns `InsufficientBalance` if `caller` doesn't have enough tokens. No transfer is made then.
+    #[ink(message)]
+    fn transfer_batch(&mut self, transfers: Vec<(AccountId, Balance)>) -> Result<(), PSP22Error>;
+}
+
+#[ink::trait_definition]
+pub trait AbaxTokenAirdrop {
+    /// Publishes `root` of the Merkle tree of `(index, account, amount)` leaves and starts a new airdrop round.
+    /// Airdropped tokens are paid from the balance of the token contract.
+    ///
+    /// On success emits `AirdropRootSet` event.
+    ///
+    /// # Errors
+    /// Returns `AccessControlError` if `caller` is not the admin.
+    #[ink(message)]
+    fn set_airdrop_root(&mut self, root: Hash) -> Result<(), PSP22Error>;
+
+    /// Returns the current airdrop round and its root.
+    #[ink(message)]
+    fn airdrop_root(&self) -> Option<(u32, Hash)>;
+
+    /// Returns whether the leaf at `index` was claimed in the current round.
+    #[ink(message)]
+    fn is_claimed(&self, index: u32) -> bool;
+
+    /// Claims `amount` of tokens for `caller` with the `proof` of the leaf `(index, caller, amount)`.
+    /// `caller` receives the tokens itself, so it isn't notified even if it is a hook receiver.
+    ///
+    /// On success emits `AirdropClaimed` and `Transfer` events.
+    ///
+    /// # Errors
+    /// Returns `Custom("AD:NoAirdrop")` if no root was published.
+    /// Returns `Custom("AD:AlreadyClaimed")` if the leaf at `index` was already claimed in the current round.
+    /// Returns `Custom("AD:InvalidProof")` if `proof` doesn't prove the leaf.
+    /// Returns `InsufficientBalance` if the token contract doesn't hold enough tokens.
+    #[ink(message)]
+    fn claim(&mut self, index: u32, amount: Balance, proof: Vec<Hash>) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,39 @@
+use pendzl::{contracts::psp22::PSP22Error, math::errors::MathError};
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum MinterAllowanceError {
+    MathError(MathError),
+    /// the window duration must be greater than 0.
+    ZeroWindowDuration,
+    /// minting would exceed the maximal amount of the window.
+    WindowLimitExceeded,
+    /// minting would exceed the lifetime budget of the minter.
+    LifetimeBudgetExceeded,
+    /// the minter has no allowance.
+    NoAllowance,
+}
+
+impl From<MathError> for MinterAllowanceError {
+    fn from(error: MathError) -> Self {
+        MinterAllowanceError::MathError(error)
+    }
+}
+
+impl From<MinterAllowanceError> for PSP22Error {
+    fn from(error: MinterAllowanceError) -> Self {
+        match error {
+            MinterAllowanceError::MathError(error) => error.into(),
+            MinterAllowanceError::ZeroWindowDuration => {
+                PSP22Error::Custom("MA:ZeroWindowDuration".into())
+            }
+            MinterAllowanceError::WindowLimitExceeded => {
+                PSP22Error::Custom("MA:WindowLimitExceeded".into())
+            }
+            MinterAllowanceError::LifetimeBudgetExceeded => {
+                PSP22Error::Custom("MA:LifetimeBudgetExceeded".into())
+            }
+            MinterAllowanceError::NoAllowance => PSP22Error::Custom("MA:NoAllowance".into()),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use ink::storage::Mapping;
+use pendzl::traits::{AccountId, Balance, Timestamp};
+
+use crate::modules::minter_allowance::{errors::MinterAllowanceError, structs::MinterAllowance};
+
+/// Allowances of minters. Minters without an allowance can't mint.
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct MinterAllowanceData {
+    allowances: Mapping<AccountId, MinterAllowance>,
+}
+
+impl MinterAllowanceData {
+    pub fn allowance_of(&self, minter: &AccountId) -> Option<MinterAllowance> {
+        self.allowances.get(minter)
+    }
+
+    /// Sets limits of `minter` at `now` keeping its usage.
+    pub fn set_allowance(
+        &mut self,
+        minter: &AccountId,
+        window_duration: Timestamp,
+        max_per_window: Balance,
+        lifetime_budget: Balance,
+        now: Timestamp,
+    ) -> Result<(), MinterAllowanceError> {
+        let mut allowance = self.allowance_of(minter).unwrap_or_default();
+        allowance.set_limits(window_duration, max_per_window, lifetime_budget, now)?;
+        self.allowances.insert(minter, &allowance);
+        Ok(())
+    }
+
+    pub fn remove_allowance(&mut self, minter: &AccountId) {
+        self.allowances.remove(minter);
+    }
+
+    /// Records minting of `amount` by `minter` at `now`.
+    pub fn use_allowance(
+        &mut self,
+        minter: &AccountId,
+        amount: Balance,
+        now: Timestamp,
+    ) -> Result<(), MinterAllowanceError> {
+        let mut allowance = self
+            .allowance_of(minter)
+            .ok_or(MinterAllowanceError::NoAllowance)?;
+        allowance.use_allowance(amount, now)?;
+        self.allowances.insert(minter, &allowance);
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod errors;
+pub mod minter_allowance_storage_field;
+pub mod structs;
+pub mod traits;
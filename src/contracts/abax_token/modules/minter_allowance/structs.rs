@@ -0,0 +1,197 @@
+use pendzl::{
+    math::{
+        errors::MathError,
+        operations::{mul_div, Rounding},
+    },
+    traits::{Balance, Timestamp},
+};
+
+use crate::modules::minter_allowance::errors::MinterAllowanceError;
+
+/// Limits of a minter together with its usage.
+///
+/// The window is rolling: the amount minted within the window decays linearly to 0 over `window_duration`.
+/// A minter can mint at most `max_per_window` at once and, on average, at most `max_per_window` per `window_duration`.
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct MinterAllowance {
+    /// duration over which the amount minted within the window decays to 0.
+    pub window_duration: Timestamp,
+    /// maximal amount that can be minted within the window.
+    pub max_per_window: Balance,
+    /// maximal amount that can be minted in total.
+    pub lifetime_budget: Balance,
+    /// timestamp at which `minted_in_window` was last updated.
+    pub updated_at: Timestamp,
+    /// amount minted within the window as of `updated_at`.
+    pub minted_in_window: Balance,
+    /// amount minted in total.
+    pub minted_total: Balance,
+}
+
+impl MinterAllowance {
+    /// Returns the amount minted within the window as of `now`.
+    pub fn minted_in_window_at(&self, now: Timestamp) -> Balance {
+        let elapsed = now.saturating_sub(self.updated_at);
+        if elapsed >= self.window_duration {
+            return 0;
+        }
+        // rounding up so that the decay never favours the minter.
+        // can not fail as the denominator is not 0 and the result is at most `minted_in_window`.
+        mul_div(
+            self.minted_in_window,
+            (self.window_duration - elapsed) as u128,
+            self.window_duration as u128,
+            Rounding::Up,
+        )
+        .unwrap_or(self.minted_in_window)
+    }
+
+    /// Returns the amount that can be minted at `now`.
+    pub fn remaining(&self, now: Timestamp) -> Balance {
+        self.max_per_window
+            .saturating_sub(self.minted_in_window_at(now))
+            .min(self.lifetime_budget.saturating_sub(self.minted_total))
+    }
+
+    /// Sets the limits at `now` keeping the usage.
+    pub fn set_limits(
+        &mut self,
+        window_duration: Timestamp,
+        max_per_window: Balance,
+        lifetime_budget: Balance,
+        now: Timestamp,
+    ) -> Result<(), MinterAllowanceError> {
+        if window_duration == 0 {
+            return Err(MinterAllowanceError::ZeroWindowDuration);
+        }
+        // the usage decayed so far is settled under the previous duration
+        self.minted_in_window = self.minted_in_window_at(now);
+        self.updated_at = now;
+        self.window_duration = window_duration;
+        self.max_per_window = max_per_window;
+        self.lifetime_budget = lifetime_budget;
+        Ok(())
+    }
+
+    /// Records minting of `amount` at `now`.
+    pub fn use_allowance(
+        &mut self,
+        amount: Balance,
+        now: Timestamp,
+    ) -> Result<(), MinterAllowanceError> {
+        let minted_total = self
+            .minted_total
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        if minted_total > self.lifetime_budget {
+            return Err(MinterAllowanceError::LifetimeBudgetExceeded);
+        }
+        let minted_in_window = self
+            .minted_in_window_at(now)
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        if minted_in_window > self.max_per_window {
+            return Err(MinterAllowanceError::WindowLimitExceeded);
+        }
+        self.updated_at = now;
+        self.minted_in_window = minted_in_window;
+        self.minted_total = minted_total;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Timestamp = 1000;
+    const MAX: Balance = 100;
+
+    fn allowance(lifetime_budget: Balance) -> MinterAllowance {
+        let mut allowance = MinterAllowance::default();
+        allowance
+            .set_limits(WINDOW, MAX, lifetime_budget, 0)
+            .unwrap();
+        allowance
+    }
+
+    #[test]
+    fn set_limits_rejects_zero_window_duration() {
+        let mut allowance = MinterAllowance::default();
+        assert_eq!(
+            allowance.set_limits(0, MAX, MAX, 0),
+            Err(MinterAllowanceError::ZeroWindowDuration)
+        );
+    }
+
+    #[test]
+    fn window_limit_is_enforced() {
+        let mut allowance = allowance(10 * MAX);
+        assert_eq!(allowance.use_allowance(MAX, 10), Ok(()));
+        assert_eq!(
+            allowance.use_allowance(1, 10),
+            Err(MinterAllowanceError::WindowLimitExceeded)
+        );
+        assert_eq!(allowance.remaining(10), 0);
+    }
+
+    #[test]
+    fn window_limit_cannot_be_doubled_across_a_boundary() {
+        let mut allowance = allowance(10 * MAX);
+        assert_eq!(allowance.use_allowance(MAX, WINDOW - 1), Ok(()));
+        assert_eq!(
+            allowance.use_allowance(MAX, WINDOW),
+            Err(MinterAllowanceError::WindowLimitExceeded)
+        );
+        assert_eq!(allowance.remaining(WINDOW), 0);
+    }
+
+    #[test]
+    fn usage_decays_linearly() {
+        let mut allowance = allowance(10 * MAX);
+        assert_eq!(allowance.use_allowance(MAX, 0), Ok(()));
+        assert_eq!(allowance.remaining(WINDOW / 4), MAX / 4);
+        assert_eq!(allowance.remaining(WINDOW / 2), MAX / 2);
+        assert_eq!(allowance.use_allowance(MAX / 2, WINDOW / 2), Ok(()));
+        assert_eq!(allowance.remaining(WINDOW / 2), 0);
+        assert_eq!(allowance.remaining(WINDOW / 2 + WINDOW), MAX);
+    }
+
+    #[test]
+    fn decay_rounds_against_the_minter() {
+        let mut allowance = allowance(10 * MAX);
+        assert_eq!(allowance.use_allowance(1, 0), Ok(()));
+        assert_eq!(allowance.minted_in_window_at(WINDOW - 1), 1);
+        assert_eq!(allowance.minted_in_window_at(WINDOW), 0);
+    }
+
+    #[test]
+    fn lifetime_budget_is_enforced() {
+        let mut allowance = allowance(MAX + MAX / 2);
+        assert_eq!(allowance.use_allowance(MAX, 0), Ok(()));
+        assert_eq!(allowance.remaining(WINDOW), MAX / 2);
+        assert_eq!(
+            allowance.use_allowance(MAX, WINDOW),
+            Err(MinterAllowanceError::LifetimeBudgetExceeded)
+        );
+        assert_eq!(allowance.use_allowance(MAX / 2, WINDOW), Ok(()));
+        assert_eq!(allowance.remaining(2 * WINDOW), 0);
+    }
+
+    #[test]
+    fn set_limits_keeps_the_decayed_usage() {
+        let mut allowance = allowance(10 * MAX);
+        assert_eq!(allowance.use_allowance(MAX, 0), Ok(()));
+        assert_eq!(
+            allowance.set_limits(2 * WINDOW, MAX, 10 * MAX, WINDOW / 2),
+            Ok(())
+        );
+        assert_eq!(allowance.minted_in_window, MAX / 2);
+        assert_eq!(allowance.minted_total, MAX);
+        assert_eq!(allowance.remaining(WINDOW / 2 + WINDOW), 3 * MAX / 4);
+    }
+}
@@ -0,0 +1,44 @@
+use pendzl::traits::{AccountId, Balance, Timestamp};
+
+use pendzl::contracts::psp22::PSP22Error;
+
+use crate::modules::minter_allowance::structs::MinterAllowance;
+
+/// Limits of minters. An account with the MINTER role can `mint` only within its allowance.
+#[ink::trait_definition]
+pub trait AbaxTokenMinterAllowance {
+    /// Returns the allowance of `minter` with its usage or `None` if `minter` has no allowance.
+    #[ink(message)]
+    fn minter_allowance(&self, minter: AccountId) -> Option<MinterAllowance>;
+
+    /// Returns the amount `minter` can mint now (not taking the cap into account) or `None` if `minter` has no allowance.
+    #[ink(message)]
+    fn remaining_minter_allowance(&self, minter: AccountId) -> Option<Balance>;
+
+    /// Limits `minter` to `max_per_window` per rolling `window_duration` and to `lifetime_budget` in total.
+    /// The amount minted within the window decays linearly to 0 over `window_duration`.
+    /// The amount already minted by `minter` counts toward the new limits.
+    ///
+    /// On success emits `MinterAllowanceSet` event.
+    ///
+    /// # Errors
+    /// Returns `AccessControlError` if `caller` is not the admin.
+    /// Returns `Custom("MA:ZeroWindowDuration")` if `window_duration` is 0.
+    #[ink(message)]
+    fn set_minter_allowance(
+        &mut self,
+        minter: AccountId,
+        window_duration: Timestamp,
+        max_per_window: Balance,
+        lifetime_budget: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Removes the allowance of `minter` so that it can't mint anymore.
+    ///
+    /// On success emits `MinterAllowanceRemoved` event.
+    ///
+    /// # Errors
+    /// Returns `AccessControlError` if `caller` is not the admin.
+    #[ink(message)]
+    fn remove_minter_allowance(&mut self, minter: AccountId) -> Result<(), PSP22Error>;
+}
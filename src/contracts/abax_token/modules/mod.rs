@@ -1 +1,6 @@
 pub mod capped_inflation;
+pub mod distribution;
+pub mod minter_allowance;
+pub mod permit;
+pub mod receiver;
+pub mod votes;
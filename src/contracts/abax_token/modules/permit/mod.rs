@@ -0,0 +1,2 @@
+pub mod permit_storage_field;
+pub mod traits;
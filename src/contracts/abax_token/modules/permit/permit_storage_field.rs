@@ -0,0 +1,85 @@
+use ink::{
+    env::hash::{Blake2x256, HashOutput},
+    primitives::Hash,
+    storage::Mapping,
+};
+use pendzl::{
+    math::errors::MathError,
+    traits::{AccountId, Balance, Timestamp},
+};
+
+/// Prefix of the domain separator. Distinguishes permits from other signed payloads.
+pub const PERMIT_DOMAIN_PREFIX: &[u8] = b"PSP22Permit";
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PermitData {
+    nonces: Mapping<AccountId, u64>,
+    #[lazy]
+    chain_id: Hash,
+}
+
+impl PermitData {
+    pub fn nonce_of(&self, owner: &AccountId) -> u64 {
+        self.nonces.get(owner).unwrap_or_default()
+    }
+
+    pub fn use_nonce(&mut self, owner: &AccountId) -> Result<u64, MathError> {
+        let nonce = self.nonce_of(owner);
+        self.nonces
+            .insert(owner, &(nonce.checked_add(1).ok_or(MathError::Overflow)?));
+        Ok(nonce)
+    }
+
+    pub fn chain_id(&self) -> Hash {
+        self.chain_id.get().unwrap_or_default()
+    }
+
+    pub fn set_chain_id(&mut self, chain_id: &Hash) {
+        self.chain_id.set(chain_id);
+    }
+
+    pub fn domain_separator(&self, contract: &AccountId) -> Hash {
+        blake2_256(&scale::Encode::encode(&(
+            PERMIT_DOMAIN_PREFIX,
+            contract,
+            self.chain_id(),
+        )))
+        .into()
+    }
+}
+
+/// Hash of the payload that `owner` signs to permit `spender` to spend `value` of its tokens.
+pub fn permit_message_hash(
+    domain_separator: &Hash,
+    owner: &AccountId,
+    spender: &AccountId,
+    value: &Balance,
+    nonce: u64,
+    deadline: &Timestamp,
+) -> Hash {
+    blake2_256(&scale::Encode::encode(&(
+        domain_separator,
+        owner,
+        spender,
+        value,
+        nonce,
+        deadline,
+    )))
+    .into()
+}
+
+/// Returns the account of the ECDSA key that signed `message_hash`.
+/// The account is derived the same way Substrate derives it - blake2_256 of the compressed public key.
+pub fn recover_signer(signature: &[u8; 65], message_hash: &Hash) -> Option<AccountId> {
+    let message_hash: [u8; 32] = (*message_hash).into();
+    let mut public_key = [0u8; 33];
+    ink::env::ecdsa_recover(signature, &message_hash, &mut public_key).ok()?;
+    Some(AccountId::from(blake2_256(&public_key)))
+}
+
+fn blake2_256(input: &[u8]) -> [u8; 32] {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink::env::hash_bytes::<Blake2x256>(input, &mut output);
+    output
+}
@@ -0,0 +1,36 @@
+use ink::primitives::Hash;
+use pendzl::traits::{AccountId, Balance, Timestamp};
+
+use pendzl::contracts::psp22::PSP22Error;
+
+#[ink::trait_definition]
+pub trait PSP22Permit {
+    /// Sets the allowance of `spender` over `owner` tokens to `value` using `owner` signature instead of a transaction.
+    ///
+    /// `signature` is an ECDSA signature of the blake2_256 hash of the scale encoded
+    /// `(domain_separator, owner, spender, value, nonce, deadline)` where `nonce` is the current `nonces(owner)`.
+    /// `owner` must be the account derived from the signing key.
+    ///
+    /// On success increments the nonce of `owner` and emits `Approval` event.
+    ///
+    /// # Errors
+    /// Returns `Custom("PermitExpired")` if `deadline` has passed.
+    /// Returns `Custom("InvalidSignature")` if `signature` wasn't made by `owner` over the expected payload.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: [u8; 65],
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the nonce that the next permit of `owner` must be signed with.
+    #[ink(message)]
+    fn nonces(&self, owner: AccountId) -> u64;
+
+    /// Returns the domain separator - hash of the contract account id and the chain id.
+    #[ink(message)]
+    fn domain_separator(&self) -> Hash;
+}
@@ -0,0 +1,2 @@
+pub mod receivers_storage_field;
+pub mod traits;
@@ -0,0 +1,23 @@
+use ink::storage::Mapping;
+use pendzl::traits::AccountId;
+
+/// Contracts that are notified with `PSP22Receiver::on_received` when they receive tokens.
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct ReceiversData {
+    receivers: Mapping<AccountId, ()>,
+}
+
+impl ReceiversData {
+    pub fn is_receiver(&self, account: &AccountId) -> bool {
+        self.receivers.contains(account)
+    }
+
+    pub fn set_receiver(&mut self, account: &AccountId, allowed: bool) {
+        if allowed {
+            self.receivers.insert(account, &());
+        } else {
+            self.receivers.remove(account);
+        }
+    }
+}
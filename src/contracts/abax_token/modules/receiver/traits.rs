@@ -0,0 +1,48 @@
+use ink::{contract_ref, env::DefaultEnvironment, prelude::string::String, prelude::vec::Vec};
+use pendzl::traits::{AccountId, Balance};
+
+use pendzl::contracts::psp22::PSP22Error;
+
+pub type PSP22ReceiverRef = contract_ref!(PSP22Receiver, DefaultEnvironment);
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22ReceiverError {
+    TransferRejected(String),
+}
+
+/// Implemented by contracts that want to react to received tokens.
+#[ink::trait_definition]
+pub trait PSP22Receiver {
+    /// Called by the token after `value` of tokens were transferred from `from` to the receiver on behalf of `operator`.
+    /// `data` is passed through from `transfer` / `transfer_from` and is empty for `transfer_batch`.
+    /// Returning an error reverts the transfer.
+    ///
+    /// The receiver may call back into the token. It is not called when it is the `operator` itself,
+    /// e.g. when it pulls tokens with `transfer_from`.
+    #[ink(message)]
+    fn on_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22ReceiverError>;
+}
+
+#[ink::trait_definition]
+pub trait AbaxTokenReceivers {
+    /// Returns whether `account` is notified when it receives tokens.
+    #[ink(message)]
+    fn is_hook_receiver(&self, account: AccountId) -> bool;
+
+    /// Sets whether `account` is notified with `PSP22Receiver::on_received` when it receives tokens with `transfer`, `transfer_from`
+    /// or `transfer_batch` called by another account.
+    ///
+    /// On success emits `HookReceiverSet` event.
+    ///
+    /// # Errors
+    /// Returns `AccessControlError` if `caller` is not the admin.
+    #[ink(message)]
+    fn set_hook_receiver(&mut self, account: AccountId, allowed: bool) -> Result<(), PSP22Error>;
+}
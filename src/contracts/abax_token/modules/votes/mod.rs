@@ -0,0 +1,3 @@
+pub mod structs;
+pub mod traits;
+pub mod votes_storage_field;
@@ -0,0 +1,14 @@
+use pendzl::traits::Balance;
+
+pub type BlockNumber = u32;
+
+/// Amount of votes since `block`.
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Checkpoint {
+    pub block: BlockNumber,
+    pub votes: Balance,
+}
@@ -0,0 +1,41 @@
+use pendzl::traits::{AccountId, Balance};
+
+use pendzl::contracts::psp22::PSP22Error;
+
+use crate::modules::votes::structs::BlockNumber;
+
+#[ink::trait_definition]
+pub trait PSP22Votes {
+    /// Returns the account `account` delegates its votes to.
+    #[ink(message)]
+    fn delegates(&self, account: AccountId) -> Option<AccountId>;
+
+    /// Returns the current votes of `account`.
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> Balance;
+
+    /// Returns votes of `account` at the end of `block`.
+    ///
+    /// # Errors
+    /// Returns `Custom("VO:FutureLookup")` if `block` isn't in the past.
+    #[ink(message)]
+    fn get_past_votes(&self, account: AccountId, block: BlockNumber)
+        -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply at the end of `block`.
+    ///
+    /// # Errors
+    /// Returns `Custom("VO:FutureLookup")` if `block` isn't in the past.
+    #[ink(message)]
+    fn get_past_total_supply(&self, block: BlockNumber) -> Result<Balance, PSP22Error>;
+
+    /// Returns the number of checkpoints of `account`.
+    #[ink(message)]
+    fn num_checkpoints(&self, account: AccountId) -> u32;
+
+    /// Delegates votes of `caller` to `delegatee` (`caller` itself to vote with own tokens). `None` removes the delegation.
+    ///
+    /// On success emits `DelegateChanged` event and `DelegateVotesChanged` events for affected delegates.
+    #[ink(message)]
+    fn delegate(&mut self, delegatee: Option<AccountId>) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,238 @@
+use ink::storage::Mapping;
+use pendzl::{
+    math::errors::MathError,
+    traits::{AccountId, Balance},
+};
+
+use crate::modules::votes::structs::{BlockNumber, Checkpoint};
+
+/// Delegation of voting power and checkpoints of votes of delegates and of the total supply.
+/// Tokens of an account that hasn't delegated carry no votes.
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct VotesData {
+    delegates: Mapping<AccountId, AccountId>,
+    checkpoints: Mapping<(AccountId, u32), Checkpoint>,
+    checkpoints_count: Mapping<AccountId, u32>,
+    total_supply_checkpoints: Mapping<u32, Checkpoint>,
+    #[lazy]
+    total_supply_checkpoints_count: u32,
+}
+
+impl VotesData {
+    pub fn delegate_of(&self, account: &AccountId) -> Option<AccountId> {
+        self.delegates.get(account)
+    }
+
+    pub fn set_delegate(&mut self, account: &AccountId, delegatee: &Option<AccountId>) {
+        match delegatee {
+            Some(delegatee) => {
+                self.delegates.insert(account, delegatee);
+            }
+            None => self.delegates.remove(account),
+        }
+    }
+
+    pub fn checkpoints_count(&self, account: &AccountId) -> u32 {
+        self.checkpoints_count.get(account).unwrap_or_default()
+    }
+
+    pub fn checkpoint(&self, account: &AccountId, index: u32) -> Option<Checkpoint> {
+        self.checkpoints.get((account, index))
+    }
+
+    pub fn votes_of(&self, account: &AccountId) -> Balance {
+        match self.checkpoints_count(account) {
+            0 => 0,
+            count => self
+                .checkpoint(account, count - 1)
+                .map(|checkpoint| checkpoint.votes)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns votes of `account` at the end of `block`.
+    pub fn past_votes_of(&self, account: &AccountId, block: BlockNumber) -> Balance {
+        upper_lookup(self.checkpoints_count(account), block, |index| {
+            self.checkpoint(account, index)
+        })
+    }
+
+    /// Returns the total supply at the end of `block`.
+    pub fn past_total_supply(&self, block: BlockNumber) -> Balance {
+        upper_lookup(self.total_supply_checkpoints_count(), block, |index| {
+            self.total_supply_checkpoints.get(index)
+        })
+    }
+
+    /// Increases votes of `account` by `amount` at `block`. Returns the previous and the new votes.
+    pub fn increase_votes(
+        &mut self,
+        account: &AccountId,
+        amount: Balance,
+        block: BlockNumber,
+    ) -> Result<(Balance, Balance), MathError> {
+        let previous_votes = self.votes_of(account);
+        let new_votes = previous_votes
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        self.push_checkpoint(account, new_votes, block)?;
+        Ok((previous_votes, new_votes))
+    }
+
+    /// Decreases votes of `account` by `amount` at `block`. Returns the previous and the new votes.
+    pub fn decrease_votes(
+        &mut self,
+        account: &AccountId,
+        amount: Balance,
+        block: BlockNumber,
+    ) -> Result<(Balance, Balance), MathError> {
+        let previous_votes = self.votes_of(account);
+        let new_votes = previous_votes
+            .checked_sub(amount)
+            .ok_or(MathError::Underflow)?;
+        self.push_checkpoint(account, new_votes, block)?;
+        Ok((previous_votes, new_votes))
+    }
+
+    /// Records `total_supply` at `block`.
+    pub fn push_total_supply(
+        &mut self,
+        total_supply: Balance,
+        block: BlockNumber,
+    ) -> Result<(), MathError> {
+        let count = self.total_supply_checkpoints_count();
+        let checkpoint = Checkpoint {
+            block,
+            votes: total_supply,
+        };
+        match count.checked_sub(1) {
+            Some(last)
+                if self.total_supply_checkpoints.get(last).map(|c| c.block) == Some(block) =>
+            {
+                self.total_supply_checkpoints.insert(last, &checkpoint);
+            }
+            _ => {
+                self.total_supply_checkpoints.insert(count, &checkpoint);
+                self.total_supply_checkpoints_count
+                    .set(&(count.checked_add(1).ok_or(MathError::Overflow)?));
+            }
+        }
+        Ok(())
+    }
+
+    fn total_supply_checkpoints_count(&self) -> u32 {
+        self.total_supply_checkpoints_count
+            .get()
+            .unwrap_or_default()
+    }
+
+    // there is at most one checkpoint per block - the last one in the block overrides the previous ones
+    fn push_checkpoint(
+        &mut self,
+        account: &AccountId,
+        votes: Balance,
+        block: BlockNumber,
+    ) -> Result<(), MathError> {
+        let count = self.checkpoints_count(account);
+        let checkpoint = Checkpoint { block, votes };
+        match count.checked_sub(1) {
+            Some(last) if self.checkpoint(account, last).map(|c| c.block) == Some(block) => {
+                self.checkpoints.insert((account, last), &checkpoint);
+            }
+            _ => {
+                self.checkpoints.insert((account, count), &checkpoint);
+                self.checkpoints_count
+                    .insert(account, &(count.checked_add(1).ok_or(MathError::Overflow)?));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns votes of the last checkpoint with block lower or equal to `block` (binary search over checkpoints sorted by block).
+fn upper_lookup(
+    count: u32,
+    block: BlockNumber,
+    checkpoint_at: impl Fn(u32) -> Option<Checkpoint>,
+) -> Balance {
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match checkpoint_at(mid) {
+            Some(checkpoint) if checkpoint.block > block => high = mid,
+            _ => low = mid + 1,
+        }
+    }
+    match high.checked_sub(1) {
+        Some(index) => checkpoint_at(index)
+            .map(|checkpoint| checkpoint.votes)
+            .unwrap_or_default(),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: [u8; 32] = [1; 32];
+    const BOB: [u8; 32] = [2; 32];
+
+    #[ink::test]
+    fn set_delegate_sets_and_removes_delegation() {
+        let mut votes = VotesData::default();
+        assert_eq!(votes.delegate_of(&ALICE.into()), None);
+
+        votes.set_delegate(&ALICE.into(), &Some(BOB.into()));
+        assert_eq!(votes.delegate_of(&ALICE.into()), Some(BOB.into()));
+
+        votes.set_delegate(&ALICE.into(), &None);
+        assert_eq!(votes.delegate_of(&ALICE.into()), None);
+    }
+
+    #[ink::test]
+    fn checkpoints_of_the_same_block_are_merged() {
+        let mut votes = VotesData::default();
+        assert_eq!(votes.increase_votes(&ALICE.into(), 100, 5), Ok((0, 100)));
+        assert_eq!(votes.decrease_votes(&ALICE.into(), 30, 5), Ok((100, 70)));
+        assert_eq!(votes.checkpoints_count(&ALICE.into()), 1);
+
+        assert_eq!(votes.increase_votes(&ALICE.into(), 10, 6), Ok((70, 80)));
+        assert_eq!(votes.checkpoints_count(&ALICE.into()), 2);
+        assert_eq!(votes.votes_of(&ALICE.into()), 80);
+        assert_eq!(
+            votes.decrease_votes(&ALICE.into(), 81, 7),
+            Err(MathError::Underflow)
+        );
+    }
+
+    #[ink::test]
+    fn past_votes_are_looked_up_by_block() {
+        let mut votes = VotesData::default();
+        for (block, amount) in [(10, 100), (20, 50), (30, 25), (40, 5)] {
+            votes.increase_votes(&ALICE.into(), amount, block).unwrap();
+        }
+
+        assert_eq!(votes.past_votes_of(&ALICE.into(), 9), 0);
+        assert_eq!(votes.past_votes_of(&ALICE.into(), 10), 100);
+        assert_eq!(votes.past_votes_of(&ALICE.into(), 19), 100);
+        assert_eq!(votes.past_votes_of(&ALICE.into(), 20), 150);
+        assert_eq!(votes.past_votes_of(&ALICE.into(), 35), 175);
+        assert_eq!(votes.past_votes_of(&ALICE.into(), 1_000), 180);
+        assert_eq!(votes.past_votes_of(&BOB.into(), 1_000), 0);
+    }
+
+    #[ink::test]
+    fn past_total_supply_is_looked_up_by_block() {
+        let mut votes = VotesData::default();
+        votes.push_total_supply(100, 10).unwrap();
+        votes.push_total_supply(300, 20).unwrap();
+        votes.push_total_supply(200, 20).unwrap();
+
+        assert_eq!(votes.past_total_supply(9), 0);
+        assert_eq!(votes.past_total_supply(15), 100);
+        assert_eq!(votes.past_total_supply(20), 200);
+        assert_eq!(votes.past_total_supply(21), 200);
+    }
+}
@@ -9,7 +9,7 @@ pub mod abax_treasury {
         errors::AbaxTreasuryError,
         events::{OrderCancelled, OrderCreated, OrderExecuted, VesterChanged},
         storage_fields::operations::OrdersStorage,
-        structs::{Operation, Order, OrderId},
+        structs::{NativeTransfer, Operation, Order, OrderId, PSP22Transfer, Vest},
         traits::{AbaxTreasury, AbaxTreasuryView},
     };
     use ink::codegen::TraitCallBuilder;
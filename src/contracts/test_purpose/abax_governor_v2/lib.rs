@@ -0,0 +1,1134 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+mod modules;
+/// This is AbaxGovernor Contract implementation.
+/// It allows for staking PSP22 token (Abax token) in exchange for PSP22Vault shares (votes).
+/// The shares are non-transferrable.
+/// Withdrawing assets is possible only after unstake period - unstaking is handled by GeneralVest contract.
+///
+/// The contract allows for proposing and voting on proposals by implementing Govern trait.
+/// To create a proposal, the proposer must have enough votes (shares) to meet the minimum stake part.
+/// While proposal is created the proposer must deposit a part of his votes. This votes are returned when proposal is finalized unless the proposal is finalized with 'DefeatedWithSlash' status.
+/// Alternatively the deposit can be taken in assets (Abax token). Then the proposer doesn't have to meet the minimum stake part and the slashed assets are shared among stakers.
+/// One share is one vote.
+/// Proposal has 3 periods of voting: Intial, Flat and Final which influence the minimum votes to finalize.
+/// If proposal was finalized in Final phase, it's possible to force unstake an account that didn't vote on that proposal once the grace period after finalization has passed.
+/// Voters without an opinion can abstain. Abstaining votes count toward the quorum and the minimum votes to finalize, but not for or against.
+/// Proposal can use commit-reveal voting in which votes are committed as hashes and counted only when revealed after the final period.
+/// Stakers that don't meet the minimum stake part alone can create a draft proposal that becomes active once sponsors pledge enough votes.
+/// Account with PAUSER role can temporarily pause a subset of messages. While voting is paused, voting periods of active proposals are extended.
+/// Storage layout is versioned. After the code hash is updated, account with MIGRATOR role runs the migration steps in batches of proposals with `migrate`.
+///
+/// Contract is using pendzl Access Control to manage access to the messages
+
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22Metadata, AccessControl, SetCodeHash)]
+#[ink::contract]
+mod abax_governor {
+    pub use crate::modules::govern::{
+        helpers::{
+            finalization::{minimum_quorum, minimum_to_finalize, voting_time},
+            hashes::{hash_description, hash_proposal, hash_vote},
+        },
+        storage::{
+            govern_storage_item::GovernData,
+            locked_assets_storage_item::LockedAssetsData,
+            locked_shares_storage_item::LockedSharesData,
+            pause_storage_item::PauseData,
+            sponsorship_storage_item::SponsorshipData,
+            unstake_storage_item::UnstakeData,
+            vault_counter_storage_item::VaultCounterData,
+            version_storage_item::{VersionData, STORAGE_VERSION},
+        },
+        traits::{
+            AbaxGovern, AbaxGovernInternal, AbaxGovernManage, AbaxGovernView, CommitRevealRules,
+            CommitRevealRulesChanged, Draft, DraftCreated, GovernError, Migrated, OpaqueTypes,
+            Pause, Paused, PausedMessages, Proposal, ProposalCreated, ProposalExecuted,
+            ProposalFinalized, ProposalHash, ProposalId, ProposalState, ProposalStatus,
+            ProposerDeposit, ProposerDepositChanged, Sponsored, Sponsorship, Unpaused, Unsponsored,
+            UnstakePeriodChanged, UserVote, Vote, VoteCasted, VoteCommitted, VotingRules,
+            VotingRulesChanged,
+        },
+    };
+    use ink::codegen::TraitCallBuilder;
+    pub use ink::{
+        codegen::Env,
+        env::DefaultEnvironment,
+        prelude::string::{String, ToString},
+        ToAccountId,
+    };
+
+    pub use pendzl::{
+        contracts::{
+            access_control::RoleType,
+            general_vest::{
+                ExternalTimeConstraint, GeneralVest, ProvideVestScheduleInfo, VestingSchedule,
+            },
+            psp22::{vault::PSP22VaultInternalDefaultImpl, *},
+        },
+        math::operations::mul_div,
+        traits::Flush,
+    };
+
+    pub const EXECUTOR: RoleType = ink::selector_id!("EXECUTOR");
+    pub const PARAMETERS_ADMIN: RoleType = ink::selector_id!("PARAMETERS_ADMIN"); // 368_001_360_u32
+    pub const PAUSER: RoleType = ink::selector_id!("PAUSER");
+    pub const MIGRATOR: RoleType = ink::selector_id!("MIGRATOR");
+
+    #[derive(StorageFieldGetter)]
+    #[ink(storage)]
+    pub struct AbaxGovernor {
+        // pendzl storage fields
+        #[storage_field]
+        access_control: AccessControlData,
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        vault: PSP22VaultData,
+        #[storage_field]
+        metadata: PSP22MetadataData,
+        // non-pendzl storage fields
+        #[storage_field]
+        govern: GovernData,
+        #[storage_field]
+        counter: VaultCounterData,
+        #[storage_field]
+        lock: LockedSharesData,
+        #[storage_field]
+        locked_assets: LockedAssetsData,
+        #[storage_field]
+        sponsorship: SponsorshipData,
+        #[storage_field]
+        unstake: UnstakeData,
+        #[storage_field]
+        pause: PauseData,
+        #[storage_field]
+        upgradeable: VersionData,
+    }
+
+    #[overrider(PSP22VaultInternal)]
+    fn _deposit(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+        shares: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if self._paused_messages().deposit {
+            return Err(PSP22Error::Custom("Paused".to_string()));
+        }
+        self.counter.increase_counter(*shares);
+        self.govern.set_last_stake_timestamp(receiver);
+        self._deposit_default_impl(caller, receiver, assets, shares)?;
+        self._sync_votes(receiver)?;
+
+        Ok(())
+    }
+
+    // proposer deposits taken in assets don't back the shares
+    #[overrider(PSP22VaultInternal)]
+    fn _total_assets(&self) -> Balance {
+        self._total_assets_default_impl()
+            .saturating_sub(self.locked_assets.total_locked())
+    }
+
+    #[overrider(PSP22VaultInternal)]
+    fn _withdraw(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        owner: &AccountId,
+        assets: &Balance,
+        shares: &Balance,
+    ) -> Result<(), PSP22Error> {
+        // force unstake is paused separately
+        if *caller != self.env().account_id() && self._paused_messages().withdraw {
+            return Err(PSP22Error::Custom("Paused".to_string()));
+        }
+        if *caller != *owner && *caller != self.env().account_id() {
+            self._decrease_allowance_from_to(owner, caller, shares)?;
+        }
+
+        self._burn_from(owner, shares)?;
+        self._sync_votes(owner)?;
+
+        if self._balance_of(owner) == 0 {
+            self.govern.remove_last_stake_timestamp(owner);
+        }
+
+        self.vault
+            .asset()
+            .call_mut()
+            .approve(self.unstake.general_vester().to_account_id(), *assets)
+            .call_v1()
+            .invoke()?;
+
+        match self
+            .unstake
+            .general_vester()
+            .call_mut()
+            .create_vest(
+                *receiver,
+                Some(self.vault.asset().to_account_id()),
+                *assets,
+                VestingSchedule::External(ExternalTimeConstraint {
+                    account: self.env().account_id(),
+                    fallback_values: (self.unstake.unstake_period(), 0),
+                }),
+                Vec::<u8>::new(),
+            )
+            .call_v1()
+            .invoke()
+        {
+            Ok(_) => {}
+            Err(_) => {
+                return Err(PSP22Error::Custom(
+                    "Failed during create vest call".to_string(),
+                ))
+            }
+        }
+
+        ink::env::emit_event::<DefaultEnvironment, Withdraw>(Withdraw {
+            sender: *caller,
+            receiver: *receiver,
+            owner: *owner,
+            assets: *assets,
+            shares: *shares,
+        });
+        Ok(())
+    }
+
+    #[overrider(PSP22)]
+    fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
+        Err(PSP22Error::Custom("Untransferrable".to_string()))
+    }
+
+    #[overrider(PSP22)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        Err(PSP22Error::Custom("Untransferrable".to_string()))
+    }
+
+    impl AbaxGovernor {
+        #[allow(clippy::too_many_arguments)]
+        #[ink(constructor)]
+        pub fn new(
+            asset: AccountId,
+            vester: AccountId,
+            foundation: AccountId,
+            parameters_admin: Option<AccountId>,
+            unstake_period: Timestamp,
+            name: String,
+            symbol: String,
+            rules: VotingRules,
+        ) -> Result<Self, GovernError> {
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                &rules,
+                &CommitRevealRules::default(),
+                unstake_period,
+            )?;
+
+            let mut instance = Self {
+                access_control: AccessControlData::new(Some(Self::env().account_id())),
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                metadata: PSP22MetadataData::new(Some(name), Some(symbol)),
+                govern: GovernData::new(&rules),
+                counter: VaultCounterData::default(),
+                lock: LockedSharesData::default(),
+                locked_assets: LockedAssetsData::default(),
+                sponsorship: SponsorshipData::default(),
+                unstake: UnstakeData::new(vester, unstake_period),
+                pause: PauseData::default(),
+                upgradeable: VersionData::new(),
+            };
+
+            if let Some(admin) = parameters_admin {
+                instance._grant_role(PARAMETERS_ADMIN, Some(admin))?;
+            }
+
+            instance._grant_role(EXECUTOR, Some(foundation))?;
+            Ok(instance)
+        }
+
+        /// Migrates the storage from `version` to `version + 1`, at most `count` proposals at once.
+        /// Returns true if the step is finished.
+        fn _migration_step(&mut self, version: u16, count: u32) -> Result<bool, GovernError> {
+            match version {
+                // storage of version 0 differs only by the missing version marker
+                0 => Ok(true),
+                // `ProposalState` got the `last_vote` field
+                1 => {
+                    let from = self.upgradeable.migration_cursor().unwrap_or_default();
+                    let cursor = self.govern.migrate_proposal_states(from, count);
+                    self.upgradeable.set_migration_cursor(cursor);
+                    Ok(cursor.is_none())
+                }
+                // there are no other versions below `STORAGE_VERSION`
+                _ => Ok(true),
+            }
+        }
+
+        /// Runs the ongoing migration step on at most `count` proposals and bumps the version once the step is finished.
+        fn _migrate(&mut self, count: u32) -> Result<(), GovernError> {
+            let from = self.upgradeable.version();
+            if from >= STORAGE_VERSION {
+                return Err(GovernError::AlreadyMigrated);
+            }
+            if self._migration_step(from, count)? {
+                let to = from + 1;
+                self.upgradeable.set_version(to);
+                ink::env::emit_event::<DefaultEnvironment, Migrated>(Migrated { from, to });
+            }
+            Ok(())
+        }
+
+        fn _update_vote(
+            &mut self,
+            voter: &AccountId,
+            proposal_id: ProposalId,
+            vote: Vote,
+        ) -> Result<(), GovernError> {
+            let voter_votes = {
+                let balance = self._balance_of(voter);
+                let locked = self.lock.locked(&proposal_id);
+                let proposer = self
+                    .govern
+                    .state_of(&proposal_id)
+                    .ok_or(GovernError::ProposalDoesntExist)?
+                    .proposer;
+                if self.env().caller() == proposer {
+                    balance.checked_add(locked).ok_or(MathError::Overflow)?
+                } else {
+                    balance
+                }
+            };
+
+            self.govern
+                .update_vote_of_for(voter, &proposal_id, &vote, &voter_votes)?;
+
+            ink::env::emit_event::<DefaultEnvironment, VoteCasted>(VoteCasted {
+                account: *voter,
+                proposal_id,
+                vote,
+            });
+
+            Ok(())
+        }
+
+        fn _take_assets_deposit(
+            &mut self,
+            proposer: &AccountId,
+            proposal_id: &ProposalId,
+            assets: Balance,
+        ) -> Result<(), GovernError> {
+            self.locked_assets.lock(proposal_id, assets)?;
+            self.vault
+                .asset()
+                .call_mut()
+                .transfer_from(*proposer, self.env().account_id(), assets, Vec::<u8>::new())
+                .call_v1()
+                .invoke()?;
+            Ok(())
+        }
+
+        /// Updates votes of `account` on all active proposals to its current balance.
+        fn _sync_votes(&mut self, account: &AccountId) -> Result<(), MathError> {
+            let balance = self._balance_of(account);
+            for proposal_id in self.govern.active_proposal_ids() {
+                if self.govern.vote_of_for(account, &proposal_id).is_none() {
+                    continue;
+                }
+                let amount = match self.govern.state_of(&proposal_id) {
+                    Some(state) if state.proposer == *account => balance
+                        .checked_add(self.lock.locked(&proposal_id))
+                        .ok_or(MathError::Overflow)?,
+                    _ => balance,
+                };
+                self.govern
+                    .sync_vote_amount(account, &proposal_id, &amount)?;
+            }
+            Ok(())
+        }
+
+        fn _paused_messages(&self) -> PausedMessages {
+            self.pause
+                .is_paused(self.env().block_timestamp())
+                .unwrap_or_default()
+        }
+    }
+
+    impl AbaxGovern for AbaxGovernor {
+        #[ink(message)]
+        fn propose(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError> {
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._propose(&self.env().caller(), &proposal, false)
+        }
+
+        #[ink(message)]
+        fn propose_commit_reveal(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError> {
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._propose(&self.env().caller(), &proposal, true)
+        }
+
+        #[ink(message)]
+        fn create_draft(&mut self, proposal: Proposal) -> Result<ProposalHash, GovernError> {
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._create_draft(&self.env().caller(), &proposal)
+        }
+
+        #[ink(message)]
+        fn sponsor(
+            &mut self,
+            proposal_hash: ProposalHash,
+            amount: Balance,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().propose {
+                return Err(GovernError::Paused);
+            }
+            self._sponsor(&self.env().caller(), &proposal_hash, amount)
+        }
+
+        #[ink(message)]
+        fn unsponsor(&mut self, proposal_hash: ProposalHash) -> Result<(), GovernError> {
+            self._unsponsor(&self.env().caller(), &proposal_hash)
+        }
+
+        #[ink(message)]
+        fn finalize(&mut self, proposal_id: ProposalId) -> Result<(), GovernError> {
+            if self._paused_messages().finalize {
+                return Err(GovernError::Paused);
+            }
+            self._finalize(&proposal_id)
+        }
+
+        #[ink(message)]
+        fn execute(&mut self, proposal: Proposal) -> Result<(), GovernError> {
+            self._ensure_has_role(EXECUTOR, Some(self.env().caller()))?;
+            self._execute(&proposal)
+        }
+
+        #[ink(message)]
+        fn vote(
+            &mut self,
+            proposal_id: ProposalId,
+            vote: Vote,
+            _reason: Vec<u8>,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().vote {
+                return Err(GovernError::Paused);
+            }
+            self._cast_vote(&self.env().caller(), proposal_id, vote, _reason)
+        }
+
+        #[ink(message)]
+        fn commit_vote(
+            &mut self,
+            proposal_id: ProposalId,
+            vote_hash: Hash,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().vote {
+                return Err(GovernError::Paused);
+            }
+            self._commit_vote(&self.env().caller(), proposal_id, vote_hash)
+        }
+
+        #[ink(message)]
+        fn reveal_vote(
+            &mut self,
+            proposal_id: ProposalId,
+            vote: Vote,
+            salt: Hash,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().vote {
+                return Err(GovernError::Paused);
+            }
+            self._reveal_vote(&self.env().caller(), proposal_id, vote, salt)
+        }
+
+        #[ink(message)]
+        fn force_unstake(
+            &mut self,
+            account: AccountId,
+            proposal_id: ProposalId,
+        ) -> Result<(), GovernError> {
+            if self._paused_messages().force_unstake {
+                return Err(GovernError::Paused);
+            }
+            self._force_unstake(&account, &proposal_id)?;
+            Ok(())
+        }
+    }
+
+    impl AbaxGovernManage for AbaxGovernor {
+        #[ink(message)]
+        fn change_voting_rules(&mut self, rules: VotingRules) -> Result<(), GovernError> {
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                &rules,
+                &self.commit_reveal_rules(),
+                self.unstake.unstake_period(),
+            )?;
+            self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
+            self.govern.change_rule(&rules);
+            ink::env::emit_event::<DefaultEnvironment, VotingRulesChanged>(VotingRulesChanged {
+                rules,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_commit_reveal_rules(
+            &mut self,
+            rules: CommitRevealRules,
+        ) -> Result<(), GovernError> {
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                &self.rules(),
+                &rules,
+                self.unstake.unstake_period(),
+            )?;
+            self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
+            self.govern.change_commit_reveal_rules(&rules);
+            ink::env::emit_event::<DefaultEnvironment, CommitRevealRulesChanged>(
+                CommitRevealRulesChanged { rules },
+            );
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_proposer_deposit(&mut self, deposit: ProposerDeposit) -> Result<(), GovernError> {
+            self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
+            self.govern.change_proposer_deposit(&deposit);
+            ink::env::emit_event::<DefaultEnvironment, ProposerDepositChanged>(
+                ProposerDepositChanged { deposit },
+            );
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_unstake_period(&mut self, period: Timestamp) -> Result<(), GovernError> {
+            _ensure_voting_rules_and_unstake_period_are_valid(
+                &self.rules(),
+                &self.commit_reveal_rules(),
+                period,
+            )?;
+            self._ensure_has_role(PARAMETERS_ADMIN, Some(self.env().caller()))?;
+            self.unstake.set_unstake_period(period);
+            ink::env::emit_event::<DefaultEnvironment, UnstakePeriodChanged>(
+                UnstakePeriodChanged {
+                    unstake_period: period,
+                },
+            );
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn pause(
+            &mut self,
+            messages: PausedMessages,
+            duration: Timestamp,
+        ) -> Result<(), GovernError> {
+            self._ensure_has_role(PAUSER, Some(self.env().caller()))?;
+            let pause = self
+                .pause
+                .set_pause(&messages, duration, self.env().block_timestamp())?;
+            ink::env::emit_event::<DefaultEnvironment, Paused>(Paused {
+                messages,
+                until: pause.end,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn unpause(&mut self) -> Result<(), GovernError> {
+            self._ensure_has_role(PAUSER, Some(self.env().caller()))?;
+            self.pause.unpause(self.env().block_timestamp())?;
+            ink::env::emit_event::<DefaultEnvironment, Unpaused>(Unpaused {});
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn migrate(&mut self, count: u32) -> Result<(), GovernError> {
+            self._ensure_has_role(MIGRATOR, Some(self.env().caller()))?;
+            self._migrate(count)
+        }
+    }
+
+    impl AbaxGovernView for AbaxGovernor {
+        #[ink(message)]
+        fn vester(&self) -> AccountId {
+            self.unstake.general_vester().to_account_id()
+        }
+
+        #[ink(message)]
+        fn hash(&self, proposal: Proposal) -> ProposalHash {
+            hash_proposal(&proposal)
+        }
+
+        #[ink(message)]
+        fn hash_description(&self, description: String) -> Hash {
+            hash_description(&description)
+        }
+
+        #[ink(message)]
+        fn hash_by_id(&self, proposal_id: ProposalId) -> Option<ProposalHash> {
+            self.govern.proposal_id_to_hash(&proposal_id)
+        }
+
+        #[ink(message)]
+        fn rules(&self) -> VotingRules {
+            self.govern.rules()
+        }
+
+        #[ink(message)]
+        fn commit_reveal_rules(&self) -> CommitRevealRules {
+            self.govern.commit_reveal_rules()
+        }
+
+        #[ink(message)]
+        fn proposer_deposit(&self) -> ProposerDeposit {
+            self.govern.proposer_deposit()
+        }
+
+        #[ink(message)]
+        fn locked_assets(&self, proposal_id: ProposalId) -> Balance {
+            self.locked_assets.locked(&proposal_id)
+        }
+
+        #[ink(message)]
+        fn draft(&self, proposal_hash: ProposalHash) -> Option<Draft> {
+            self.sponsorship.draft(&proposal_hash)
+        }
+
+        #[ink(message)]
+        fn sponsorships(&self, proposal_hash: ProposalHash) -> Vec<Sponsorship> {
+            self.sponsorship.sponsorships(&proposal_hash)
+        }
+
+        #[ink(message)]
+        fn status(&self, proposal_id: ProposalId) -> Option<ProposalStatus> {
+            self.govern.state_of(&proposal_id).map(|state| state.status)
+        }
+
+        #[ink(message)]
+        fn minimum_to_finalize(&self, proposal_id: ProposalId) -> Option<Balance> {
+            let state = self.govern.state_of(&proposal_id)?;
+
+            if state.status != ProposalStatus::Active {
+                return None;
+            }
+
+            let now = ink::env::block_timestamp::<DefaultEnvironment>();
+            let voting_now =
+                voting_time(&state, now, self.pause.voting_paused_duration(now).unwrap()).unwrap();
+
+            Some(
+                minimum_to_finalize(&state, &self.rules(), voting_now, self.counter.counter())
+                    .unwrap(),
+            )
+        }
+
+        #[ink(message)]
+        fn minimum_quorum(&self, proposal_id: ProposalId) -> Option<Balance> {
+            let state = self.govern.state_of(&proposal_id)?;
+
+            if state.status != ProposalStatus::Active {
+                return None;
+            }
+
+            Some(minimum_quorum(&state, &self.rules(), self.counter.counter()).unwrap())
+        }
+
+        #[ink(message)]
+        fn state(&self, proposal_id: ProposalId) -> Option<ProposalState> {
+            self.govern.state_of(&proposal_id)
+        }
+
+        #[ink(message)]
+        fn vote_of_for(&self, account: AccountId, proposal_id: ProposalId) -> Option<UserVote> {
+            self.govern.vote_of_for(&account, &proposal_id)
+        }
+
+        #[ink(message)]
+        fn commit_of_for(&self, account: AccountId, proposal_id: ProposalId) -> Option<Hash> {
+            self.govern.commit_of_for(&account, &proposal_id)
+        }
+
+        #[ink(message)]
+        fn hash_vote(
+            &self,
+            account: AccountId,
+            proposal_id: ProposalId,
+            vote: Vote,
+            salt: Hash,
+        ) -> Hash {
+            hash_vote(&account, &proposal_id, &vote, &salt)
+        }
+
+        #[ink(message)]
+        fn last_force_unstakes(&self, account: AccountId) -> Option<ProposalId> {
+            self.govern.last_force_unstake(&account)
+        }
+
+        #[ink(message)]
+        fn last_stake_timestamp(&self, account: AccountId) -> Option<Timestamp> {
+            self.govern.last_stake_timestamp(&account)
+        }
+
+        #[ink(message)]
+        fn active_proposals(&self) -> u32 {
+            self.govern.active_proposals()
+        }
+
+        #[ink(message)]
+        fn active_proposal_ids(&self) -> Vec<ProposalId> {
+            self.govern.active_proposal_ids()
+        }
+
+        #[ink(message)]
+        fn active_proposals_of(&self, account: AccountId) -> u32 {
+            self.govern.active_proposals_of(&account)
+        }
+
+        #[ink(message)]
+        fn last_proposal_timestamp_of(&self, account: AccountId) -> Option<Timestamp> {
+            self.govern.last_proposal_timestamp(&account)
+        }
+
+        #[ink(message)]
+        fn finalized_proposals(&self) -> u32 {
+            self.govern.finalized_proposals()
+        }
+
+        #[ink(message)]
+        fn executed_proposals(&self) -> u32 {
+            self.govern.executed_proposals()
+        }
+
+        #[ink(message)]
+        fn next_proposal_id(&self) -> ProposalId {
+            self.govern.next_proposal_id()
+        }
+        #[ink(message)]
+        fn description_url_by_proposal_id(&self, proposal_id: ProposalId) -> Option<String> {
+            self.govern.proposal_id_to_description_url(&proposal_id)
+        }
+        #[ink(message)]
+        fn description_hash_by_proposal_id(&self, proposal_id: ProposalId) -> Option<Hash> {
+            self.govern.proposal_id_to_description_hash(&proposal_id)
+        }
+
+        #[ink(message)]
+        fn pause_info(&self) -> Option<Pause> {
+            self.pause.pause()
+        }
+
+        #[ink(message)]
+        fn storage_version(&self) -> u16 {
+            self.upgradeable.version()
+        }
+    }
+
+    impl AbaxGovernInternal for AbaxGovernor {
+        fn _propose(
+            &mut self,
+            proposer: &AccountId,
+            proposal: &Proposal,
+            commit_reveal: bool,
+        ) -> Result<ProposalId, GovernError> {
+            let deposit_kind = self.govern.proposer_deposit();
+            //check if the proposer has enough votes to create a proposal
+            let total_votes = self._total_supply();
+            let minimum_votes_to_propose = mul_div(
+                total_votes,
+                u128::from(self.govern.rules().minimum_stake_part_e3),
+                1000,
+                Rounding::Down,
+            )?;
+
+            // deposit in assets is enough to propose
+            let proposer_votes = self._balance_of(proposer);
+            if deposit_kind == ProposerDeposit::Shares && proposer_votes < minimum_votes_to_propose
+            {
+                return Err(GovernError::InsuficientVotes);
+            }
+            let proposal_hash = hash_proposal(proposal);
+
+            // make a proposer deposit
+            let proposer_deposit = mul_div(
+                minimum_votes_to_propose,
+                self.govern.rules().proposer_deposit_part_e3 as u128,
+                1000,
+                Rounding::Down,
+            )?;
+            // create proposal
+            let proposal_id = self.govern.register_new_proposal(
+                proposer,
+                &proposal_hash,
+                proposal.earliest_execution,
+                &proposal.description_url,
+                &proposal.description_hash,
+                total_votes,
+                self.counter.counter(),
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+                commit_reveal,
+            )?;
+
+            match deposit_kind {
+                ProposerDeposit::Shares => {
+                    self.lock.lock(&proposal_id, proposer_deposit)?;
+
+                    self._transfer(proposer, &self.env().account_id(), &proposer_deposit)?;
+                    self._sync_votes(proposer)?;
+                }
+                ProposerDeposit::Assets => {
+                    let assets = self._preview_redeem(&proposer_deposit)?;
+                    self._take_assets_deposit(proposer, &proposal_id, assets)?;
+                }
+                ProposerDeposit::FixedAssets(assets) => {
+                    self._take_assets_deposit(proposer, &proposal_id, assets)?;
+                }
+            }
+
+            ink::env::emit_event::<DefaultEnvironment, ProposalCreated>(ProposalCreated {
+                proposal_id,
+                proposal_hash,
+                proposal: proposal.clone(),
+            });
+            Ok(proposal_id)
+        }
+
+        fn _create_draft(
+            &mut self,
+            proposer: &AccountId,
+            proposal: &Proposal,
+        ) -> Result<ProposalHash, GovernError> {
+            if self._balance_of(proposer) == 0 {
+                return Err(GovernError::InsuficientVotes);
+            }
+            let proposal_hash = hash_proposal(proposal);
+            if self.govern.proposal_hash_to_id(&proposal_hash).is_some() {
+                return Err(GovernError::ProposalAlreadyExists);
+            }
+
+            self.sponsorship
+                .create_draft(proposer, &proposal_hash, proposal)?;
+
+            ink::env::emit_event::<DefaultEnvironment, DraftCreated>(DraftCreated {
+                proposal_hash,
+                proposer: *proposer,
+                proposal: proposal.clone(),
+            });
+            Ok(proposal_hash)
+        }
+
+        fn _sponsor(
+            &mut self,
+            sponsor: &AccountId,
+            proposal_hash: &ProposalHash,
+            amount: Balance,
+        ) -> Result<(), GovernError> {
+            if amount == 0 || self._balance_of(sponsor) < amount {
+                return Err(GovernError::InsuficientVotes);
+            }
+
+            // sponsor deposits the same part of pledged votes as the proposer does
+            let deposit = mul_div(
+                amount,
+                self.govern.rules().proposer_deposit_part_e3 as u128,
+                1000,
+                Rounding::Down,
+            )?;
+            let draft = self
+                .sponsorship
+                .sponsor(proposal_hash, sponsor, amount, deposit)?;
+
+            self._transfer(sponsor, &self.env().account_id(), &deposit)?;
+            self._sync_votes(sponsor)?;
+
+            ink::env::emit_event::<DefaultEnvironment, Sponsored>(Sponsored {
+                proposal_hash: *proposal_hash,
+                sponsor: *sponsor,
+                pledged: amount,
+                deposit,
+            });
+
+            let total_votes = self._total_supply();
+            let minimum_votes_to_propose = mul_div(
+                total_votes,
+                u128::from(self.govern.rules().minimum_stake_part_e3),
+                1000,
+                Rounding::Down,
+            )?;
+            if draft.pledged < minimum_votes_to_propose {
+                return Ok(());
+            }
+
+            // activate the draft
+            let (draft, proposal) = self.sponsorship.take_draft(proposal_hash)?;
+            let proposal_id = self.govern.register_new_proposal(
+                &draft.proposer,
+                proposal_hash,
+                proposal.earliest_execution,
+                &proposal.description_url,
+                &proposal.description_hash,
+                total_votes,
+                self.counter.counter(),
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+                false,
+            )?;
+
+            ink::env::emit_event::<DefaultEnvironment, ProposalCreated>(ProposalCreated {
+                proposal_id,
+                proposal_hash: *proposal_hash,
+                proposal,
+            });
+            Ok(())
+        }
+
+        fn _unsponsor(
+            &mut self,
+            sponsor: &AccountId,
+            proposal_hash: &ProposalHash,
+        ) -> Result<(), GovernError> {
+            let sponsorship = self.sponsorship.unsponsor(proposal_hash, sponsor)?;
+
+            self._transfer(&self.env().account_id(), sponsor, &sponsorship.deposit)?;
+            self._sync_votes(sponsor)?;
+
+            ink::env::emit_event::<DefaultEnvironment, Unsponsored>(Unsponsored {
+                proposal_hash: *proposal_hash,
+                sponsor: *sponsor,
+                pledged: sponsorship.pledged,
+                deposit: sponsorship.deposit,
+            });
+            Ok(())
+        }
+
+        fn _cast_vote(
+            &mut self,
+            voter: &AccountId,
+            proposal_id: ProposalId,
+            vote: Vote,
+            #[allow(unused_variables)] reason: Vec<u8>,
+        ) -> Result<(), GovernError> {
+            if self
+                .govern
+                .state_of(&proposal_id)
+                .ok_or(GovernError::ProposalDoesntExist)?
+                .commit_reveal
+            {
+                return Err(GovernError::WrongVotingMode);
+            }
+
+            self._update_vote(voter, proposal_id, vote)
+        }
+
+        fn _commit_vote(
+            &mut self,
+            voter: &AccountId,
+            proposal_id: ProposalId,
+            vote_hash: Hash,
+        ) -> Result<(), GovernError> {
+            self.govern.commit_vote(
+                voter,
+                &proposal_id,
+                &vote_hash,
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+            )?;
+
+            ink::env::emit_event::<DefaultEnvironment, VoteCommitted>(VoteCommitted {
+                account: *voter,
+                proposal_id,
+            });
+
+            Ok(())
+        }
+
+        fn _reveal_vote(
+            &mut self,
+            voter: &AccountId,
+            proposal_id: ProposalId,
+            vote: Vote,
+            salt: Hash,
+        ) -> Result<(), GovernError> {
+            let commit = self.govern.take_commit(
+                voter,
+                &proposal_id,
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+            )?;
+
+            if commit != hash_vote(voter, &proposal_id, &vote, &salt) {
+                return Err(GovernError::CommitMismatch);
+            }
+
+            self._update_vote(voter, proposal_id, vote)
+        }
+
+        fn _finalize(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError> {
+            let status = self.govern.finalize(
+                proposal_id,
+                self.counter.counter(),
+                self.pause
+                    .voting_paused_duration(self.env().block_timestamp())?,
+            )?;
+
+            let locked_assets = self.locked_assets.locked(proposal_id);
+            // slashed deposit in assets stays in the vault and is shared among stakers
+            self.locked_assets.unlock(proposal_id, locked_assets)?;
+
+            // return the proposer deposit if proposal was not 'DefeatedWithSlash'
+            if status != ProposalStatus::DefeatedWithSlash {
+                let locked = self.lock.locked(proposal_id);
+                let proposer = self.govern.state_of(proposal_id).unwrap().proposer;
+                if locked > 0 {
+                    self.lock.unlock(proposal_id, locked)?;
+                    self._transfer(&self.env().account_id(), &proposer, &locked)?;
+                    self._sync_votes(&proposer)?;
+                }
+                if locked_assets > 0 {
+                    self.vault
+                        .asset()
+                        .call_mut()
+                        .transfer(proposer, locked_assets, Vec::<u8>::new())
+                        .call_v1()
+                        .invoke()?;
+                }
+            }
+
+            // sponsors' deposits are returned or slashed the same way as the proposer's deposit
+            let proposal_hash = self.govern.proposal_id_to_hash(proposal_id).unwrap();
+            let sponsorships = self.sponsorship.take_sponsorships(&proposal_hash);
+            if status != ProposalStatus::DefeatedWithSlash {
+                for sponsorship in sponsorships {
+                    self._transfer(
+                        &self.env().account_id(),
+                        &sponsorship.sponsor,
+                        &sponsorship.deposit,
+                    )?;
+                    self._sync_votes(&sponsorship.sponsor)?;
+                }
+            }
+
+            ink::env::emit_event::<DefaultEnvironment, ProposalFinalized>(ProposalFinalized {
+                proposal_id: *proposal_id,
+                status,
+            });
+            Ok(())
+        }
+
+        fn _execute(&mut self, proposal: &Proposal) -> Result<(), GovernError> {
+            let proposal_hash = hash_proposal(proposal);
+
+            let proposal_id = &self
+                .govern
+                .proposal_hash_to_id(&proposal_hash)
+                .ok_or(GovernError::ProposalDoesntExist)?;
+
+            self.govern.mark_as_executed(proposal_id)?;
+
+            for tx in &proposal.transactions {
+                self.flush();
+
+                // let call = tx.clone().build_call();
+                let call = ink::env::call::build_call::<DefaultEnvironment>()
+                    .call_v1(tx.callee)
+                    .transferred_value(tx.transferred_value)
+                    .call_flags(ink::env::CallFlags::ALLOW_REENTRY)
+                    .exec_input(
+                        ink::env::call::ExecutionInput::new(tx.selector.into())
+                            .push_arg(OpaqueTypes(tx.input.clone())),
+                    )
+                    .returns::<OpaqueTypes>()
+                    .try_invoke();
+                match call {
+                    Ok(contract_res) => match contract_res {
+                        Ok(_) => Ok(()),
+                        Err(e) => Err(GovernError::UnderlyingTransactionReverted(
+                            ink::prelude::format!("{:?}", e),
+                        )),
+                    },
+                    Err(e) => match e {
+                        ink::env::Error::Decode(err) => {
+                            Err(GovernError::UnderlyingTransactionReverted(
+                                ink::prelude::format!("Decode Error: {:?}", err),
+                            ))
+                        }
+                        _ => Err(GovernError::UnderlyingTransactionReverted(
+                            ink::prelude::format!("{:?}", e),
+                        )),
+                    },
+                }?;
+                self.load();
+            }
+
+            ink::env::emit_event::<DefaultEnvironment, ProposalExecuted>(ProposalExecuted {
+                proposal_id: *proposal_id,
+            });
+
+            Ok(())
+        }
+
+        fn _force_unstake(
+            &mut self,
+            account: &AccountId,
+            proposal_id: &ProposalId,
+        ) -> Result<(), GovernError> {
+            self.govern.force_unstake(account, proposal_id)?;
+            let balance = self._balance_of(account);
+            let assets = self._preview_redeem(&balance)?;
+            self._withdraw(
+                &self.env().account_id(),
+                account,
+                account,
+                &assets,
+                &balance,
+            )?;
+
+            Ok(())
+        }
+    }
+
+    impl ProvideVestScheduleInfo for AbaxGovernor {
+        #[ink(message)]
+        fn get_waiting_and_vesting_durations(&self) -> (Timestamp, Timestamp) {
+            (self.unstake.unstake_period(), 0)
+        }
+    }
+
+    fn _ensure_voting_rules_and_unstake_period_are_valid(
+        rules: &VotingRules,
+        commit_reveal_rules: &CommitRevealRules,
+        unstake_period: Timestamp,
+    ) -> Result<(), GovernError> {
+        if rules
+            .initial_period
+            .checked_add(rules.flat_period)
+            .ok_or(MathError::Overflow)?
+            .checked_add(rules.final_period)
+            .ok_or(MathError::Overflow)?
+            .checked_add(commit_reveal_rules.reveal_period)
+            .ok_or(MathError::Overflow)?
+            > unstake_period
+        {
+            return Err(GovernError::UnstakeShorterThanVotingPeriod);
+        }
+        if rules.minimum_quorum_e3 > 1000 {
+            return Err(GovernError::InvalidQuorum);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, Timestamp},
+};
+
+use crate::modules::govern::{
+    helpers::mul_div::mul_div_r_down,
+    traits::{ProposalState, VotingPhase, VotingRules},
+};
+
+/// Returns the end of the final period of the proposal.
+pub fn final_period_end(
+    state: &ProposalState,
+    rules: &VotingRules,
+) -> Result<Timestamp, MathError> {
+    state
+        .start
+        .checked_add(rules.initial_period)
+        .ok_or(MathError::Overflow)?
+        .checked_add(rules.flat_period)
+        .ok_or(MathError::Overflow)?
+        .checked_add(rules.final_period)
+        .ok_or(MathError::Overflow)
+}
+
+/// Returns the phase of voting on the proposal at `now`.
+pub fn voting_phase(
+    state: &ProposalState,
+    rules: &VotingRules,
+    now: Timestamp,
+) -> Result<VotingPhase, MathError> {
+    let initial_period_end = state
+        .start
+        .checked_add(rules.initial_period)
+        .ok_or(MathError::Overflow)?;
+    let flat_period_end = initial_period_end
+        .checked_add(rules.flat_period)
+        .ok_or(MathError::Overflow)?;
+
+    Ok(if now <= initial_period_end {
+        VotingPhase::Initial
+    } else if now <= flat_period_end {
+        VotingPhase::Flat
+    } else if now <= final_period_end(state, rules)? {
+        VotingPhase::Final
+    } else {
+        VotingPhase::Ended
+    })
+}
+
+fn total_votes(state: &ProposalState, current_counter: u128) -> Result<Balance, MathError> {
+    let counter_diff = current_counter.overflowing_sub(state.counter_at_start).0;
+    state
+        .votes_at_start
+        .checked_add(counter_diff)
+        .ok_or(MathError::Overflow)
+}
+
+pub fn minimum_to_finalize(
+    state: &ProposalState,
+    rules: &VotingRules,
+    now: Timestamp,
+    current_counter: u128,
+) -> Result<Balance, MathError> {
+    let initial_period_end = state
+        .start
+        .checked_add(rules.initial_period)
+        .ok_or(MathError::Overflow)?;
+    let flat_period_end = initial_period_end
+        .checked_add(rules.flat_period)
+        .ok_or(MathError::Overflow)?;
+    let final_period_end = flat_period_end
+        .checked_add(rules.final_period)
+        .ok_or(MathError::Overflow)?;
+
+    let total_votes = total_votes(state, current_counter)?;
+
+    //print all of args and above
+
+    let half_total_votes = total_votes.checked_div(2).ok_or(MathError::DivByZero)?;
+
+    Ok(if now <= initial_period_end {
+        let time_in_initial_period = initial_period_end
+            .checked_sub(now)
+            .ok_or(MathError::Underflow)? as u128;
+        let over_half = mul_div_r_down(
+            half_total_votes,
+            time_in_initial_period,
+            rules.initial_period as u128,
+        )?;
+        half_total_votes
+            .checked_add(over_half)
+            .ok_or(MathError::Overflow)?
+    } else if now <= flat_period_end {
+        half_total_votes
+    } else if now <= final_period_end {
+        let time_in_final_period = final_period_end
+            .checked_sub(now)
+            .ok_or(MathError::Underflow)? as u128;
+        mul_div_r_down(
+            half_total_votes,
+            time_in_final_period,
+            rules.final_period as u128,
+        )?
+    } else {
+        0
+    })
+}
+
+/// Returns minimal amount of votes that has to be casted to finalize the proposal other than `Defeated` because of too low participation.
+pub fn minimum_quorum(
+    state: &ProposalState,
+    rules: &VotingRules,
+    current_counter: u128,
+) -> Result<Balance, MathError> {
+    mul_div_r_down(
+        total_votes(state, current_counter)?,
+        rules.minimum_quorum_e3 as u128,
+        1000,
+    )
+}
+
+/// Returns `now` shifted back by the duration during which voting was paused since the start of the proposal.
+pub fn voting_time(
+    state: &ProposalState,
+    now: Timestamp,
+    voting_paused_duration: Timestamp,
+) -> Result<Timestamp, MathError> {
+    let paused_since_start = voting_paused_duration
+        .checked_sub(state.voting_paused_at_start)
+        .ok_or(MathError::Underflow)?;
+    now.checked_sub(paused_since_start)
+        .ok_or(MathError::Underflow)
+}
@@ -0,0 +1,3 @@
+pub mod finalization;
+pub mod hashes;
+pub mod mul_div;
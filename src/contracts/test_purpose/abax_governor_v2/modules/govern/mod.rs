@@ -0,0 +1,3 @@
+pub mod helpers;
+pub mod storage;
+pub mod traits;
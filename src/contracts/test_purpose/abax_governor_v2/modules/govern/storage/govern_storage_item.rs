@@ -0,0 +1,637 @@
+use ink::{
+    env::DefaultEnvironment,
+    prelude::{string::ToString, vec::Vec},
+    primitives::AccountId,
+    storage::Mapping,
+};
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, Hash, String, Timestamp},
+};
+
+use crate::modules::govern::{
+    helpers::finalization::{
+        final_period_end, minimum_quorum, minimum_to_finalize, voting_phase, voting_time,
+    },
+    traits::{
+        CommitRevealRules, GovernError, ProposalId, ProposalState, ProposalStateV1, ProposalStatus,
+        ProposerDeposit, UserVote, Vote, VotingRules,
+    },
+};
+
+#[derive(Debug)]
+#[pendzl::storage_item]
+pub struct GovernData {
+    #[lazy]
+    rules: VotingRules,
+    #[lazy]
+    commit_reveal_rules: CommitRevealRules,
+    #[lazy]
+    proposer_deposit: ProposerDeposit,
+    #[lazy]
+    active_proposals: u32,
+    /// ids of active proposals. Its length is bounded by `VotingRules::maximum_active_proposals`.
+    #[lazy]
+    active_proposal_ids: Vec<ProposalId>,
+    #[lazy]
+    finalized_proposals: u32,
+    #[lazy]
+    executed_proposals: u32,
+    #[lazy]
+    next_proposal_id: ProposalId,
+    proposal_id_to_hash: Mapping<ProposalId, Hash>,
+    proposal_id_to_description_url: Mapping<ProposalId, String>,
+    proposal_id_to_description_hash: Mapping<ProposalId, Hash>,
+    proposal_hash_to_id: Mapping<Hash, ProposalId>,
+    /// states in the layout of storage version 1. Emptied by the migration to version 2.
+    state: Mapping<ProposalId, ProposalStateV1>,
+    state_v2: Mapping<ProposalId, ProposalState>,
+    votes: Mapping<(AccountId, ProposalId), UserVote>,
+    /// hashes of not yet revealed votes on proposals with commit-reveal voting
+    commits: Mapping<(AccountId, ProposalId), Hash>,
+    /// Last time when the user staked and had no stake before, when user has no stake it should be None.
+    last_stake_timestamp: Mapping<AccountId, Timestamp>,
+    /// Last proposal that account didnt vote and was in consequence force unstaked
+    last_force_unstake: Mapping<AccountId, ProposalId>,
+    /// number of active proposals created by the account
+    active_proposals_of: Mapping<AccountId, u32>,
+    /// Last time when the account created a proposal
+    last_proposal_timestamp: Mapping<AccountId, Timestamp>,
+}
+
+impl GovernData {
+    pub fn new(rules: &VotingRules) -> Self {
+        let mut instance = Self {
+            rules: Default::default(),
+            commit_reveal_rules: Default::default(),
+            proposer_deposit: Default::default(),
+            active_proposals: Default::default(),
+            active_proposal_ids: Default::default(),
+            finalized_proposals: Default::default(),
+            executed_proposals: Default::default(),
+            next_proposal_id: Default::default(),
+            proposal_id_to_hash: Default::default(),
+            proposal_id_to_description_url: Default::default(),
+            proposal_id_to_description_hash: Default::default(),
+            proposal_hash_to_id: Default::default(),
+            state: Default::default(),
+            state_v2: Default::default(),
+            votes: Default::default(),
+            commits: Default::default(),
+            last_stake_timestamp: Default::default(),
+            last_force_unstake: Default::default(),
+            active_proposals_of: Default::default(),
+            last_proposal_timestamp: Default::default(),
+        };
+        instance.rules.set(rules);
+        instance
+    }
+
+    pub fn set_last_stake_timestamp(&mut self, account: &AccountId) {
+        let timestamp = ink::env::block_timestamp::<DefaultEnvironment>();
+        if self.last_stake_timestamp(account).is_some() {
+            return;
+        }
+        self.last_stake_timestamp.insert(account, &timestamp);
+    }
+    pub fn remove_last_stake_timestamp(&mut self, account: &AccountId) {
+        self.last_stake_timestamp.remove(account);
+    }
+    pub fn last_stake_timestamp(&self, account: &AccountId) -> Option<Timestamp> {
+        self.last_stake_timestamp.get(account)
+    }
+    pub fn last_force_unstake(&self, account: &AccountId) -> Option<ProposalId> {
+        self.last_force_unstake.get(account)
+    }
+
+    pub fn rules(&self) -> VotingRules {
+        self.rules.get().unwrap_or_default()
+    }
+
+    pub fn change_rule(&mut self, rules: &VotingRules) {
+        self.rules.set(rules);
+    }
+
+    pub fn commit_reveal_rules(&self) -> CommitRevealRules {
+        self.commit_reveal_rules.get().unwrap_or_default()
+    }
+
+    pub fn change_commit_reveal_rules(&mut self, rules: &CommitRevealRules) {
+        self.commit_reveal_rules.set(rules);
+    }
+
+    pub fn proposer_deposit(&self) -> ProposerDeposit {
+        self.proposer_deposit.get().unwrap_or_default()
+    }
+
+    pub fn change_proposer_deposit(&mut self, deposit: &ProposerDeposit) {
+        self.proposer_deposit.set(deposit);
+    }
+
+    pub fn active_proposals(&self) -> u32 {
+        self.active_proposals.get().unwrap_or_default()
+    }
+
+    pub fn active_proposal_ids(&self) -> Vec<ProposalId> {
+        self.active_proposal_ids.get().unwrap_or_default()
+    }
+
+    pub fn active_proposals_of(&self, account: &AccountId) -> u32 {
+        self.active_proposals_of.get(account).unwrap_or_default()
+    }
+
+    pub fn last_proposal_timestamp(&self, account: &AccountId) -> Option<Timestamp> {
+        self.last_proposal_timestamp.get(account)
+    }
+
+    pub fn finalized_proposals(&self) -> u32 {
+        self.finalized_proposals.get().unwrap_or_default()
+    }
+
+    pub fn executed_proposals(&self) -> u32 {
+        self.executed_proposals.get().unwrap_or_default()
+    }
+
+    pub fn next_proposal_id(&self) -> ProposalId {
+        self.next_proposal_id.get().unwrap_or_default()
+    }
+
+    pub fn proposal_id_to_hash(&self, proposal_id: &ProposalId) -> Option<Hash> {
+        self.proposal_id_to_hash.get(proposal_id)
+    }
+
+    pub fn proposal_hash_to_id(&self, proposal_hash: &Hash) -> Option<ProposalId> {
+        self.proposal_hash_to_id.get(proposal_hash)
+    }
+    pub fn proposal_id_to_description_url(&self, proposal_id: &ProposalId) -> Option<String> {
+        self.proposal_id_to_description_url.get(proposal_id)
+    }
+    pub fn proposal_id_to_description_hash(&self, proposal_id: &ProposalId) -> Option<Hash> {
+        self.proposal_id_to_description_hash.get(proposal_id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_new_proposal(
+        &mut self,
+        proposer: &AccountId,
+        proposal_hash: &Hash,
+        earliest_execution: Option<Timestamp>,
+        description_url: &String,
+        description_hash: &Hash,
+        votes_at_start: Balance,
+        counter_at_start: u128,
+        voting_paused_at_start: Timestamp,
+        commit_reveal: bool,
+    ) -> Result<ProposalId, GovernError> {
+        if self.proposal_hash_to_id(proposal_hash).is_some() {
+            return Err(GovernError::ProposalAlreadyExists);
+        }
+        if commit_reveal && self.commit_reveal_rules().reveal_period == 0 {
+            return Err(GovernError::CommitRevealDisabled);
+        }
+        let rules = self.rules();
+        if self.active_proposals() >= rules.maximum_active_proposals {
+            return Err(GovernError::TooManyActiveProposals);
+        }
+        let active_proposals_of = self.active_proposals_of(proposer);
+        if active_proposals_of >= rules.maximum_active_proposals_per_proposer {
+            return Err(GovernError::TooManyActiveProposalsOfProposer);
+        }
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        if let Some(last_proposal_timestamp) = self.last_proposal_timestamp(proposer) {
+            if now
+                < last_proposal_timestamp
+                    .checked_add(rules.minimum_proposal_interval)
+                    .ok_or(MathError::Overflow)?
+            {
+                return Err(GovernError::ProposalTooSoon);
+            }
+        }
+
+        let proposal_id = self.next_proposal_id();
+        self.next_proposal_id
+            .set(&(proposal_id.checked_add(1).ok_or(MathError::Overflow)?));
+
+        self.proposal_id_to_hash.insert(proposal_id, proposal_hash);
+        self.proposal_hash_to_id.insert(proposal_hash, &proposal_id);
+        self.proposal_id_to_description_url
+            .insert(proposal_id, &description_url.to_string());
+        self.proposal_id_to_description_hash
+            .insert(proposal_id, description_hash);
+
+        self.state_v2.insert(
+            proposal_id,
+            &ProposalState {
+                status: ProposalStatus::Active,
+                force_unstake_possible: false,
+                proposer: *proposer,
+                commit_reveal,
+                start: now,
+                counter_at_start,
+                votes_at_start,
+                voting_paused_at_start,
+                finalized: None,
+                votes_for: 0,
+                votes_against: 0,
+                votes_against_with_slash: 0,
+                votes_abstain: 0,
+                earliest_execution,
+                voters_count: 0,
+                finalization_phase: None,
+                minimum_to_finalize_at_finalization: None,
+                executed: None,
+                last_vote: None,
+            },
+        );
+
+        self.active_proposals.set(
+            &(self
+                .active_proposals()
+                .checked_add(1)
+                .ok_or(MathError::Overflow)?),
+        );
+        let mut active_proposal_ids = self.active_proposal_ids();
+        active_proposal_ids.push(proposal_id);
+        self.active_proposal_ids.set(&active_proposal_ids);
+        self.active_proposals_of.insert(
+            proposer,
+            &(active_proposals_of
+                .checked_add(1)
+                .ok_or(MathError::Overflow)?),
+        );
+        self.last_proposal_timestamp.insert(proposer, &now);
+
+        Ok(proposal_id)
+    }
+
+    pub fn finalize(
+        &mut self,
+        proposal_id: &ProposalId,
+        current_counter: u128,
+        voting_paused_duration: Timestamp,
+    ) -> Result<ProposalStatus, GovernError> {
+        let mut state = self
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+
+        if state.status != ProposalStatus::Active {
+            return Err(GovernError::WrongStatus);
+        }
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        let voting_now = voting_time(&state, now, voting_paused_duration)?;
+
+        // votes are counted only after they are revealed
+        if state.commit_reveal
+            && voting_now
+                <= final_period_end(&state, &self.rules())?
+                    .checked_add(self.commit_reveal_rules().reveal_period)
+                    .ok_or(MathError::Overflow)?
+        {
+            return Err(GovernError::FinalizeCondition);
+        }
+
+        let minimum_quorum = minimum_quorum(&state, &self.rules(), current_counter)?;
+        let minimum_to_finalize =
+            minimum_to_finalize(&state, &self.rules(), voting_now, current_counter)?;
+        let votes_total = state
+            .votes_for
+            .checked_add(state.votes_against)
+            .ok_or(MathError::Overflow)?
+            .checked_add(state.votes_against_with_slash)
+            .ok_or(MathError::Overflow)?
+            .checked_add(state.votes_abstain)
+            .ok_or(MathError::Overflow)?;
+
+        if votes_total < minimum_quorum {
+            // the quorum wasn't reached by the end of the final period
+            if voting_now <= final_period_end(&state, &self.rules())? {
+                return Err(GovernError::FinalizeCondition);
+            }
+            state.status = ProposalStatus::Defeated;
+        } else {
+            // abstaining votes count toward the threshold but don't change the outcome
+            let leading_votes = state.votes_for.max(
+                state
+                    .votes_against
+                    .checked_add(state.votes_against_with_slash)
+                    .ok_or(MathError::Overflow)?,
+            );
+            if leading_votes
+                .checked_add(state.votes_abstain)
+                .ok_or(MathError::Overflow)?
+                < minimum_to_finalize
+            {
+                return Err(GovernError::FinalizeCondition);
+            }
+
+            if state
+                .votes_against
+                .checked_add(state.votes_against_with_slash)
+                .ok_or(MathError::Overflow)?
+                >= state.votes_for
+            {
+                if state.votes_against_with_slash
+                    > state
+                        .votes_against
+                        .checked_add(state.votes_for)
+                        .ok_or(MathError::Overflow)?
+                {
+                    state.status = ProposalStatus::DefeatedWithSlash;
+                } else {
+                    state.status = ProposalStatus::Defeated;
+                }
+            } else if state.votes_for
+                > state
+                    .votes_against
+                    .checked_add(state.votes_against_with_slash)
+                    .ok_or(MathError::Overflow)?
+            {
+                state.status = ProposalStatus::Succeeded;
+            } else {
+                state.status = ProposalStatus::Defeated;
+            }
+        }
+
+        let initital_plus_flat_duration = self
+            .rules()
+            .initial_period
+            .checked_add(self.rules().flat_period)
+            .ok_or(MathError::Overflow)?;
+
+        let is_post_flat_period = voting_now
+            >= state
+                .start
+                .checked_add(initital_plus_flat_duration)
+                .ok_or(MathError::Overflow)?;
+        if is_post_flat_period {
+            state.force_unstake_possible = true;
+        }
+
+        state.finalized = Some(now);
+        state.finalization_phase = Some(voting_phase(&state, &self.rules(), voting_now)?);
+        state.minimum_to_finalize_at_finalization = Some(minimum_to_finalize);
+
+        self.state_v2.insert(proposal_id, &state);
+        self.active_proposals.set(
+            &(self
+                .active_proposals()
+                .checked_sub(1)
+                .ok_or(MathError::Overflow)?),
+        );
+        let mut active_proposal_ids = self.active_proposal_ids();
+        active_proposal_ids.retain(|id| id != proposal_id);
+        self.active_proposal_ids.set(&active_proposal_ids);
+        self.active_proposals_of.insert(
+            state.proposer,
+            &(self
+                .active_proposals_of(&state.proposer)
+                .checked_sub(1)
+                .ok_or(MathError::Underflow)?),
+        );
+        self.finalized_proposals.set(
+            &(self
+                .finalized_proposals()
+                .checked_add(1)
+                .ok_or(MathError::Overflow)?),
+        );
+
+        Ok(state.status)
+    }
+
+    pub fn mark_as_executed(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError> {
+        let mut state = self
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        if state.earliest_execution.unwrap_or_default()
+            > ink::env::block_timestamp::<DefaultEnvironment>()
+        {
+            return Err(GovernError::TooEarlyToExecuteProposal);
+        }
+        if state.status != ProposalStatus::Succeeded {
+            return Err(GovernError::WrongStatus);
+        }
+        state.status = ProposalStatus::Executed;
+        state.executed = Some(ink::env::block_timestamp::<DefaultEnvironment>());
+        self.state_v2.insert(proposal_id, &state);
+        Ok(())
+    }
+
+    /// Moves states of at most `count` proposals starting from `from` from the layout of storage version 1 to the current one.
+    ///
+    /// Returns the id of the next proposal to migrate or None if all proposals are migrated.
+    pub fn migrate_proposal_states(&mut self, from: ProposalId, count: u32) -> Option<ProposalId> {
+        let next_proposal_id = self.next_proposal_id();
+        let until = from.saturating_add(count).min(next_proposal_id);
+        for proposal_id in from..until {
+            if let Some(state) = self.state.take(proposal_id) {
+                self.state_v2
+                    .insert(proposal_id, &ProposalState::from(state));
+            }
+        }
+        if until < next_proposal_id {
+            Some(until)
+        } else {
+            None
+        }
+    }
+
+    pub fn state_of(&self, proposal_id: &ProposalId) -> Option<ProposalState> {
+        self.state_v2.get(proposal_id)
+    }
+
+    pub fn status_of(&self, proposal_id: &ProposalId) -> Option<ProposalStatus> {
+        self.state_of(proposal_id).map(|state| state.status)
+    }
+
+    pub fn vote_of_for(&self, account: &AccountId, proposal_id: &ProposalId) -> Option<UserVote> {
+        self.votes.get((*account, *proposal_id))
+    }
+
+    pub fn update_vote_of_for(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+        vote: &Vote,
+        amount: &Balance,
+    ) -> Result<(), GovernError> {
+        if *amount == 0 {
+            return Err(GovernError::InsuficientVotes);
+        }
+        let mut state = self
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        if state.status != ProposalStatus::Active {
+            return Err(GovernError::WrongStatus);
+        }
+
+        match self.vote_of_for(account, proposal_id) {
+            Some(old_vote) => {
+                let old_tally = tally_of(&mut state, &old_vote.vote);
+                *old_tally = old_tally
+                    .checked_sub(old_vote.amount)
+                    .ok_or(MathError::Underflow)?;
+            }
+            None => {
+                state.voters_count = state
+                    .voters_count
+                    .checked_add(1)
+                    .ok_or(MathError::Overflow)?
+            }
+        }
+        let tally = tally_of(&mut state, vote);
+        *tally = tally.checked_add(*amount).ok_or(MathError::Overflow)?;
+        state.last_vote = Some(ink::env::block_timestamp::<DefaultEnvironment>());
+
+        let new_vote = UserVote {
+            vote: *vote,
+            amount: *amount,
+        };
+
+        self.votes.insert((*account, *proposal_id), &new_vote);
+
+        self.state_v2.insert(proposal_id, &state);
+        Ok(())
+    }
+
+    /// Sets the amount of the existing vote of `account` on active `proposal_id` to `amount` and updates the tallies accordingly.
+    /// Does nothing if `account` hasn't voted.
+    pub fn sync_vote_amount(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+        amount: &Balance,
+    ) -> Result<(), MathError> {
+        let mut user_vote = match self.vote_of_for(account, proposal_id) {
+            Some(user_vote) => user_vote,
+            None => return Ok(()),
+        };
+        let mut state = match self.state_of(proposal_id) {
+            Some(state) if state.status == ProposalStatus::Active => state,
+            _ => return Ok(()),
+        };
+
+        let tally = tally_of(&mut state, &user_vote.vote);
+        *tally = tally
+            .checked_sub(user_vote.amount)
+            .ok_or(MathError::Underflow)?
+            .checked_add(*amount)
+            .ok_or(MathError::Overflow)?;
+        user_vote.amount = *amount;
+
+        self.votes.insert((*account, *proposal_id), &user_vote);
+        self.state_v2.insert(proposal_id, &state);
+        Ok(())
+    }
+
+    pub fn commit_of_for(&self, account: &AccountId, proposal_id: &ProposalId) -> Option<Hash> {
+        self.commits.get((*account, *proposal_id))
+    }
+
+    /// Stores `vote_hash` of `account` on `proposal_id`. Overrides the previous commit.
+    pub fn commit_vote(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+        vote_hash: &Hash,
+        voting_paused_duration: Timestamp,
+    ) -> Result<(), GovernError> {
+        let state = self.active_commit_reveal_state_of(proposal_id)?;
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        if voting_time(&state, now, voting_paused_duration)?
+            > final_period_end(&state, &self.rules())?
+        {
+            return Err(GovernError::NotCommitPeriod);
+        }
+
+        self.commits.insert((*account, *proposal_id), vote_hash);
+        Ok(())
+    }
+
+    /// Removes and returns the commit of `account` on `proposal_id` if it is the reveal period.
+    pub fn take_commit(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+        voting_paused_duration: Timestamp,
+    ) -> Result<Hash, GovernError> {
+        let state = self.active_commit_reveal_state_of(proposal_id)?;
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        let voting_now = voting_time(&state, now, voting_paused_duration)?;
+        let final_period_end = final_period_end(&state, &self.rules())?;
+        if voting_now <= final_period_end
+            || voting_now
+                > final_period_end
+                    .checked_add(self.commit_reveal_rules().reveal_period)
+                    .ok_or(MathError::Overflow)?
+        {
+            return Err(GovernError::NotRevealPeriod);
+        }
+
+        self.commits
+            .take((*account, *proposal_id))
+            .ok_or(GovernError::NoCommit)
+    }
+
+    fn active_commit_reveal_state_of(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalState, GovernError> {
+        let state = self
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        if state.status != ProposalStatus::Active {
+            return Err(GovernError::WrongStatus);
+        }
+        if !state.commit_reveal {
+            return Err(GovernError::WrongVotingMode);
+        }
+        Ok(state)
+    }
+
+    pub fn force_unstake(
+        &mut self,
+        account: &AccountId,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernError> {
+        let state = self
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+
+        if !state.force_unstake_possible {
+            return Err(GovernError::CantForceUnstake);
+        }
+
+        let finalized = state.finalized.unwrap_or_default();
+        if ink::env::block_timestamp::<DefaultEnvironment>()
+            < finalized
+                .checked_add(self.rules().force_unstake_grace_period)
+                .ok_or(MathError::Overflow)?
+        {
+            return Err(GovernError::ForceUnstakeGracePeriod);
+        }
+        if finalized <= self.last_stake_timestamp(account).unwrap_or_default() {
+            return Err(GovernError::CantForceUnstake);
+        }
+        if self.vote_of_for(account, proposal_id).is_some() {
+            return Err(GovernError::CantForceUnstake);
+        }
+
+        if let Some(last_proposal_id) = self.last_force_unstake.get(account) {
+            if last_proposal_id >= *proposal_id {
+                return Err(GovernError::CantForceUnstake);
+            }
+        }
+        self.last_force_unstake.insert(account, proposal_id);
+        Ok(())
+    }
+}
+
+/// Returns the tally of `state` to which votes of type `vote` are counted.
+fn tally_of<'a>(state: &'a mut ProposalState, vote: &Vote) -> &'a mut Balance {
+    match vote {
+        Vote::Agreed => &mut state.votes_for,
+        Vote::Disagreed => &mut state.votes_against,
+        Vote::DisagreedWithProposerSlashing => &mut state.votes_against_with_slash,
+        Vote::Abstain => &mut state.votes_abstain,
+    }
+}
@@ -0,0 +1,53 @@
+use ink::storage::Mapping;
+use pendzl::{math::errors::MathError, traits::Balance};
+
+use crate::modules::govern::traits::ProposalId;
+
+/// Proposer deposits taken in assets. They are excluded from the vault's total assets.
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct LockedAssetsData {
+    locked: Mapping<ProposalId, Balance>,
+    #[lazy]
+    total_locked: Balance,
+}
+
+impl LockedAssetsData {
+    pub fn locked(&self, proposal_id: &ProposalId) -> Balance {
+        self.locked.get(proposal_id).unwrap_or_default()
+    }
+
+    pub fn total_locked(&self) -> Balance {
+        self.total_locked.get().unwrap_or_default()
+    }
+
+    pub fn lock(&mut self, proposal_id: &ProposalId, amount: Balance) -> Result<(), MathError> {
+        let mut locked = self.locked(proposal_id);
+        locked = locked.checked_add(amount).ok_or(MathError::Overflow)?;
+        self.locked.insert(proposal_id, &locked);
+        self.total_locked.set(
+            &self
+                .total_locked()
+                .checked_add(amount)
+                .ok_or(MathError::Overflow)?,
+        );
+        Ok(())
+    }
+
+    pub fn unlock(&mut self, proposal_id: &ProposalId, amount: Balance) -> Result<(), MathError> {
+        let mut locked = self.locked(proposal_id);
+        locked = locked.checked_sub(amount).ok_or(MathError::Underflow)?;
+        if locked > 0 {
+            self.locked.insert(proposal_id, &locked);
+        } else {
+            self.locked.remove(proposal_id);
+        }
+        self.total_locked.set(
+            &self
+                .total_locked()
+                .checked_sub(amount)
+                .ok_or(MathError::Underflow)?,
+        );
+        Ok(())
+    }
+}
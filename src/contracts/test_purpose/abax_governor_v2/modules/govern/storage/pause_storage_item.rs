@@ -0,0 +1,112 @@
+use pendzl::{math::errors::MathError, traits::Timestamp};
+
+use crate::modules::govern::traits::{GovernError, Pause, PausedMessages};
+
+const DAY: Timestamp = 24 * 60 * 60 * 1000;
+/// maximal duration of a single pause. After that time the pause expires automatically.
+pub const MAXIMAL_PAUSE_DURATION: Timestamp = 14 * DAY;
+/// minimal time between the end of a pause and the start of the next one.
+pub const PAUSE_COOLDOWN: Timestamp = 14 * DAY;
+
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct PauseData {
+    #[lazy]
+    pause: Option<Pause>,
+    /// total duration during which voting was paused, counted up to `settled_at`.
+    #[lazy]
+    voting_paused_duration: Timestamp,
+    #[lazy]
+    settled_at: Timestamp,
+}
+
+impl PauseData {
+    pub fn pause(&self) -> Option<Pause> {
+        self.pause.get().flatten()
+    }
+
+    pub fn is_paused(&self, now: Timestamp) -> Option<PausedMessages> {
+        self.pause()
+            .filter(|pause| now < pause.end)
+            .map(|pause| pause.messages)
+    }
+
+    /// Returns total duration during which voting was paused up to `now`.
+    pub fn voting_paused_duration(&self, now: Timestamp) -> Result<Timestamp, MathError> {
+        let settled = self.voting_paused_duration.get().unwrap_or_default();
+        let pause = match self.pause() {
+            Some(pause) if pause.messages.vote => pause,
+            _ => return Ok(settled),
+        };
+        let from = pause.start.max(self.settled_at.get().unwrap_or_default());
+        let to = pause.end.min(now);
+        if to <= from {
+            return Ok(settled);
+        }
+        settled
+            .checked_add(to.checked_sub(from).ok_or(MathError::Underflow)?)
+            .ok_or(MathError::Overflow)
+    }
+
+    /// Pauses `messages` until `now + duration`.
+    /// If there is an ongoing pause, it is updated. Its end can't be further than `MAXIMAL_PAUSE_DURATION` from its start.
+    pub fn set_pause(
+        &mut self,
+        messages: &PausedMessages,
+        duration: Timestamp,
+        now: Timestamp,
+    ) -> Result<Pause, GovernError> {
+        let end = now.checked_add(duration).ok_or(MathError::Overflow)?;
+        let start = match self.pause() {
+            Some(pause) if now < pause.end => pause.start,
+            Some(pause) => {
+                if now
+                    < pause
+                        .end
+                        .checked_add(PAUSE_COOLDOWN)
+                        .ok_or(MathError::Overflow)?
+                {
+                    return Err(GovernError::PauseCooldown);
+                }
+                now
+            }
+            None => now,
+        };
+
+        if end
+            > start
+                .checked_add(MAXIMAL_PAUSE_DURATION)
+                .ok_or(MathError::Overflow)?
+        {
+            return Err(GovernError::PauseTooLong);
+        }
+
+        self.settle(now)?;
+        let pause = Pause {
+            messages: *messages,
+            start,
+            end,
+        };
+        self.pause.set(&Some(pause));
+        Ok(pause)
+    }
+
+    /// Ends the ongoing pause at `now`.
+    pub fn unpause(&mut self, now: Timestamp) -> Result<(), GovernError> {
+        let mut pause = match self.pause() {
+            Some(pause) if now < pause.end => pause,
+            _ => return Err(GovernError::NotPaused),
+        };
+        self.settle(now)?;
+        pause.end = now;
+        self.pause.set(&Some(pause));
+        Ok(())
+    }
+
+    fn settle(&mut self, now: Timestamp) -> Result<(), MathError> {
+        let voting_paused_duration = self.voting_paused_duration(now)?;
+        self.voting_paused_duration.set(&voting_paused_duration);
+        self.settled_at.set(&now);
+        Ok(())
+    }
+}
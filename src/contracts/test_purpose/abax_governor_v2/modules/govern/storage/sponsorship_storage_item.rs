@@ -0,0 +1,154 @@
+use ink::{prelude::vec::Vec, storage::Mapping};
+use pendzl::{
+    math::errors::MathError,
+    traits::{AccountId, Balance},
+};
+
+use crate::modules::govern::traits::{Draft, GovernError, Proposal, ProposalHash, Sponsorship};
+
+/// maximal number of sponsors of a single proposal.
+pub const MAXIMAL_SPONSORS: usize = 32;
+
+#[derive(Debug, Default)]
+#[pendzl::storage_item]
+pub struct SponsorshipData {
+    drafts: Mapping<ProposalHash, Draft>,
+    draft_proposals: Mapping<ProposalHash, Proposal>,
+    /// sponsorships of drafts and of proposals activated from drafts that weren't finalized yet.
+    sponsorships: Mapping<ProposalHash, Vec<Sponsorship>>,
+}
+
+impl SponsorshipData {
+    pub fn draft(&self, proposal_hash: &ProposalHash) -> Option<Draft> {
+        self.drafts.get(proposal_hash)
+    }
+
+    pub fn sponsorships(&self, proposal_hash: &ProposalHash) -> Vec<Sponsorship> {
+        self.sponsorships.get(proposal_hash).unwrap_or_default()
+    }
+
+    pub fn create_draft(
+        &mut self,
+        proposer: &AccountId,
+        proposal_hash: &ProposalHash,
+        proposal: &Proposal,
+    ) -> Result<(), GovernError> {
+        if self.drafts.contains(proposal_hash) {
+            return Err(GovernError::DraftAlreadyExists);
+        }
+        self.drafts.insert(
+            proposal_hash,
+            &Draft {
+                proposer: *proposer,
+                pledged: 0,
+                deposit: 0,
+            },
+        );
+        self.draft_proposals.insert(proposal_hash, proposal);
+        Ok(())
+    }
+
+    /// Adds `pledged` votes and `deposit` shares of `sponsor` to the draft. Returns the updated draft.
+    pub fn sponsor(
+        &mut self,
+        proposal_hash: &ProposalHash,
+        sponsor: &AccountId,
+        pledged: Balance,
+        deposit: Balance,
+    ) -> Result<Draft, GovernError> {
+        let mut draft = self
+            .draft(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        let mut sponsorships = self.sponsorships(proposal_hash);
+
+        match sponsorships.iter_mut().find(|s| s.sponsor == *sponsor) {
+            Some(sponsorship) => {
+                sponsorship.pledged = sponsorship
+                    .pledged
+                    .checked_add(pledged)
+                    .ok_or(MathError::Overflow)?;
+                sponsorship.deposit = sponsorship
+                    .deposit
+                    .checked_add(deposit)
+                    .ok_or(MathError::Overflow)?;
+            }
+            None => {
+                if sponsorships.len() >= MAXIMAL_SPONSORS {
+                    return Err(GovernError::TooManySponsors);
+                }
+                sponsorships.push(Sponsorship {
+                    sponsor: *sponsor,
+                    pledged,
+                    deposit,
+                });
+            }
+        }
+
+        draft.pledged = draft
+            .pledged
+            .checked_add(pledged)
+            .ok_or(MathError::Overflow)?;
+        draft.deposit = draft
+            .deposit
+            .checked_add(deposit)
+            .ok_or(MathError::Overflow)?;
+
+        self.drafts.insert(proposal_hash, &draft);
+        self.sponsorships.insert(proposal_hash, &sponsorships);
+        Ok(draft)
+    }
+
+    /// Removes sponsorship of `sponsor` from the draft and returns it.
+    pub fn unsponsor(
+        &mut self,
+        proposal_hash: &ProposalHash,
+        sponsor: &AccountId,
+    ) -> Result<Sponsorship, GovernError> {
+        let mut draft = self
+            .draft(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        let mut sponsorships = self.sponsorships(proposal_hash);
+        let index = sponsorships
+            .iter()
+            .position(|s| s.sponsor == *sponsor)
+            .ok_or(GovernError::NotSponsor)?;
+        let sponsorship = sponsorships.swap_remove(index);
+
+        draft.pledged = draft
+            .pledged
+            .checked_sub(sponsorship.pledged)
+            .ok_or(MathError::Underflow)?;
+        draft.deposit = draft
+            .deposit
+            .checked_sub(sponsorship.deposit)
+            .ok_or(MathError::Underflow)?;
+
+        self.drafts.insert(proposal_hash, &draft);
+        if sponsorships.is_empty() {
+            self.sponsorships.remove(proposal_hash);
+        } else {
+            self.sponsorships.insert(proposal_hash, &sponsorships);
+        }
+        Ok(sponsorship)
+    }
+
+    /// Removes the draft so it can be registered as a proposal. Sponsorships are kept until the proposal is finalized.
+    pub fn take_draft(
+        &mut self,
+        proposal_hash: &ProposalHash,
+    ) -> Result<(Draft, Proposal), GovernError> {
+        let draft = self
+            .drafts
+            .take(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        let proposal = self
+            .draft_proposals
+            .take(proposal_hash)
+            .ok_or(GovernError::DraftDoesntExist)?;
+        Ok((draft, proposal))
+    }
+
+    pub fn take_sponsorships(&mut self, proposal_hash: &ProposalHash) -> Vec<Sponsorship> {
+        self.sponsorships.take(proposal_hash).unwrap_or_default()
+    }
+}
@@ -0,0 +1,60 @@
+use pendzl::traits::String;
+pub use pendzl::{
+    contracts::{access_control::AccessControlError, psp22::PSP22Error},
+    math::errors::MathError,
+};
+
+#[derive(scale::Encode, scale::Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum GovernError {
+    MathError(MathError),
+    PSP22Error(PSP22Error),
+    InsuficientVotes,
+    ProposalAlreadyExists,
+    ProposalDoesntExist,
+    WrongStatus,
+    TooEarlyToExecuteProposal,
+    FinalizeCondition,
+    UnderlyingTransactionReverted(String),
+    CantForceUnstake,
+    ForceUnstakeGracePeriod,
+    AccessControlError(AccessControlError),
+    UnstakeShorterThanVotingPeriod,
+    InvalidQuorum,
+    TooManyActiveProposals,
+    TooManyActiveProposalsOfProposer,
+    ProposalTooSoon,
+    DraftAlreadyExists,
+    DraftDoesntExist,
+    TooManySponsors,
+    NotSponsor,
+    CommitRevealDisabled,
+    WrongVotingMode,
+    NotCommitPeriod,
+    NotRevealPeriod,
+    NoCommit,
+    CommitMismatch,
+    Paused,
+    NotPaused,
+    PauseTooLong,
+    PauseCooldown,
+    AlreadyMigrated,
+}
+
+impl From<MathError> for GovernError {
+    fn from(error: MathError) -> Self {
+        GovernError::MathError(error)
+    }
+}
+
+impl From<PSP22Error> for GovernError {
+    fn from(error: PSP22Error) -> Self {
+        GovernError::PSP22Error(error)
+    }
+}
+
+impl From<AccessControlError> for GovernError {
+    fn from(error: AccessControlError) -> Self {
+        GovernError::AccessControlError(error)
+    }
+}
@@ -0,0 +1,111 @@
+use pendzl::traits::{AccountId, Balance, Timestamp};
+
+use super::{
+    CommitRevealRules, PausedMessages, Proposal, ProposalHash, ProposalId, ProposalStatus,
+    ProposerDeposit, Vote, VotingRules,
+};
+
+#[ink::event]
+pub struct ProposalCreated {
+    #[ink(topic)]
+    pub proposal_id: ProposalId,
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub proposal: Proposal,
+}
+
+#[ink::event]
+pub struct DraftCreated {
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub proposer: AccountId,
+    pub proposal: Proposal,
+}
+
+#[ink::event]
+pub struct Sponsored {
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub sponsor: AccountId,
+    pub pledged: Balance,
+    pub deposit: Balance,
+}
+
+#[ink::event]
+pub struct Unsponsored {
+    #[ink(topic)]
+    pub proposal_hash: ProposalHash,
+    #[ink(topic)]
+    pub sponsor: AccountId,
+    pub pledged: Balance,
+    pub deposit: Balance,
+}
+
+#[ink::event]
+pub struct ProposalFinalized {
+    #[ink(topic)]
+    pub proposal_id: ProposalId,
+    #[ink(topic)]
+    pub status: ProposalStatus,
+}
+
+#[ink::event]
+pub struct ProposalExecuted {
+    #[ink(topic)]
+    pub proposal_id: ProposalId,
+}
+
+#[ink::event]
+pub struct VoteCasted {
+    #[ink(topic)]
+    pub account: AccountId,
+    #[ink(topic)]
+    pub proposal_id: ProposalId,
+    pub vote: Vote,
+}
+
+#[ink::event]
+pub struct VoteCommitted {
+    #[ink(topic)]
+    pub account: AccountId,
+    #[ink(topic)]
+    pub proposal_id: ProposalId,
+}
+
+#[ink::event]
+pub struct VotingRulesChanged {
+    pub rules: VotingRules,
+}
+
+#[ink::event]
+pub struct CommitRevealRulesChanged {
+    pub rules: CommitRevealRules,
+}
+
+#[ink::event]
+pub struct ProposerDepositChanged {
+    pub deposit: ProposerDeposit,
+}
+
+#[ink::event]
+pub struct UnstakePeriodChanged {
+    pub unstake_period: Timestamp,
+}
+
+#[ink::event]
+pub struct Paused {
+    pub messages: PausedMessages,
+    pub until: Timestamp,
+}
+
+#[ink::event]
+pub struct Unpaused {}
+
+#[ink::event]
+pub struct Migrated {
+    pub from: u16,
+    pub to: u16,
+}
@@ -0,0 +1,163 @@
+pub type ProposalHash = Hash;
+
+#[ink::trait_definition]
+pub trait AbaxGovern {
+    /// Propose a `proposal`.
+    ///
+    /// On success emits `ProposalCreated` event.
+    ///
+    /// # Returns
+    ///
+    /// Returns `ProposalId` of the created proposal.
+    ///
+    /// # Errors
+    /// Returns `ProposalAlreadyExists` if `propsal` with the same `proposal_description` exists,
+    /// Returns `InsuficientVotes` if `caller` has insufficient amount of votes to create a proposal and the deposit is taken in shares.
+    /// Returns `TooManyActiveProposals` if the maximal number of active proposals is reached.
+    /// Returns `TooManyActiveProposalsOfProposer` if `caller` has the maximal number of active proposals.
+    /// Returns `ProposalTooSoon` if the minimal interval since the last proposal of `caller` hasn't passed.
+    /// Returns `PSP22Error` if the deposit is taken in assets and `caller` hasn't approved enough of them.
+    #[ink(message)]
+    fn propose(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError>;
+
+    /// Propose a `proposal` with commit-reveal voting.
+    /// Votes are committed with `commit_vote` until the end of the final period and counted only when revealed with `reveal_vote` during the reveal period.
+    ///
+    /// On success emits `ProposalCreated` event.
+    ///
+    /// # Returns
+    ///
+    /// Returns `ProposalId` of the created proposal.
+    ///
+    /// # Errors
+    /// Returns `ProposalAlreadyExists` if `propsal` with the same `proposal_description` exists,
+    /// Returns `InsuficientVotes` if `caller` has insufficient amount of votes to create a proposal.
+    /// Returns `CommitRevealDisabled` if the reveal period is 0.
+    #[ink(message)]
+    fn propose_commit_reveal(&mut self, proposal: Proposal) -> Result<ProposalId, GovernError>;
+
+    /// Creates a draft of `proposal` that becomes an active proposal once sponsors pledge enough votes.
+    /// `caller` becomes the proposer.
+    ///
+    /// On success emits `DraftCreated` event.
+    ///
+    /// # Errors
+    /// Returns `InsuficientVotes` if `caller` has no votes.
+    /// Returns `ProposalAlreadyExists` if `proposal` already exists.
+    /// Returns `DraftAlreadyExists` if draft of `proposal` already exists.
+    #[ink(message)]
+    fn create_draft(&mut self, proposal: Proposal) -> Result<ProposalHash, GovernError>;
+
+    /// Pledges `amount` of `caller` votes to the draft with `proposal_hash` and deposits the proposer deposit part of `amount` in shares.
+    /// Once the pledged votes reach the minimum stake part, the draft becomes an active proposal.
+    ///
+    /// On success emits `Sponsored` event and, if draft is activated, `ProposalCreated` event.
+    ///
+    /// # Errors
+    /// Returns `DraftDoesntExist` if draft doesn't exist.
+    /// Returns `InsuficientVotes` if `amount` is 0 or exceeds `caller` votes.
+    /// Returns `TooManySponsors` if the draft has the maximal number of sponsors.
+    #[ink(message)]
+    fn sponsor(&mut self, proposal_hash: ProposalHash, amount: Balance) -> Result<(), GovernError>;
+
+    /// Withdraws `caller` sponsorship of the draft with `proposal_hash` and returns the deposit.
+    ///
+    /// On success emits `Unsponsored` event.
+    ///
+    /// # Errors
+    /// Returns `DraftDoesntExist` if draft doesn't exist (or was already activated).
+    /// Returns `NotSponsor` if `caller` doesn't sponsor the draft.
+    #[ink(message)]
+    fn unsponsor(&mut self, proposal_hash: ProposalHash) -> Result<(), GovernError>;
+
+    /// Finilize `proposal_id` if the finalization conditions are met.  
+    ///
+    /// On success emits `ProposalFinalized` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal is not `Active``.
+    /// Returns `FinalizeCondition` if finalize condition isn't met or the quorum wasn't reached and the final period hasn't ended yet.
+    #[ink(message)]
+    fn finalize(&mut self, proposal_id: ProposalId) -> Result<(), GovernError>;
+
+    /// Executes the `proposal` which was finalized with `Succeeded` status.
+    ///
+    /// On success emits `ProposalExecuted` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal status is not `Succeeded`.
+    /// Returns `UnderlyingTransactionReverted` if any of Transactions from the `proposal` fails.
+    #[ink(message)]
+    fn execute(&mut self, proposal: Proposal) -> Result<(), GovernError>;
+
+    /// Cast vote in the name of `caller` on `proposa_id` for `vote` with `reason`.
+    ///
+    /// On Success emits `VoteCasted` event.
+    ///
+    /// # Errors
+    /// Returns `InsuficientVotes` if `caller` has no votes.
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal status isn't `Active`.
+    /// Returns `WrongVotingMode` if proposal uses commit-reveal voting.
+    #[ink(message)]
+    fn vote(
+        &mut self,
+        proposal_id: ProposalId,
+        vote: Vote,
+        reason: Vec<u8>,
+    ) -> Result<(), GovernError>;
+
+    /// Commits `vote_hash` in the name of `caller` on commit-reveal `proposal_id`. Overrides the previous commit.
+    /// `vote_hash` should be computed with `hash_vote`.
+    ///
+    /// On Success emits `VoteCommitted` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal status isn't `Active`.
+    /// Returns `WrongVotingMode` if proposal doesn't use commit-reveal voting.
+    /// Returns `NotCommitPeriod` if the final period has ended.
+    #[ink(message)]
+    fn commit_vote(&mut self, proposal_id: ProposalId, vote_hash: Hash) -> Result<(), GovernError>;
+
+    /// Reveals the vote committed by `caller` on commit-reveal `proposal_id` and casts it.
+    ///
+    /// On Success emits `VoteCasted` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal status isn't `Active`.
+    /// Returns `WrongVotingMode` if proposal doesn't use commit-reveal voting.
+    /// Returns `NotRevealPeriod` if it isn't the reveal period.
+    /// Returns `NoCommit` if `caller` has no commit on the proposal.
+    /// Returns `CommitMismatch` if `vote` and `salt` don't match the commit.
+    /// Returns `InsuficientVotes` if `caller` has no votes.
+    #[ink(message)]
+    fn reveal_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        vote: Vote,
+        salt: Hash,
+    ) -> Result<(), GovernError>;
+
+    /// Forcefully unstakes all tokens of `account` if:
+    /// 1. proposal with `proposal_id` was finalized in Final phase
+    /// 2. `account` has staked some tokens before the proposal was created.
+    /// 3. the grace period after the proposal finalization has passed.
+    ///
+    /// On success emits `ForcefullyUnstaked` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal wasn't finalized in final phase.
+    /// Returns `CantForceUnstake` if proposal doesnt allow for force unstake or the 'account' was already force unstaked for not voting on proposal with id >= 'proposal_id'.
+    /// Returns `ForceUnstakeGracePeriod` if the grace period after the proposal finalization hasn't passed yet.
+    #[ink(message)]
+    fn force_unstake(
+        &mut self,
+        account: AccountId,
+        proposal_id: ProposalId,
+    ) -> Result<(), GovernError>;
+}
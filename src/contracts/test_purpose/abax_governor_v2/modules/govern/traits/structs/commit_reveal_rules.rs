@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// Timing of proposals with commit-reveal voting.
+/// Votes are committed until the end of `final_period` and revealed afterwards.
+pub struct CommitRevealRules {
+    /// time after the end of final_period during which committed votes can be revealed.
+    /// If it is 0, proposals with commit-reveal voting can't be created.
+    pub reveal_period: Timestamp,
+}
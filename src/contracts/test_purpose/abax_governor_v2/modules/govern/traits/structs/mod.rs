@@ -0,0 +1,18 @@
+use ink::primitives::Hash;
+pub use pendzl::traits::{AccountId, Balance, Timestamp};
+
+pub type ProposalId = u32;
+
+include!("voting_rules.rs");
+include!("commit_reveal_rules.rs");
+include!("proposer_deposit.rs");
+include!("sponsorship.rs");
+include!("proposal_state.rs");
+include!("proposal_state_v1.rs");
+include!("proposal_status.rs");
+include!("proposal.rs");
+include!("transaction.rs");
+include!("user_vote.rs");
+include!("vote.rs");
+include!("voting_phase.rs");
+include!("pause.rs");
@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// Subset of messages that can be paused. `true` means paused.
+pub struct PausedMessages {
+    pub propose: bool,
+    /// while voting is paused, voting periods of active proposals are extended by the paused duration.
+    pub vote: bool,
+    pub finalize: bool,
+    pub deposit: bool,
+    pub withdraw: bool,
+    pub force_unstake: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Pause {
+    /// paused messages
+    pub messages: PausedMessages,
+    /// time at which the pause started
+    pub start: Timestamp,
+    /// time at which the pause ends automatically (or was ended by `unpause`)
+    pub end: Timestamp,
+}
@@ -0,0 +1,70 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// Layout of `ProposalState` in storage version 1.
+pub struct ProposalStateV1 {
+    /// proposal status
+    pub status: ProposalStatus,
+    /// if proposal was finalized in final phase, then it's possible to force unstake
+    pub force_unstake_possible: bool,
+    /// the proposer
+    pub proposer: AccountId,
+    /// if true, votes are committed as hashes and counted only when revealed after the final period
+    pub commit_reveal: bool,
+    /// time of proposition
+    pub start: Timestamp,
+    /// Stake::total_stake at start
+    pub votes_at_start: Balance,
+    /// Stake::counter_stake at start
+    pub counter_at_start: Balance,
+    /// total duration during which voting was paused, at start
+    pub voting_paused_at_start: Timestamp,
+    /// time of proposal finalization. Some if proposal finalized. None if porposal is not finalized yet.
+    pub finalized: Option<Timestamp>,
+    /// amount of votes to accept the proposal
+    pub votes_for: Balance,
+    /// amount of votes to reject proposal
+    pub votes_against: Balance,
+    /// amount of votes to reject proposal and slash the proposer
+    pub votes_against_with_slash: Balance,
+    /// amount of votes that participated without taking a side
+    pub votes_abstain: Balance,
+    /// earliest time when proposal can be executed
+    pub earliest_execution: Option<Timestamp>,
+    /// number of accounts that voted on the proposal
+    pub voters_count: u32,
+    /// phase of voting in which the proposal was finalized. Some if proposal finalized.
+    pub finalization_phase: Option<VotingPhase>,
+    /// minimum votes to finalize at the moment of finalization. Some if proposal finalized.
+    pub minimum_to_finalize_at_finalization: Option<Balance>,
+    /// time of proposal execution. Some if proposal executed.
+    pub executed: Option<Timestamp>,
+}
+
+impl From<ProposalStateV1> for ProposalState {
+    fn from(state: ProposalStateV1) -> Self {
+        ProposalState {
+            status: state.status,
+            force_unstake_possible: state.force_unstake_possible,
+            proposer: state.proposer,
+            commit_reveal: state.commit_reveal,
+            start: state.start,
+            votes_at_start: state.votes_at_start,
+            counter_at_start: state.counter_at_start,
+            voting_paused_at_start: state.voting_paused_at_start,
+            finalized: state.finalized,
+            votes_for: state.votes_for,
+            votes_against: state.votes_against,
+            votes_against_with_slash: state.votes_against_with_slash,
+            votes_abstain: state.votes_abstain,
+            earliest_execution: state.earliest_execution,
+            voters_count: state.voters_count,
+            finalization_phase: state.finalization_phase,
+            minimum_to_finalize_at_finalization: state.minimum_to_finalize_at_finalization,
+            executed: state.executed,
+            last_vote: None,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+/// The way the proposer deposit is taken.
+pub enum ProposerDeposit {
+    /// `proposer_deposit_part_e3` of the minimum votes to propose is locked in shares. The proposer must meet `minimum_stake_part_e3`.
+    #[default]
+    Shares,
+    /// `proposer_deposit_part_e3` of the minimum votes to propose converted to assets is pulled from the proposer with `transfer_from`.
+    Assets,
+    /// Fixed amount of assets is pulled from the proposer with `transfer_from`.
+    FixedAssets(Balance),
+}
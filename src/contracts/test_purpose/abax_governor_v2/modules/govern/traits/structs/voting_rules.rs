@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+
+pub struct VotingRules {
+    /// minimal part of proposer stake in total stake to propose.
+    pub minimum_stake_part_e3: u16,
+    /// part of total
+    pub proposer_deposit_part_e3: u16,
+    /// during initial period required amount to finalize proposal falls from 100% to 50% of total votes.
+    pub initial_period: Timestamp,
+    /// time after start of proposal during which the required amount to finalize proposal is flat at 50%.
+    pub flat_period: Timestamp,
+    /// time after flat_period during which the required amount to finalize proposal linearly falls to 0.
+    pub final_period: Timestamp,
+    /// minimal part of total votes that has to participate in voting to finalize proposal, regardless of the time passed.
+    /// If it is not reached by the end of final_period, the proposal is finalized as Defeated.
+    pub minimum_quorum_e3: u16,
+    /// maximal number of simultaneously active proposals.
+    pub maximum_active_proposals: u32,
+    /// maximal number of simultaneously active proposals created by a single proposer.
+    pub maximum_active_proposals_per_proposer: u32,
+    /// minimal time between two proposals created by the same proposer.
+    pub minimum_proposal_interval: Timestamp,
+    /// time after finalization of a proposal during which accounts that didn't vote can't be force unstaked yet.
+    pub force_unstake_grace_period: Timestamp,
+}
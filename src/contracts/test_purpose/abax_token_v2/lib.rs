@@ -6,8 +6,8 @@ pub mod modules;
 #[ink::contract]
 pub mod abax_token {
     use crate::modules::{
-        capped_infaltion_storage_field::CappedInflation, new_storage_field::NewStorageFieldView,
-        reserved::Reserved,
+        capped_infaltion_storage_field::CappedInflation, errors::CappedInflationError,
+        new_storage_field::NewStorageFieldView, reserved::Reserved,
     };
     use ink::prelude::string::String;
     use pendzl::{
@@ -69,13 +69,13 @@ pub mod abax_token {
             Ok(instance)
         }
 
-        pub fn _inflate_cap(&mut self) {
+        pub fn _inflate_cap(&mut self) -> Result<(), CappedInflationError> {
             self.capped_inflation
-                .inflate(self.env().block_timestamp())
-                .unwrap();
+                .inflate(self.env().block_timestamp())?;
             self.env().emit_event(CapUpdated {
                 cap: self.capped_inflation.cap(),
             });
+            Ok(())
         }
 
         #[ink(message)]
@@ -128,7 +128,7 @@ pub mod abax_token {
         #[ink(message)]
         fn generate(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
             self._ensure_has_role(GENERATOR, Some(self.env().caller()))?;
-            self._inflate_cap();
+            self._inflate_cap()?;
             let delta_inflation = amount.checked_div(TEN_YEARS).ok_or(MathError::DivByZero)?;
             self.capped_inflation
                 .increase_inflation_rate_per_milisecond(delta_inflation)?;
@@ -151,7 +151,7 @@ pub mod abax_token {
         #[ink(message)]
         fn mint(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
             self._ensure_has_role(MINTER, Some(self.env().caller()))?;
-            self._inflate_cap();
+            self._inflate_cap()?;
             self._mint_to(&to, &amount)
         }
     }
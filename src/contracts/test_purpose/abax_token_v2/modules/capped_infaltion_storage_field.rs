@@ -3,6 +3,8 @@ use pendzl::{
     traits::{Balance, Timestamp},
 };
 
+use crate::modules::errors::CappedInflationError;
+
 #[derive(Default, Debug)]
 #[pendzl::storage_item]
 pub struct CappedInflation {
@@ -12,6 +14,8 @@ pub struct CappedInflation {
     inflation_rate_per_milisecond: Balance,
     #[lazy]
     last_cap_update: Timestamp,
+    #[lazy]
+    sanitize_clock_regression: bool,
     // new field added
     #[lazy]
     added_new_field: Balance,
@@ -54,14 +58,20 @@ impl CappedInflation {
         self.last_cap_update.get().unwrap_or(0)
     }
 
-    pub fn inflate(&mut self, now: Timestamp) -> Result<(), MathError> {
+    pub fn sanitize_clock_regression(&self) -> bool {
+        self.sanitize_clock_regression.get().unwrap_or(false)
+    }
+
+    pub fn inflate(&mut self, now: Timestamp) -> Result<(), CappedInflationError> {
         if now < self.last_cap_update() {
-            panic!("Invalid timestamp");
+            if self.sanitize_clock_regression() {
+                return Ok(());
+            }
+            return Err(CappedInflationError::TimestampBeforeLastCapUpdate);
         }
         let time_diff = now
             .checked_sub(self.last_cap_update.get().unwrap_or(0))
-            .ok_or(MathError::Overflow)
-            .unwrap();
+            .ok_or(MathError::Underflow)?;
         let increase_cap_by = self
             .inflation_rate_per_milisecond()
             .checked_mul(time_diff as u128)
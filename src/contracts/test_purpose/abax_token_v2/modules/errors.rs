@@ -0,0 +1,26 @@
+use pendzl::{contracts::psp22::PSP22Error, math::errors::MathError};
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CappedInflationError {
+    MathError(MathError),
+    /// `now` is before the last cap update.
+    TimestampBeforeLastCapUpdate,
+}
+
+impl From<MathError> for CappedInflationError {
+    fn from(error: MathError) -> Self {
+        CappedInflationError::MathError(error)
+    }
+}
+
+impl From<CappedInflationError> for PSP22Error {
+    fn from(error: CappedInflationError) -> Self {
+        match error {
+            CappedInflationError::MathError(error) => error.into(),
+            CappedInflationError::TimestampBeforeLastCapUpdate => {
+                PSP22Error::Custom("CI:TimestampBeforeLastCapUpdate".into())
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod capped_infaltion_storage_field;
+pub mod errors;
 pub mod new_storage_field;
 pub mod reserved;
 pub mod traits;
@@ -1,5 +1,5 @@
 import BN from 'bn.js';
-import { ABAX_DECIMALS, AllAbaxDAORoleNames, ONE_YEAR } from 'tests/consts';
+import { ABAX_DECIMALS, AllAbaxDAORoleNames, MAX_U128, ONE_YEAR } from 'tests/consts';
 import { stringToSelectorId, testAccessControlForMessage } from 'tests/misc';
 import { expect } from 'tests/setup/chai';
 import AbaxToken from 'typechain/contracts/abax_token';
@@ -16,8 +16,9 @@ describe('AbaxToken', () => {
   let abaxToken: AbaxToken;
   beforeEach(async () => {
     const api = await localApi.get();
-    abaxToken = (await new AbaxTokenDeployer(api, deployer).new('NAME', 'SYMBOL', ABAX_DECIMALS)).contract;
+    abaxToken = (await new AbaxTokenDeployer(api, deployer).new('NAME', 'SYMBOL', ABAX_DECIMALS, api.genesisHash.toHex())).contract;
     await abaxToken.withSigner(deployer).tx.grantRole(stringToSelectorId('MINTER'), minter.address);
+    await abaxToken.withSigner(deployer).tx.setMinterAllowance(minter.address, ONE_YEAR, MAX_U128, MAX_U128);
     await abaxToken.withSigner(deployer).tx.grantRole(stringToSelectorId('CODE_UPGRADER'), upgrader.address);
     await abaxToken.withSigner(deployer).tx.grantRole(stringToSelectorId('GENERATOR'), generator.address);
   });
@@ -141,6 +142,10 @@ describe('AbaxToken', () => {
       });
     });
     describe('when called by minter', () => {
+      it('when minter has no allowance should fail', async () => {
+        await abaxToken.withSigner(deployer).tx.removeMinterAllowance(minter.address);
+        await expect(abaxToken.withSigner(minter).query.mint(other.address, 0)).to.be.revertedWithError({ custom: 'MA:NoAllowance' });
+      });
       it('when called while cap is 0 thenshould fail', async () => {
         await expect(abaxToken.withSigner(minter).query.mint(other.address, 1)).to.be.revertedWithError({ custom: 'CapReached' });
       });
@@ -6,6 +6,23 @@ export const ONE_DAY = new BN(24 * 60 * 60 * 1000);
 export const ONE_YEAR = ONE_DAY.mul(new BN(365));
 export const MAX_U128 = '340282366920938463463374607431768211455';
 
+const FOUR_YEARS = ONE_YEAR.muln(4).toString();
+// 20% public contribution (40% instant, 60% vested), 20% founders (20% instant, 80% vested), 2% foundation, 58% strategic reserves
+export const TGE_TOKEN_ALLOCATION_DISTRIBUTION = {
+  publicContribution: {
+    partE3: 200,
+    instantReleasePercentageE3: 400,
+    vestingParams: { duration: FOUR_YEARS },
+  },
+  founders: {
+    partE3: 200,
+    instantReleasePercentageE3: 200,
+    vestingParams: { duration: FOUR_YEARS },
+  },
+  foundation: { partE3: 20, instantReleasePercentageE3: 1000, vestingParams: null },
+  strategicReserves: { partE3: 580, instantReleasePercentageE3: 1000, vestingParams: null },
+};
+
 export const ContractRoleNames = [
   'DEFAULT_ADMIN',
   'TREASURY',
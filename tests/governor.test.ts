@@ -13,12 +13,14 @@ import GovernorDeployer from 'typechain/deployers/abax_governor';
 import Psp22EmitableDeployer from 'typechain/deployers/psp22_emitable';
 import VesterDeployer from 'typechain/deployers/abax_vester';
 import { ProposalCreated } from 'typechain/event-types/governor';
-import { Proposal, Transaction, VotingRules } from 'typechain/types-arguments/governor';
+import { Proposal, ProposerDepositBuilder, Transaction, VotingRules } from 'typechain/types-arguments/governor';
 import { AccessControlError, GovernError, GovernErrorBuilder, ProposalStatus, Vote } from 'typechain/types-returns/governor';
 import { ONE_DAY } from '@c-forge/polkahat-chai-matchers';
 import { E12bn, duration, generateRandomSignerWithBalance, getSigners, localApi, time } from '@c-forge/polkahat-network-helpers';
 import { numbersToHex, paramsToInputNumbers } from './paramsHexConversionUtils';
-import { roleToSelectorId } from 'tests/misc';
+import { roleToSelectorId, stringToSelectorId } from 'tests/misc';
+import AbaxGovernorV2Contract from 'typechain/contracts/abax_governor_v2';
+import AbaxGovernorV2Deployer from 'typechain/deployers/abax_governor_v2';
 
 const [deployer, other] = getSigners();
 const ONE_TOKEN = new BN(10).pow(new BN(ABAX_DECIMALS));
@@ -35,6 +37,11 @@ const VOTING_RULES: VotingRules = {
   initialPeriod: ONE_DAY.muln(3),
   flatPeriod: ONE_DAY.muln(7),
   finalPeriod: ONE_DAY.muln(4),
+  minimumQuorumE3: 0,
+  maximumActiveProposals: 10,
+  maximumActiveProposalsPerProposer: 10,
+  minimumProposalInterval: 0,
+  forceUnstakeGracePeriod: 0,
 };
 
 const descriptionUrl = 'https://someurl.com/proposal/21iuhsa837iuhsa218312sajdiuhsad';
@@ -85,6 +92,11 @@ describe('AbaxGovernor', () => {
         initialPeriod: ONE_DAY.muln(3),
         flatPeriod: ONE_DAY.muln(7),
         finalPeriod: ONE_DAY.muln(4),
+        minimumQuorumE3: 0,
+        maximumActiveProposals: 10,
+        maximumActiveProposalsPerProposer: 10,
+        minimumProposalInterval: 0,
+        forceUnstakeGracePeriod: 0,
       };
       await expect(
         new GovernorDeployer(await localApi.get(), deployer).new(
@@ -117,6 +129,11 @@ describe('AbaxGovernor', () => {
           initialPeriod: ONE_DAY.muln(50),
           flatPeriod: ONE_DAY.muln(100),
           finalPeriod: ONE_DAY.muln(50),
+          minimumQuorumE3: 0,
+          maximumActiveProposals: 10,
+          maximumActiveProposalsPerProposer: 10,
+          minimumProposalInterval: 0,
+          forceUnstakeGracePeriod: 0,
         };
         await expect(governor.withSigner(parametersAdmin).query.changeVotingRules(newVotingRules)).to.be.revertedWithError(
           GovernErrorBuilder.UnstakeShorterThanVotingPeriod(),
@@ -129,6 +146,11 @@ describe('AbaxGovernor', () => {
           initialPeriod: ONE_DAY.muln(5),
           flatPeriod: ONE_DAY.muln(10),
           finalPeriod: ONE_DAY.muln(5),
+          minimumQuorumE3: 0,
+          maximumActiveProposals: 10,
+          maximumActiveProposalsPerProposer: 10,
+          minimumProposalInterval: 0,
+          forceUnstakeGracePeriod: 0,
         };
 
         const tx = governor.withSigner(parametersAdmin).tx.changeVotingRules(newVotingRules);
@@ -139,6 +161,11 @@ describe('AbaxGovernor', () => {
             initialPeriod: newVotingRules.initialPeriod,
             flatPeriod: newVotingRules.flatPeriod,
             finalPeriod: newVotingRules.finalPeriod,
+            minimumQuorumE3: newVotingRules.minimumQuorumE3,
+            maximumActiveProposals: newVotingRules.maximumActiveProposals,
+            maximumActiveProposalsPerProposer: newVotingRules.maximumActiveProposalsPerProposer,
+            minimumProposalInterval: newVotingRules.minimumProposalInterval,
+            forceUnstakeGracePeriod: newVotingRules.forceUnstakeGracePeriod,
           },
         });
       });
@@ -677,6 +704,83 @@ describe('AbaxGovernor', () => {
         });
       });
     });
+    describe('Proposer deposit in assets', () => {
+      const description = 'Abax will be the best ;-)';
+      const proposerAssets = midStake;
+      let proposer: KeyringPair;
+      beforeEach(async () => {
+        proposer = voters[0];
+        await token.tx.mint(proposer.address, proposerAssets);
+        await token.withSigner(proposer).tx.approve(governor.address, proposerAssets);
+      });
+      it('fails to set a fixed deposit of 0 assets', async () => {
+        await expect(governor.withSigner(parametersAdmin).query.changeProposerDeposit(ProposerDepositBuilder.FixedAssets(0))).to.be.revertedWithError(
+          GovernErrorBuilder.InvalidProposerDeposit(),
+        );
+      });
+      describe('deposit is a fixed amount of assets', () => {
+        const fixedDeposit = smallStake.muln(3);
+        let proposalId: BN;
+        beforeEach(async () => {
+          await governor.withSigner(parametersAdmin).tx.changeProposerDeposit(ProposerDepositBuilder.FixedAssets(fixedDeposit));
+          [proposalId] = await proposeAndCheck(governor, proposer, [], description);
+        });
+        it('locks the fixed amount of assets and no shares', async () => {
+          await expect(governor.query.lockedAssets(proposalId)).to.haveOkResult(fixedDeposit);
+          await expect(token.query.balanceOf(proposer.address)).to.haveOkResult(proposerAssets.sub(fixedDeposit));
+          await expect(governor.query.balanceOf(proposer.address)).to.haveOkResult(bigStake);
+        });
+        it('locked assets do not back the shares', async () => {
+          await expect(governor.query.totalAssets()).to.haveOkResult(totalStake);
+          await expect(governor.query.convertToAssets(ONE_TOKEN)).to.haveOkResult(ONE_TOKEN);
+        });
+      });
+      describe('deposit is a part of the minimum stake to propose taken in assets', () => {
+        let proposalId: BN;
+        let deposit: BN;
+        beforeEach(async () => {
+          await governor.withSigner(parametersAdmin).tx.changeProposerDeposit(ProposerDepositBuilder.Assets());
+          [proposalId] = await proposeAndCheck(governor, proposer, [], description);
+          deposit = new BN((await governor.query.lockedAssets(proposalId)).value.unwrap()!.toString());
+        });
+        it('locks assets worth the proposer deposit part of the minimum stake to propose', async () => {
+          const minimumStakeToPropose = totalStake.muln(VOTING_RULES.minimumStakePartE3 as number).divn(1000);
+          expect(deposit.toString()).to.equal(minimumStakeToPropose.muln(VOTING_RULES.proposerDepositPartE3 as number).divn(1000).toString());
+          await expect(token.query.balanceOf(proposer.address)).to.haveOkResult(proposerAssets.sub(deposit));
+          await expect(governor.query.balanceOf(proposer.address)).to.haveOkResult(bigStake);
+          await expect(governor.query.totalAssets()).to.haveOkResult(totalStake);
+        });
+        describe(`all stakers votes for 'disagree'`, () => {
+          beforeEach(async () => {
+            for (const voter of voters.slice(0, 6)) {
+              await voteAndCheck(governor, voter, proposalId, Vote.disagreed);
+            }
+          });
+          it('returns the deposit to the proposer on finalization', async () => {
+            await finalizeAndCheck(governor, voters[1], proposalId, ProposalStatus.defeated);
+            await expect(governor.query.lockedAssets(proposalId)).to.haveOkResult(0);
+            await expect(token.query.balanceOf(proposer.address)).to.haveOkResult(proposerAssets);
+            await expect(governor.query.totalAssets()).to.haveOkResult(totalStake);
+          });
+        });
+        describe(`all stakers vote for 'disagreedWithProposerSlashing'`, () => {
+          beforeEach(async () => {
+            for (const voter of voters.slice(0, 6)) {
+              await voteAndCheck(governor, voter, proposalId, Vote.disagreedWithProposerSlashing);
+            }
+          });
+          it('slashes the deposit, which is shared among stakers', async () => {
+            const sharePriceBefore = new BN((await governor.query.convertToAssets(totalStake)).value.unwrap()!.toString());
+            await finalizeAndCheck(governor, voters[1], proposalId, ProposalStatus.defeatedWithSlash);
+            await expect(governor.query.lockedAssets(proposalId)).to.haveOkResult(0);
+            await expect(token.query.balanceOf(proposer.address)).to.haveOkResult(proposerAssets.sub(deposit));
+            await expect(governor.query.totalAssets()).to.haveOkResult(totalStake.add(deposit));
+            const sharePriceAfter = new BN((await governor.query.convertToAssets(totalStake)).value.unwrap()!.toString());
+            expect(sharePriceAfter.gt(sharePriceBefore)).to.be.true;
+          });
+        });
+      });
+    });
     describe('Execute', () => {
       const description = 'Abax will be the best ;-)';
       let proposalId: BN;
@@ -907,6 +1011,77 @@ describe('AbaxGovernor', () => {
           });
         });
 
+        describe('that upgrades the governor to v2 and grants MIGRATOR role to foundation', () => {
+          const MIGRATION_BATCH = 10;
+          const MIGRATOR = parseInt(stringToSelectorId('MIGRATOR'));
+          let newCodeHash: string;
+          beforeEach(async () => {
+            const api = await localApi.get();
+            const governorV2 = (
+              await new AbaxGovernorV2Deployer(api, deployer).new(
+                token.address,
+                vester.address,
+                foundation.address,
+                parametersAdmin.address,
+                UNSTAKE_PERIOD,
+                'AbaxGovernor Votes',
+                'VOTE',
+                VOTING_RULES,
+              )
+            ).contract;
+            newCodeHash = ((await api.query.contracts.contractInfoOf(governorV2.address)).toHuman() as { codeHash: string }).codeHash;
+
+            const setCodeHashParams = paramsToInputNumbers(governor.abi.findMessage('SetCodeHash::set_code_hash').toU8a([newCodeHash]));
+            const grantRoleParams = paramsToInputNumbers(governor.abi.findMessage('AccessControl::grant_role').toU8a([MIGRATOR, foundation.address]));
+            transactions = [
+              {
+                callee: governor.address,
+                selector: setCodeHashParams.selector,
+                input: setCodeHashParams.data,
+                transferredValue: 0,
+              },
+              {
+                callee: governor.address,
+                selector: grantRoleParams.selector,
+                input: grantRoleParams.data,
+                transferredValue: 0,
+              },
+            ];
+            [proposalId, descriptionHash] = await proposeAndCheck(governor, voters[0], transactions, description);
+            proposal = { descriptionUrl, descriptionHash, transactions, earliestExecution: null };
+          });
+
+          it('migration preserves existing proposals and runs only once', async () => {
+            await finalize();
+            await expect(governor.query.storageVersion()).to.haveOkResult(1);
+            const stateBefore = (await governor.query.state(proposalId)).value.unwrapRecursively()!;
+            await governor.withSigner(foundation).tx.execute(proposal);
+
+            const api = await localApi.get();
+            const res = (await api.query.contracts.contractInfoOf(governor.address)).toHuman() as { codeHash: string };
+            expect(res.codeHash).to.equal(newCodeHash);
+
+            const governorAsV2 = new AbaxGovernorV2Contract(governor.address, foundation, governor.nativeAPI);
+            await expect(governorAsV2.withSigner(voters[0]).query.migrate(MIGRATION_BATCH)).to.be.revertedWithError(
+              GovernErrorBuilder.AccessControlError(AccessControlError.missingRole),
+            );
+            const tx = governorAsV2.withSigner(foundation).tx.migrate(MIGRATION_BATCH);
+            await expect(tx).to.emitEvent(governorAsV2, 'Migrated', { from: 1, to: 2 });
+            await expect(governorAsV2.query.storageVersion()).to.haveOkResult(2);
+
+            const stateAfter = (await governorAsV2.query.state(proposalId)).value.unwrapRecursively()!;
+            expect(stateAfter.proposer).to.equal(stateBefore.proposer);
+            expect(stateAfter.status).to.equal(ProposalStatus.executed);
+            expect(stateAfter.votesFor.toString()).to.equal(stateBefore.votesFor.toString());
+            expect(stateAfter.votersCount).to.equal(stateBefore.votersCount);
+            expect(stateAfter.lastVote).to.equal(null);
+
+            await expect(governorAsV2.withSigner(foundation).query.migrate(MIGRATION_BATCH)).to.be.revertedWithError(
+              GovernErrorBuilder.AlreadyMigrated(),
+            );
+          });
+        });
+
         describe('that have params', () => {
           beforeEach(async () => {
             const message = token.abi.findMessage('PSP22::increase_allowance');
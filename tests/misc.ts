@@ -11,19 +11,19 @@ import { AbaxAccessControlRole } from 'tests/consts';
 
 //based on the above
 export async function getTgeParams(tge: AbaxTge) {
-  const res = (await tge.query.tgeParameters()).value.ok!;
+  const res = (await tge.query.parameters()).value.ok!;
   return {
-    startTime: res[0],
-    phaseTwoStartTime: res[1],
-    phaseTwoDuration: res[2],
-    generatedTokenAddress: res[3],
-    wazeroAddress: res[4],
-    vesterAddress: res[5],
-    foundersAddress: res[6],
-    foundationAddress: res[7],
-    strategicReservesAddress: res[8],
-    phaseOneTokenCap: res[9],
-    costToMintMillionTokens: res[10],
+    startTime: res.startTime,
+    phaseTwoStartTime: res.phaseTwoStartTime,
+    phaseTwoDuration: res.phaseTwoDuration,
+    generatedTokenAddress: res.generatedTokenAddress,
+    wazeroAddress: res.contributionTokenAddress,
+    vesterAddress: res.vesterAddress,
+    foundersAddress: res.foundersAddress,
+    foundationAddress: res.foundationAddress,
+    strategicReservesAddress: res.strategicReservesAddress,
+    phaseOneTokenCap: res.phaseOneTokenCap,
+    costToMintMillionTokens: res.costToMintMilliardTokens,
   };
 }
 export const createEnumChecker = <T extends string, TEnumValue extends string>(enumVariable: { [key in T]: TEnumValue }) => {
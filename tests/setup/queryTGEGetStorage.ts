@@ -6,6 +6,7 @@ import { queryAt } from 'tests/setup/queryAt';
 import AbaxTgeMethods from 'typechain/query/abax_tge';
 type RetType = NonNullable<ReturnPromiseType<AbaxTgeMethods['tgeParameters']>['value']['ok']>;
 
+// queries historical blocks too, where `AbaxTGEView::parameters` isn't available yet, so the tuple `tge_parameters` is used
 export async function queryTGEGetStorage(apiAt: ApiDecoration<'promise'>, tge: any) {
   const res = (await queryAt<any>(apiAt, tge, nobody().address, 'AbaxTGEView::tge_parameters', [])) as RetType;
   return {
@@ -1,6 +1,6 @@
 import type { KeyringPair } from '@polkadot/keyring/types';
 import BN from 'bn.js';
-import { ABAX_DECIMALS, USDC_DECIMALS, AbaxDAOSpecificRoles } from 'tests/consts';
+import { ABAX_DECIMALS, USDC_DECIMALS, AbaxDAOSpecificRoles, TGE_TOKEN_ALLOCATION_DISTRIBUTION } from 'tests/consts';
 import { getTgeParams, roleToSelectorId } from 'tests/misc';
 import { expect } from 'tests/setup/chai';
 import { default as AbaxTge, default as AbaxTgeContract } from 'typechain/contracts/abax_tge';
@@ -11,7 +11,7 @@ import AbaxTgeDeployer from 'typechain/deployers/abax_tge';
 import AbaxTokenDeployer from 'typechain/deployers/abax_token';
 import Psp22EmitableDeployer from 'typechain/deployers/psp22_emitable';
 import VesterDeployer from 'typechain/deployers/abax_vester';
-import { AccessControlError } from 'typechain/types-arguments/abax_tge';
+import { AccessControlError, TGEPhase } from 'typechain/types-arguments/abax_tge';
 import { TGEErrorBuilder } from 'typechain/types-returns/abax_tge';
 import { MAX_U128, ONE_YEAR, stringifyNumericProps } from '@c-forge/polkahat-chai-matchers';
 import { E12bn, E3bn, E6bn, generateRandomSignerWithBalance, getSigners, localApi, time } from '@c-forge/polkahat-network-helpers';
@@ -65,7 +65,7 @@ describe('TGE', () => {
   beforeEach(async () => {
     const api = await localApi.get();
     await time.setTo(now);
-    abaxToken = (await new AbaxTokenDeployer(api, admin).new('ABAX', 'ABAX', ABAX_DECIMALS)).contract;
+    abaxToken = (await new AbaxTokenDeployer(api, admin).new('ABAX', 'ABAX', ABAX_DECIMALS, api.genesisHash.toHex())).contract;
 
     usdc = (await new Psp22EmitableDeployer(api, admin).new('USD Coin', 'USDC', USDC_DECIMALS)).contract;
     vester = (await new VesterDeployer(api, admin).new()).contract;
@@ -98,6 +98,27 @@ describe('TGE', () => {
         COST_TO_MINT_MILLIARD_TOKENS,
       ]);
     });
+    it(`should return named parameters of the tge`, async function () {
+      const parameters = (await tge.query.parameters()).value.ok!;
+      expect(stringifyNumericProps(parameters)).to.deep.equal(
+        stringifyNumericProps({
+          startTime: now + DAY,
+          phaseTwoStartTime: null,
+          phaseTwoDuration: 90 * DAY,
+          phaseTwoEndTime: null,
+          generatedTokenAddress: abaxToken.address,
+          contributionTokenAddress: usdc.address,
+          vesterAddress: vester.address,
+          foundersAddress: founders.address,
+          foundationAddress: foundation.address,
+          strategicReservesAddress: strategicReserves.address,
+          phaseOneTokenCap: PHASE_ONE_TOKEN_CAP,
+          phaseOneRemainingCapacity: PHASE_ONE_TOKEN_CAP.sub(PHASE_ONE_TOKEN_CAP.muln(800).divn(1000)),
+          costToMintMilliardTokens: COST_TO_MINT_MILLIARD_TOKENS,
+          currentPhase: TGEPhase.notStarted,
+        }),
+      );
+    });
 
     it('should properly update total minted amount', async function () {
       await expect(tge.query.totalAmountMinted()).to.haveOkResult(PHASE_ONE_TOKEN_CAP.muln(80).divn(100));
@@ -452,8 +473,10 @@ describe('TGE', () => {
 
       it('state', async function () {
         await expect(tge.query.totalAmountMinted()).to.haveOkResult(PHASE_ONE_TOKEN_CAP.toString());
-        const phaseTwoStartTime = (await tge.query.tgeParameters()).value.ok![1];
-        expect(phaseTwoStartTime).not.to.be.null;
+        const parameters = (await tge.query.parameters()).value.ok!;
+        expect(parameters.phaseTwoStartTime).not.to.be.null;
+        expect(parameters.phaseOneRemainingCapacity.toString()).to.equal('0');
+        expect(parameters.currentPhase).to.equal(TGEPhase.phaseTwo);
       });
 
       it('stakedrop should fail', async function () {
@@ -810,6 +833,7 @@ async function deployTGE(
     strategicReserves.address,
     toAbaxTokenDecimals(100_000_000),
     COST_TO_MINT_MILLIARD_TOKENS,
+    TGE_TOKEN_ALLOCATION_DISTRIBUTION,
   );
 
   await abaxToken.withSigner(admin).tx.grantRole(roleToSelectorId('GENERATOR'), res.contract.address);